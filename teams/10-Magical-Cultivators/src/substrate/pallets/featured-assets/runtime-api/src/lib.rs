@@ -0,0 +1,83 @@
+//! Runtime API definition for the featured assets pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use mc_featured_assets::{
+	AssetFeature, AssetOverview, CanReceive, FeatureDestinyRank, ReceiveError, TransferSimulation,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the state of featured assets.
+	pub trait FeaturedAssetsApi<AccountId, AssetId, Balance, DepositBalance> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		DepositBalance: Codec,
+	{
+		/// The amount of asset `id` that `who` is able to transfer right now, taking every
+		/// active restriction into account.
+		fn transferable_now(id: AssetId, who: AccountId) -> Balance;
+
+		/// The total supply of asset `id`, or `None` if it does not exist.
+		fn try_total_supply(id: AssetId) -> Option<Balance>;
+
+		/// Whether `who` can currently be credited with asset `id`, and if not, why.
+		fn can_receive(id: AssetId, who: AccountId) -> CanReceive;
+
+		/// Whether crediting `who` with asset `id` would make them a zombie, or `None` if they
+		/// already hold it or it does not exist.
+		fn would_be_zombie(id: AssetId, who: AccountId) -> Option<bool>;
+
+		/// The predicted outcome of `from` transferring `amount` of asset `id` to `to`.
+		fn simulate_transfer(
+			id: AssetId,
+			from: AccountId,
+			to: AccountId,
+			amount: Balance,
+		) -> TransferSimulation<Balance>;
+
+		/// The details, feature and metadata of asset `id`, if it exists.
+		fn asset_overview(id: AssetId) -> Option<AssetOverview<Balance, AccountId>>;
+
+		/// The Ethereum style address of asset `id`.
+		fn eth_address_of(id: AssetId) -> [u8; 20];
+
+		/// The asset at the Ethereum style `address`, if there is one.
+		fn asset_of_eth_address(address: [u8; 20]) -> Option<AssetId>;
+
+		/// The deposit reserved for creating an asset with `max_zombies`.
+		fn create_deposit(max_zombies: u32) -> DepositBalance;
+
+		/// The deposit reserved for metadata with a name and a symbol of the given lengths.
+		fn metadata_deposit(name_len: u32, symbol_len: u32) -> DepositBalance;
+
+		/// The assets created by `creator` which still exist.
+		fn assets_created_by(creator: AccountId) -> Vec<AssetId>;
+
+		/// The asset and metadata deposits of every asset owned by `owner`, summed.
+		fn total_deposit_of(owner: AccountId) -> DepositBalance;
+
+		/// Up to `limit` approvals held by `delegate` as (asset, owner, amount), after
+		/// `start_after` if given.
+		fn approvals_for_delegate(
+			delegate: AccountId,
+			start_after: Option<(AssetId, AccountId)>,
+			limit: u32,
+		) -> Vec<(AssetId, AccountId, Balance)>;
+
+		/// The number of assets with each destiny rank, from the lowest rank to the highest.
+		fn feature_histogram() -> Vec<(FeatureDestinyRank, u32)>;
+
+		/// Whether the asset `id` is featured together with its feature, if it exists.
+		fn feature_status(id: AssetId) -> Option<(bool, Option<AssetFeature>)>;
+
+		/// The text form of the asset identifier `id`.
+		fn format_asset_id(id: AssetId) -> Vec<u8>;
+
+		/// The asset identifier written as `s`, if it is one.
+		fn parse_asset_id(s: Vec<u8>) -> Option<AssetId>;
+	}
+}
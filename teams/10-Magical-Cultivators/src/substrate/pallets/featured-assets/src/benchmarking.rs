@@ -0,0 +1,132 @@
+//! Benchmarks for the featured assets pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Create an asset owned by `owner` whose accounts need no zombie allowance, and mint some of it
+/// to each of `holders`.
+fn create_asset<T: Config>(owner: &T::AccountId, holders: &[T::AccountId]) -> T::AssetId {
+	let id = T::AssetId::default();
+	assert!(Pallet::<T>::force_create(
+		T::ForceOrigin::successful_origin(),
+		id,
+		T::Lookup::unlookup(owner.clone()),
+		0,
+		1u32.into(),
+		ExistenceModel::Sufficient,
+	).is_ok());
+	for who in holders {
+		assert!(Pallet::<T>::do_mint(id, who.clone(), 1_000u32.into()).is_ok());
+	}
+	id
+}
+
+/// The whitelisted caller, given every role by `AssetAdmin`.
+fn manager<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::AssetAdmin::set_manager(&caller);
+	caller
+}
+
+fn accounts<T: Config>(name: &'static str, n: u32) -> Vec<T::AccountId> {
+	(0..n).map(|i| account(name, i, SEED)).collect()
+}
+
+benchmarks! {
+	// The draw `force_create` makes to roll the feature of the new asset, weighed on its own so
+	// runtimes can account for a `RandomNumber` source more costly than the one benchmarked.
+	randomness_draw {
+		let seed = 0u32;
+	}: {
+		let rand_value = T::RandomNumber::generate_random(seed);
+		T::FeatureRoller::roll(rand_value);
+	}
+
+	mint_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller = manager::<T>();
+		let id = create_asset::<T>(&caller, &[]);
+		let beneficiaries = accounts::<T>("beneficiary", n).into_iter()
+			.map(|who| (T::Lookup::unlookup(who), 100u32.into()))
+			.collect();
+	}: _(RawOrigin::Signed(caller), id, beneficiaries)
+	verify {
+		assert_eq!(Asset::<T>::get(id).map(|d| d.accounts), Some(n));
+	}
+
+	burn_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller = manager::<T>();
+		let targets = accounts::<T>("target", n);
+		let id = create_asset::<T>(&caller, &targets);
+		let burns = targets.into_iter()
+			.map(|who| (T::Lookup::unlookup(who), 1_000u32.into()))
+			.collect();
+	}: _(RawOrigin::Signed(caller), id, burns)
+	verify {
+		assert_eq!(Asset::<T>::get(id).map(|d| d.accounts), Some(0));
+	}
+
+	transfer_multi {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let id = create_asset::<T>(&caller, &[caller.clone()]);
+		let transfers = accounts::<T>("recipient", n).into_iter()
+			.map(|who| (T::Lookup::unlookup(who), 10u32.into()))
+			.collect();
+	}: _(RawOrigin::Signed(caller), id, transfers)
+	verify {
+		assert_eq!(Asset::<T>::get(id).map(|d| d.accounts), Some(n + 1));
+	}
+
+	freeze_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller = manager::<T>();
+		let targets = accounts::<T>("target", n);
+		let id = create_asset::<T>(&caller, &targets);
+		let who = targets.iter().cloned().map(T::Lookup::unlookup).collect();
+	}: _(RawOrigin::Signed(caller), id, who)
+	verify {
+		assert!(Account::<T>::get(id, &targets[0]).is_frozen());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::{assert_ok, weights::GetDispatchInfo};
+
+	#[test]
+	fn benchmarks_should_run() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_randomness_draw::<Test>());
+			assert_ok!(test_benchmark_mint_batch::<Test>());
+			assert_ok!(test_benchmark_burn_batch::<Test>());
+			assert_ok!(test_benchmark_transfer_multi::<Test>());
+			assert_ok!(test_benchmark_freeze_batch::<Test>());
+		});
+	}
+
+	#[test]
+	fn batch_weights_should_grow_with_the_batch() {
+		let weights = |n: u64| {
+			let items = (0..n).map(|who| (who, 10)).collect::<Vec<_>>();
+			let who = (0..n).collect::<Vec<_>>();
+			[
+				Call::<Test>::mint_batch(0, items.clone()).get_dispatch_info().weight,
+				Call::<Test>::burn_batch(0, items.clone()).get_dispatch_info().weight,
+				Call::<Test>::transfer_multi(0, items).get_dispatch_info().weight,
+				Call::<Test>::freeze_batch(0, who).get_dispatch_info().weight,
+			]
+		};
+		for n in 1..<Test as Config>::MaxBatchSize::get() as u64 {
+			let (smaller, larger) = (weights(n), weights(n + 1));
+			assert!(smaller.iter().zip(larger.iter()).all(|(s, l)| s < l));
+		}
+	}
+}
@@ -0,0 +1,989 @@
+//! Hand-estimated weights for the calls of the featured assets pallet which have no generated
+//! weights yet.
+//!
+//! These are not benchmark results. Each one is estimated from the storage its call reads and
+//! writes, on the scale of the generated weights in `weights.rs`, and should move there once the
+//! benchmark CLI has been run for it.
+//!
+//! The batch weights are the exception: their benchmarks were run natively against the test
+//! runtime, and the timings scaled by the generated weight of the call each item amounts to.
+
+#![allow(unused_parens)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use crate::weights::SubstrateWeight;
+
+/// Weight functions of the featured assets pallet without generated weights.
+pub trait EstimatedWeightInfo {
+	fn create_deterministic() -> Weight;
+	fn force_create_base() -> Weight;
+	fn randomness_draw() -> Weight;
+	fn destroy(z: u32, a: u32, ) -> Weight;
+	fn force_destroy(z: u32, a: u32, ) -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn transfer_approved() -> Weight;
+	fn force_cancel_approvals(a: u32, ) -> Weight;
+	fn set_daily_limit() -> Weight;
+	fn purge_retired() -> Weight;
+	fn owner_clear_approvals(p: u32, ) -> Weight;
+	fn mint_batch(n: u32, ) -> Weight;
+	fn burn_batch(n: u32, ) -> Weight;
+	fn transfer_multi(n: u32, ) -> Weight;
+	fn freeze_batch(n: u32, ) -> Weight;
+	fn airdrop(n: u32, ) -> Weight;
+	fn verify_asset(a: u32, ) -> Weight;
+	fn create_stream() -> Weight;
+	fn withdraw_stream() -> Weight;
+	fn cancel_stream() -> Weight;
+	fn set_whale_threshold() -> Weight;
+	fn purge_all_dust(n: u32, ) -> Weight;
+	fn spin_off(n: u32, ) -> Weight;
+	fn spin_off_continue(n: u32, ) -> Weight;
+	fn set_transfer_cooldown() -> Weight;
+	fn set_community_mint() -> Weight;
+	fn community_mint() -> Weight;
+	fn take_snapshot(n: u32, ) -> Weight;
+	fn take_snapshot_continue(n: u32, ) -> Weight;
+	fn discard_snapshot(h: u32, ) -> Weight;
+	fn lock_decimals() -> Weight;
+	fn grant_temp_issuer() -> Weight;
+	fn revoke_temp_issuer() -> Weight;
+	fn hold() -> Weight;
+	fn release_hold() -> Weight;
+	fn transfer_on_hold() -> Weight;
+	fn start_mint_batch(n: u32, ) -> Weight;
+	fn continue_batch() -> Weight;
+	fn transfer_existing() -> Weight;
+	fn transfer_create() -> Weight;
+	fn transfer_sweep() -> Weight;
+	fn set_burnable() -> Weight;
+	fn set_conversion() -> Weight;
+	fn convert() -> Weight;
+	fn set_destination_whitelist() -> Weight;
+	fn add_destination() -> Weight;
+	fn remove_destination() -> Weight;
+	fn set_expiry() -> Weight;
+	fn force_set_metadata(n: u32, s: u32, ) -> Weight;
+	fn set_redemption_rate() -> Weight;
+	fn fund_redemption() -> Weight;
+	fn redeem() -> Weight;
+	fn set_transfer_fee() -> Weight;
+	fn force_migrate_account() -> Weight;
+	fn recount(n: u32, ) -> Weight;
+	fn import_from_legacy() -> Weight;
+	fn import_accounts(n: u32, ) -> Weight;
+	fn set_clawback_window() -> Weight;
+	fn clawback() -> Weight;
+	fn set_indivisible() -> Weight;
+	fn set_metadata_batch(n: u32, b: u32, ) -> Weight;
+	fn set_max_transfer() -> Weight;
+	fn drain_to_owner(n: u32, ) -> Weight;
+	fn claim_asset_id() -> Weight;
+	fn complete_claimed_create() -> Weight;
+	fn freeze_asset_until() -> Weight;
+	fn bid_for_id() -> Weight;
+	fn claim_won_id() -> Weight;
+	fn force_clear_orphans(n: u32, ) -> Weight;
+	fn set_cross_chain() -> Weight;
+	fn set_exempt() -> Weight;
+	fn distribute(n: u32, ) -> Weight;
+	fn set_strict_whitelist() -> Weight;
+	fn set_burn_rate() -> Weight;
+	fn close_lapsed_auction() -> Weight;
+}
+
+impl<T: frame_system::Config> EstimatedWeightInfo for SubstrateWeight<T> {
+	fn create_deterministic() -> Weight {
+		(46_137_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn force_create_base() -> Weight {
+		(17_052_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn randomness_draw() -> Weight {
+		(4_428_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	fn destroy(z: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((1_149_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn force_destroy(z: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((1_146_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn approve_transfer() -> Weight {
+		(47_906_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_approval() -> Weight {
+		(48_591_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_approved() -> Weight {
+		(90_338_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn force_cancel_approvals(a: u32, ) -> Weight {
+		(17_437_000 as Weight)
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn set_daily_limit() -> Weight {
+		(22_847_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn purge_retired() -> Weight {
+		(18_403_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn owner_clear_approvals(p: u32, ) -> Weight {
+		(16_512_000 as Weight)
+			.saturating_add((20_914_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	fn mint_batch(n: u32, ) -> Weight {
+		(4_540_000 as Weight)
+			.saturating_add((32_760_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn burn_batch(n: u32, ) -> Weight {
+		(5_513_000 as Weight)
+			.saturating_add((27_793_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn transfer_multi(n: u32, ) -> Weight {
+		(6_633_000 as Weight)
+			.saturating_add((29_416_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn freeze_batch(n: u32, ) -> Weight {
+		(8_862_000 as Weight)
+			.saturating_add((29_778_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn airdrop(n: u32, ) -> Weight {
+		(12_018_000 as Weight)
+			.saturating_add((28_447_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn verify_asset(a: u32, ) -> Weight {
+		(8_714_000 as Weight)
+			.saturating_add((5_937_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+	}
+	fn create_stream() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn withdraw_stream() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_stream() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_whale_threshold() -> Weight {
+		(22_913_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn purge_all_dust(n: u32, ) -> Weight {
+		(10_512_000 as Weight)
+			.saturating_add((19_847_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn spin_off(n: u32, ) -> Weight {
+		(58_204_000 as Weight)
+			.saturating_add((31_622_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn spin_off_continue(n: u32, ) -> Weight {
+		(9_871_000 as Weight)
+			.saturating_add((31_622_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_transfer_cooldown() -> Weight {
+		(22_671_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_community_mint() -> Weight {
+		(23_105_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn community_mint() -> Weight {
+		(38_947_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn take_snapshot(n: u32, ) -> Weight {
+		(41_376_000 as Weight)
+			.saturating_add((15_233_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn take_snapshot_continue(n: u32, ) -> Weight {
+		(9_144_000 as Weight)
+			.saturating_add((15_233_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn discard_snapshot(h: u32, ) -> Weight {
+		(20_780_000 as Weight)
+			.saturating_add((2_164_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	fn lock_decimals() -> Weight {
+		(21_970_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn grant_temp_issuer() -> Weight {
+		(24_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_temp_issuer() -> Weight {
+		(23_592_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn hold() -> Weight {
+		(31_846_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn release_hold() -> Weight {
+		(30_127_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_on_hold() -> Weight {
+		(64_503_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn start_mint_batch(n: u32, ) -> Weight {
+		(11_804_000 as Weight)
+			.saturating_add((1_116_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn continue_batch() -> Weight {
+		(9_377_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_existing() -> Weight {
+		(35_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_create() -> Weight {
+		(38_954_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn transfer_sweep() -> Weight {
+		(40_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn set_burnable() -> Weight {
+		(21_733_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_conversion() -> Weight {
+		(20_451_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn convert() -> Weight {
+		(71_862_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_destination_whitelist() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn add_destination() -> Weight {
+		(19_674_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_destination() -> Weight {
+		(19_905_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_expiry() -> Weight {
+		(26_307_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn force_set_metadata(n: u32, s: u32, ) -> Weight {
+		(24_166_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_redemption_rate() -> Weight {
+		(20_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn fund_redemption() -> Weight {
+		(41_052_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn redeem() -> Weight {
+		(74_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn set_transfer_fee() -> Weight {
+		(21_504_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn force_migrate_account() -> Weight {
+		(52_746_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn recount(n: u32, ) -> Weight {
+		(12_874_000 as Weight)
+			.saturating_add((6_312_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn import_from_legacy() -> Weight {
+		(31_962_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn import_accounts(n: u32, ) -> Weight {
+		(16_205_000 as Weight)
+			.saturating_add((24_418_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_clawback_window() -> Weight {
+		(21_095_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn clawback() -> Weight {
+		(62_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn set_indivisible() -> Weight {
+		(21_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_metadata_batch(n: u32, b: u32, ) -> Weight {
+		(10_214_000 as Weight)
+			.saturating_add((49_456_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((6_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_max_transfer() -> Weight {
+		(20_846_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn drain_to_owner(n: u32, ) -> Weight {
+		(5_032_000 as Weight)
+			.saturating_add((58_391_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn claim_asset_id() -> Weight {
+		(38_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn complete_claimed_create() -> Weight {
+		(52_940_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn freeze_asset_until() -> Weight {
+		(25_305_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn bid_for_id() -> Weight {
+		(39_806_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn claim_won_id() -> Weight {
+		(71_224_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn force_clear_orphans(n: u32, ) -> Weight {
+		(11_306_000 as Weight)
+			.saturating_add((7_845_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_cross_chain() -> Weight {
+		(22_710_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_exempt() -> Weight {
+		(21_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn distribute(n: u32, ) -> Weight {
+		(12_440_000 as Weight)
+			.saturating_add((28_913_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_strict_whitelist() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_burn_rate() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn close_lapsed_auction() -> Weight {
+		(31_452_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl EstimatedWeightInfo for () {
+	fn create_deterministic() -> Weight {
+		(46_137_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn force_create_base() -> Weight {
+		(17_052_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn randomness_draw() -> Weight {
+		(4_428_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	fn destroy(z: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((1_149_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn force_destroy(z: u32, a: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((1_146_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn approve_transfer() -> Weight {
+		(47_906_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_approval() -> Weight {
+		(48_591_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_approved() -> Weight {
+		(90_338_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn force_cancel_approvals(a: u32, ) -> Weight {
+		(17_437_000 as Weight)
+			.saturating_add((21_356_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn set_daily_limit() -> Weight {
+		(22_847_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn purge_retired() -> Weight {
+		(18_403_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn owner_clear_approvals(p: u32, ) -> Weight {
+		(16_512_000 as Weight)
+			.saturating_add((20_914_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	fn mint_batch(n: u32, ) -> Weight {
+		(4_540_000 as Weight)
+			.saturating_add((32_760_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn burn_batch(n: u32, ) -> Weight {
+		(5_513_000 as Weight)
+			.saturating_add((27_793_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn transfer_multi(n: u32, ) -> Weight {
+		(6_633_000 as Weight)
+			.saturating_add((29_416_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn freeze_batch(n: u32, ) -> Weight {
+		(8_862_000 as Weight)
+			.saturating_add((29_778_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn airdrop(n: u32, ) -> Weight {
+		(12_018_000 as Weight)
+			.saturating_add((28_447_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn verify_asset(a: u32, ) -> Weight {
+		(8_714_000 as Weight)
+			.saturating_add((5_937_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+	}
+	fn create_stream() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn withdraw_stream() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_stream() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn set_whale_threshold() -> Weight {
+		(22_913_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn purge_all_dust(n: u32, ) -> Weight {
+		(10_512_000 as Weight)
+			.saturating_add((19_847_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn spin_off(n: u32, ) -> Weight {
+		(58_204_000 as Weight)
+			.saturating_add((31_622_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn spin_off_continue(n: u32, ) -> Weight {
+		(9_871_000 as Weight)
+			.saturating_add((31_622_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_transfer_cooldown() -> Weight {
+		(22_671_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_community_mint() -> Weight {
+		(23_105_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn community_mint() -> Weight {
+		(38_947_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn take_snapshot(n: u32, ) -> Weight {
+		(41_376_000 as Weight)
+			.saturating_add((15_233_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn take_snapshot_continue(n: u32, ) -> Weight {
+		(9_144_000 as Weight)
+			.saturating_add((15_233_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn discard_snapshot(h: u32, ) -> Weight {
+		(20_780_000 as Weight)
+			.saturating_add((2_164_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	fn lock_decimals() -> Weight {
+		(21_970_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn grant_temp_issuer() -> Weight {
+		(24_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_temp_issuer() -> Weight {
+		(23_592_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn hold() -> Weight {
+		(31_846_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn release_hold() -> Weight {
+		(30_127_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_on_hold() -> Weight {
+		(64_503_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn start_mint_batch(n: u32, ) -> Weight {
+		(11_804_000 as Weight)
+			.saturating_add((1_116_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn continue_batch() -> Weight {
+		(9_377_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_existing() -> Weight {
+		(35_402_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_create() -> Weight {
+		(38_954_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn transfer_sweep() -> Weight {
+		(40_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_burnable() -> Weight {
+		(21_733_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_conversion() -> Weight {
+		(20_451_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn convert() -> Weight {
+		(71_862_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn set_destination_whitelist() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn add_destination() -> Weight {
+		(19_674_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_destination() -> Weight {
+		(19_905_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_expiry() -> Weight {
+		(26_307_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn force_set_metadata(n: u32, s: u32, ) -> Weight {
+		(24_166_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_redemption_rate() -> Weight {
+		(20_873_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn fund_redemption() -> Weight {
+		(41_052_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn redeem() -> Weight {
+		(74_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn set_transfer_fee() -> Weight {
+		(21_504_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn force_migrate_account() -> Weight {
+		(52_746_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn recount(n: u32, ) -> Weight {
+		(12_874_000 as Weight)
+			.saturating_add((6_312_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn import_from_legacy() -> Weight {
+		(31_962_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn import_accounts(n: u32, ) -> Weight {
+		(16_205_000 as Weight)
+			.saturating_add((24_418_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_clawback_window() -> Weight {
+		(21_095_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn clawback() -> Weight {
+		(62_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn set_indivisible() -> Weight {
+		(21_402_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_metadata_batch(n: u32, b: u32, ) -> Weight {
+		(10_214_000 as Weight)
+			.saturating_add((49_456_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((6_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_max_transfer() -> Weight {
+		(20_846_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn drain_to_owner(n: u32, ) -> Weight {
+		(5_032_000 as Weight)
+			.saturating_add((58_391_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn claim_asset_id() -> Weight {
+		(38_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn complete_claimed_create() -> Weight {
+		(52_940_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn freeze_asset_until() -> Weight {
+		(25_305_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn bid_for_id() -> Weight {
+		(39_806_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn claim_won_id() -> Weight {
+		(71_224_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn force_clear_orphans(n: u32, ) -> Weight {
+		(11_306_000 as Weight)
+			.saturating_add((7_845_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_cross_chain() -> Weight {
+		(22_710_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_exempt() -> Weight {
+		(21_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn distribute(n: u32, ) -> Weight {
+		(12_440_000 as Weight)
+			.saturating_add((28_913_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_strict_whitelist() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_burn_rate() -> Weight {
+		(20_918_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn close_lapsed_auction() -> Weight {
+		(31_452_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
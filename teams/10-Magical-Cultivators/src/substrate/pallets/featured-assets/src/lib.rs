@@ -73,12 +73,18 @@
 //! ### Permissionless Functions
 //!
 //! * `create`: Creates a new asset class, taking the required deposit.
+//! * `create_deterministic`: Creates a new asset class whose identifier is derived from the
+//!   creator and a salt, taking the required deposit.
 //! * `transfer`: Transfer sender's assets to another account.
+//! * `approve_transfer`: Allow a delegate to transfer some of the sender's assets.
+//! * `cancel_approval`: Revoke an approval granted to a delegate, returning its deposit.
+//! * `transfer_approved`: Transfer assets of another account within an approved amount.
 //!
 //! ### Permissioned Functions
 //!
 //! * `force_create`: Creates a new asset class without taking any deposit.
 //! * `force_destroy`: Destroys an asset class.
+//! * `force_cancel_approvals`: Cancels outstanding approvals of an asset class.
 //!
 //! ### Privileged Functions
 //! * `destroy`: Destroys an entire asset class; called by the asset class's Owner.
@@ -112,35 +118,66 @@
 mod tests;
 
 pub mod weights;
+pub mod estimated_weights;
+pub mod migrations;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_helpers;
 
-use sp_std::{fmt::Debug, prelude::*};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 use sp_runtime::{
-	RuntimeDebug,
+	RuntimeDebug, ModuleId, Permill, helpers_128bit::multiply_by_rational,
 	traits::{
-		AtLeast32BitUnsigned, Zero, StaticLookup, Saturating, CheckedSub, CheckedAdd,
+		AtLeast32BitUnsigned, Zero, StaticLookup, Saturating, CheckedSub, CheckedAdd, Hash,
+		TrailingZeroInput, AccountIdConversion, SaturatedConversion, Convert, CheckedMul,
 	}
 };
 use codec::{Encode, Decode, HasCompact};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 use frame_support::{
-	ensure,
-	traits::{Currency, ReservableCurrency, BalanceStatus::Reserved},
-	dispatch::DispatchError,
+	ensure, sp_io, Blake2_128Concat, StorageHasher, ReversibleStorageHasher,
+	storage::{StoragePrefixedMap, with_transaction, TransactionOutcome},
+	traits::{
+		Currency, ReservableCurrency, Get, OnUnbalanced, ExistenceRequirement, BalanceStatus::Reserved,
+		Filter,
+	},
+	dispatch::DispatchError, weights::Weight,
 };
 use mc_support::{
-	primitives::{FeatureElements, FeatureLevel, FeatureDestinyRank, FeatureRankedLevel},
-	traits::{ManagerAccessor, RandomNumber},
+	primitives::{FeatureElements, FeatureLevel, FeatureRankedLevel},
+	traits::{
+		ManagerAccessor, RandomNumber, AssetIdDeriver, TransferPolicy, FeatureRoller, AssetRewarder,
+		AssetIdDisplay, OnAssetDestroyed, FeeRouter, OnAccountTouched, CrossChainNotifier,
+	},
 };
 
 pub use weights::WeightInfo;
+pub use estimated_weights::EstimatedWeightInfo;
+pub use mc_support::primitives::FeatureDestinyRank;
 pub use pallet::*;
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+/// The identifier, name, symbol and decimals of an asset, as given to `set_metadata_batch`.
+type MetadataItemOf<T> = (<T as Config>::AssetId, Vec<u8>, Vec<u8>, u8);
+type AssetBalanceOf<T> = AssetBalance<
+	<T as Config>::Balance,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::AccountExtra,
+>;
+
+/// The hold reason under which deposits are held when they are taken in `DepositAsset`.
+pub const DEPOSIT_HOLD_REASON: u8 = u8::MAX;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
+		transactional,
 	};
 	use frame_system::pallet_prelude::*;
 	use super::*;
@@ -156,10 +193,11 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The units in which we record balances.
-		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
+		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy
+			+ MaybeSerializeDeserialize;
 
 		/// The arithmetic type of asset identifier.
-		type AssetId: Member + Parameter + Default + Copy + HasCompact;
+		type AssetId: Member + Parameter + Default + Copy + HasCompact + MaybeSerializeDeserialize;
 
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -174,9 +212,15 @@ pub mod pallet {
 		/// supports.
 		type AssetDepositPerZombie: Get<BalanceOf<Self>>;
 
+		/// The amount of funds that must be reserved when creating a new transfer approval.
+		type ApprovalDeposit: Get<BalanceOf<Self>>;
+
 		/// The maximum length of a name or symbol stored on-chain.
 		type StringLimit: Get<u32>;
 
+		/// The maximum combined length of the name and symbol of an asset stored on-chain.
+		type MaxTotalMetadataBytes: Get<u32>;
+
 		/// The basic amount of funds that must be reserved when adding metadata to your asset.
 		type MetadataDepositBase: Get<BalanceOf<Self>>;
 
@@ -192,10 +236,142 @@ pub mod pallet {
 
 		/// Something that provides randomness in the runtime.
 		type RandomNumber: RandomNumber<u32>;
+
+		/// Derives the asset identifier used by `create_deterministic`.
+		type AssetIdDeriver: AssetIdDeriver<Self::AccountId, Self::AssetId>;
+
+		/// External policy consulted before any transfer takes place.
+		type TransferPolicy: TransferPolicy<Self::AccountId, Self::AssetId, Self::Balance>;
+
+		/// The number of blocks in a transfer rate-limiting window.
+		type RateLimitWindow: Get<Self::BlockNumber>;
+
+		/// Whether the id of a destroyed asset may be used again to create a new asset.
+		type ReusableAssetIds: Get<bool>;
+
+		/// The maximum number of items that may be passed to a single batched call.
+		type MaxBatchSize: Get<u32>;
+
+		/// Maps the random entropy drawn for a new asset to its feature code.
+		type FeatureRoller: FeatureRoller;
+
+		/// The share of the asset and metadata deposits returned to the owner when an asset is
+		/// destroyed.
+		type DestroyRefundRatio: Get<Permill>;
+
+		/// Where the share of the deposits not returned on destroy goes.
+		type DestroyFeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The largest `min_balance` an asset may be created with.
+		type MaxMinBalance: Get<Self::Balance>;
+
+		/// Maps asset identifiers to and from 20-byte, Ethereum style addresses.
+		type AssetIdConverter: Convert<Self::AssetId, [u8; 20]> + Convert<[u8; 20], Option<Self::AssetId>>;
+
+		/// The origin which may place funds on hold and settle them.
+		type HoldOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The most weight a resumable batch may spend on its items in a single call.
+		type BatchWeightLimit: Get<Weight>;
+
+		/// The asset in which asset and metadata deposits are held, or `None` to reserve them in
+		/// `Currency`. Deposits for the deposit asset itself are always reserved in `Currency`.
+		type DepositAsset: Get<Option<Self::AssetId>>;
+
+		/// The number of blocks between two supply reports, or zero to never report.
+		type SupplyReportPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of assets included in a single supply report.
+		type MaxSupplyReportAssets: Get<u32>;
+
+		/// How asset identifiers are presented to clients as text.
+		type AssetIdDisplay: AssetIdDisplay<Self::AssetId>;
+
+		/// The number of blocks an account must have existed for before its dust may be purged.
+		type MinAccountLifetime: Get<Self::BlockNumber>;
+
+		/// Called when an asset is destroyed, before its storage is removed, so dependent state can
+		/// be cleaned up.
+		type OnDestroy: OnAssetDestroyed<Self::AssetId>;
+
+		/// Whether every `Transferred` event is followed by a `TransferredHuman` event giving the
+		/// amount split by the decimals of the asset.
+		type EmitScaledAmounts: Get<bool>;
+
+		/// The amount of funds that must be reserved to claim an asset id ahead of creating it.
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks for which a claimed asset id stays reserved to its claimant.
+		type ClaimPeriod: Get<Self::BlockNumber>;
+
+		/// Where the transfer fees of an asset go. Any part of a fee left unrouted goes to the
+		/// Owner.
+		type FeeRouter: FeeRouter<Self::AccountId, Self::AssetId, Self::Balance>;
+
+		/// The most shares `FeeRouter` may split a transfer fee into. The part of the fee routed by
+		/// any further shares goes to the Owner.
+		type MaxFeeShares: Get<u32>;
+
+		/// Extra data kept with every account holding an asset, maintained by `OnAccountTouched`.
+		type AccountExtra: Member + Parameter + Default;
+
+		/// Called whenever the balance of an account changes and the account remains, so its extra
+		/// data can be updated.
+		type OnAccountTouched: OnAccountTouched<
+			Self::AssetId,
+			Self::AccountId,
+			Self::Balance,
+			Self::AccountExtra,
+		>;
+
+		/// The asset ids which may only be created by winning an auction for them.
+		type PremiumAssetIds: Filter<Self::AssetId>;
+
+		/// The number of blocks for which an auction for a premium asset id takes bids.
+		type IdAuctionDuration: Get<Self::BlockNumber>;
+
+		/// The number of blocks after an auction for a premium asset id ends in which its winner
+		/// may claim the id. The auction may be closed and the bid returned once it has passed.
+		type IdAuctionClaimPeriod: Get<Self::BlockNumber>;
+
+		/// Where the winning bids of asset id auctions go.
+		type IdAuctionProceeds: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Told about every transfer and burn of the assets flagged as cross-chain, so the movement
+		/// can be mirrored on other chains.
+		type CrossChainNotifier: CrossChainNotifier<Self::AccountId, Self::AssetId, Self::Balance>;
+
+		/// Whether the latest activity of each asset is kept in `RecentActivity` for activity feeds.
+		type RecordActivity: Get<bool>;
+
+		/// The number of entries kept in `RecentActivity` for each asset, the oldest being dropped
+		/// first.
+		type MaxRecentActivity: Get<u32>;
+
+		/// The well-known account which transfers burn rather than credit, if any.
+		type BurnAddress: Get<Option<Self::AccountId>>;
+
+		/// The highest share of each transfer, in basis points, an asset may burn.
+		type MaxBurnRateBps: Get<u16>;
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expiring = Expiries::<T>::take(now);
+			for &id in expiring.iter() {
+				if Asset::<T>::get(id).map(|d| d.expires_at) == Some(Some(now)) {
+					Self::deposit_event(Event::AssetExpired(id));
+				}
+			}
+			T::DbWeight::get().reads_writes(1 + expiring.len() as Weight, 1)
+				.saturating_add(Self::supply_heartbeat(now))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -218,6 +394,8 @@ pub mod pallet {
 		/// have no existential deposit.
 		/// - `min_balance`: The minimum balance of this new asset that any single account must
 		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `existence_model`: How accounts holding this asset are kept in existence. Only the
+		/// `Zombie` model may have a non-zero `max_zombies`.
 		///
 		/// Emits `Created` event when successful.
 		///
@@ -229,33 +407,65 @@ pub mod pallet {
 			max_zombies: u32,
 			min_balance: T::Balance,
 			feature_code: u32,
+			existence_model: ExistenceModel,
 		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
 
-			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
-			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
-			ensure!(!feature_code.is_zero(), Error::<T>::BadFeaturePoint);
+			Self::do_create(
+				&owner,
+				id,
+				max_zombies,
+				min_balance,
+				feature_code,
+				existence_model,
+				false,
+			)?;
 
-			let deposit = T::AssetDepositPerZombie::get()
-				.saturating_mul(max_zombies.into())
-			 	.saturating_add(T::AssetDepositBase::get());
-			T::Currency::reserve(&owner, deposit)?;
+			Self::deposit_event(Event::Created(id, owner));
+			Ok(().into())
+		}
 
-			Asset::<T>::insert(id, AssetDetails {
-				owner: owner.clone(),
-				supply: Zero::zero(),
-				deposit,
+		/// Issue a new class of fungible assets from a public origin, deriving its identifier
+		/// from the sender and a salt.
+		///
+		/// This behaves exactly like `create`, except that the asset identifier is computed by
+		/// `T::AssetIdDeriver` from `(owner, salt)` instead of being chosen by the caller, so two
+		/// creators can never race for the same identifier.
+		///
+		/// - `salt`: Arbitrary bytes chosen by the sender. The same sender may not reuse a salt
+		/// while the asset derived from it still exists.
+		/// - `max_zombies`: The total number of accounts which may hold assets in this class yet
+		/// have no existential deposit.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `existence_model`: How accounts holding this asset are kept in existence.
+		///
+		/// Emits `DeterministicCreated` event with the derived identifier when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_deterministic())]
+		pub(super) fn create_deterministic(
+			origin: OriginFor<T>,
+			salt: [u8; 32],
+			max_zombies: u32,
+			min_balance: T::Balance,
+			feature_code: u32,
+			existence_model: ExistenceModel,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = T::AssetIdDeriver::derive_asset_id(&owner, &salt);
+
+			Self::do_create(
+				&owner,
+				id,
 				max_zombies,
 				min_balance,
-				zombies: Zero::zero(),
-				accounts: Zero::zero(),
-				is_frozen: false,
-				is_featured: true
-			});
-			// add feature info
-			Feature::<T>::insert(id, Self::new_feature_detail(feature_code));
+				feature_code,
+				existence_model,
+				false,
+			)?;
 
-			Self::deposit_event(Event::Created(id, owner));
+			Self::deposit_event(Event::DeterministicCreated(id, owner, salt));
 			Ok(().into())
 		}
 
@@ -276,24 +486,37 @@ pub mod pallet {
 		/// have no existential deposit.
 		/// - `min_balance`: The minimum balance of this new asset that any single account must
 		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `existence_model`: How accounts holding this asset are kept in existence. Only the
+		/// `Zombie` model may have a non-zero `max_zombies`.
 		///
 		/// Emits `ForceCreated` event when successful.
 		///
-		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_create())]
+		/// Weight: `O(1)`, plus drawing the random number the feature is rolled from
+		#[pallet::weight(
+			T::WeightInfo::force_create_base().saturating_add(T::WeightInfo::randomness_draw())
+		)]
 		pub(super) fn force_create(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
 			owner: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] max_zombies: u32,
 			#[pallet::compact] min_balance: T::Balance,
+			existence_model: ExistenceModel,
 		) -> DispatchResultWithPostInfo {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
 
 			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!RetiredAssets::<T>::contains_key(id), Error::<T>::AssetRetired);
 			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+			ensure!(min_balance <= T::MaxMinBalance::get(), Error::<T>::MinBalanceTooHigh);
+			ensure!(
+				max_zombies.is_zero() || existence_model == ExistenceModel::Zombie,
+				Error::<T>::ZombiesNotAllowed,
+			);
 
+			OwnerAssets::<T>::insert(&owner, id, ());
+			CreatorAssets::<T>::insert(&owner, id, ());
 			Asset::<T>::insert(id, AssetDetails {
 				owner: owner.clone(),
 				supply: Zero::zero(),
@@ -302,12 +525,31 @@ pub mod pallet {
 				min_balance,
 				zombies: Zero::zero(),
 				accounts: Zero::zero(),
+				approvals: Zero::zero(),
 				is_frozen: false,
 				is_featured: true,
+				daily_limit: None,
+				existence_model,
+				whale_threshold: None,
+				transfer_cooldown: None,
+				community_mint: None,
+				decimals_locked: false,
+				burnable: true,
+				destination_whitelist: false,
+				expires_at: None,
+				freeze_reason: 0,
+				clawback_window: None,
+				indivisible: false,
+				max_transfer: None,
+				frozen_until: None,
+				cross_chain: false,
+				strict_whitelist: false,
+				burn_rate_bps: 0,
+				creator: owner.clone(),
 			});
 			let rand_value = T::RandomNumber::generate_random(0);
 			// add feature info
-			Feature::<T>::insert(id, Self::new_feature_detail(rand_value));
+			Self::insert_feature(id, Self::new_feature_detail(T::FeatureRoller::roll(rand_value)));
 
 			Self::deposit_event(Event::ForceCreated(id, owner));
 			Ok(().into())
@@ -319,32 +561,27 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		/// asset.
+		/// - `witness`: The number of zombie accounts and approvals of the asset. Must be no
+		/// smaller than the actual counts.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// Any outstanding approvals are cancelled and their deposits returned.
 		///
-		/// Weight: `O(z)` where `z` is the number of zombie accounts.
-		#[pallet::weight(T::WeightInfo::destroy(*zombies_witness))]
+		/// Emits `Destroyed` event when successful, and `ApprovalCancelled` for every cancelled
+		/// approval.
+		///
+		/// Weight: `O(z + a)` where `z` is the number of zombie accounts and `a` the number of
+		/// approvals.
+		#[pallet::weight(T::WeightInfo::destroy(witness.zombies, witness.approvals))]
+		#[transactional]
 		pub(super) fn destroy(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			#[pallet::compact] zombies_witness: u32,
+			witness: DestroyWitness,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
-			Asset::<T>::try_mutate_exists(id, |maybe_details| {
-				let details = maybe_details.take().ok_or(Error::<T>::Unknown)?;
-				ensure!(details.owner == origin, Error::<T>::NoPermission);
-				ensure!(details.accounts == details.zombies, Error::<T>::RefsLeft);
-				ensure!(details.zombies <= zombies_witness, Error::<T>::BadWitness);
-
-				let metadata = Metadata::<T>::take(&id);
-				T::Currency::unreserve(&details.owner, details.deposit.saturating_add(metadata.deposit));
-
-				*maybe_details = None;
-				Account::<T>::remove_prefix(&id);
-				Self::deposit_event(Event::Destroyed(id));
-				Ok(().into())
-			})
+			Self::do_destroy(id, witness, Some(origin))?;
+			Ok(().into())
 		}
 
 		/// Destroy a class of fungible assets.
@@ -353,36 +590,33 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		/// asset.
+		/// - `witness`: The number of zombie accounts and approvals of the asset. Must be no
+		/// smaller than the actual counts.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// Any outstanding approvals are cancelled and their deposits returned.
 		///
-		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_destroy(*zombies_witness))]
+		/// Emits `Destroyed` event when successful, and `ApprovalCancelled` for every cancelled
+		/// approval.
+		///
+		/// Weight: `O(z + a)` where `z` is the number of zombie accounts and `a` the number of
+		/// approvals.
+		#[pallet::weight(T::WeightInfo::force_destroy(witness.zombies, witness.approvals))]
+		#[transactional]
 		pub(super) fn force_destroy(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			#[pallet::compact] zombies_witness: u32,
+			witness: DestroyWitness,
 		) -> DispatchResultWithPostInfo {
 			T::ForceOrigin::ensure_origin(origin)?;
 
-			Asset::<T>::try_mutate_exists(id, |maybe_details| {
-				let details = maybe_details.take().ok_or(Error::<T>::Unknown)?;
-				ensure!(details.accounts == details.zombies, Error::<T>::RefsLeft);
-				ensure!(details.zombies <= zombies_witness, Error::<T>::BadWitness);
-
-				let metadata = Metadata::<T>::take(&id);
-				T::Currency::unreserve(&details.owner, details.deposit.saturating_add(metadata.deposit));
-
-				*maybe_details = None;
-				Account::<T>::remove_prefix(&id);
-				Self::deposit_event(Event::Destroyed(id));
-				Ok(().into())
-			})
+			Self::do_destroy(id, witness, None)?;
+			Ok(().into())
 		}
 
 		/// Mint assets of a particular class.
 		///
-		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`, or a
+		/// temporary issuer of it within its window and cap.
 		///
 		/// - `id`: The identifier of the asset to have some amount minted.
 		/// - `beneficiary`: The account to be credited with the minted assets.
@@ -402,31 +636,56 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 
-			Asset::<T>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			if T::AssetAdmin::is_issuer(&origin) {
+				Self::do_mint(id, beneficiary, amount)?;
+				return Ok(().into())
+			}
 
-				ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
-				details.supply = details.supply.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			TempIssuers::<T>::try_mutate(id, &origin, |maybe_issuer| {
+				let issuer = maybe_issuer.as_mut().ok_or(Error::<T>::NoPermission)?;
+				let now = frame_system::Module::<T>::block_number();
+				ensure!(now <= issuer.until, Error::<T>::NoPermission);
+				let minted = issuer.minted.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+				ensure!(minted <= issuer.cap, Error::<T>::TempIssuerCapExceeded);
 
-				Account::<T>::try_mutate(id, &beneficiary, |t| -> DispatchResultWithPostInfo {
-					let new_balance = t.balance.saturating_add(amount);
-					ensure!(new_balance >= details.min_balance, Error::<T>::BalanceLow);
-					if t.balance.is_zero() {
-						t.is_zombie = Self::new_account(&beneficiary, details)?;
-					}
-					t.balance = new_balance;
-					Ok(().into())
-				})?;
-				Self::deposit_event(Event::Issued(id, beneficiary, amount));
+				Self::do_mint(id, beneficiary, amount)?;
+				issuer.minted = minted;
 				Ok(().into())
 			})
 		}
 
+		/// Mint assets of a particular class to the current Owner of the asset.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `amount`: The amount of the asset to be minted.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existing balance of the Owner; Account pre-existence of the Owner.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub(super) fn mint_to_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] amount: T::Balance
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let owner = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?.owner;
+			ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
+			Self::do_mint(id, owner, amount)?;
+			Ok(().into())
+		}
+
 		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
 		///
 		/// Origin must be Signed and the sender should be the Manager of the asset `id`.
 		///
-		/// Bails with `BalanceZero` if the `who` is already dead.
+		/// Bails with `BalanceZero` if the `who` is already dead, and with `BurnDisabled` if the
+		/// asset is not burnable.
 		///
 		/// - `id`: The identifier of the asset to have some amount burned.
 		/// - `who`: The account to be debited from.
@@ -447,33 +706,11 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let who = T::Lookup::lookup(who)?;
 
-			Asset::<T>::try_mutate(id, |maybe_details| {
-				let d = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
-
-				let burned = Account::<T>::try_mutate_exists(
-					id,
-					&who,
-					|maybe_account| -> Result<T::Balance, DispatchError> {
-						let mut account = maybe_account.take().ok_or(Error::<T>::BalanceZero)?;
-						let mut burned = amount.min(account.balance);
-						account.balance -= burned;
-						*maybe_account = if account.balance < d.min_balance {
-							burned += account.balance;
-							Self::dead_account(&who, d, account.is_zombie);
-							None
-						} else {
-							Some(account)
-						};
-						Ok(burned)
-					}
-				)?;
-
-				d.supply = d.supply.saturating_sub(burned);
-
-				Self::deposit_event(Event::Burned(id, who, burned));
-				Ok(().into())
-			})
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+			ensure!(details.burnable, Error::<T>::BurnDisabled);
+			Self::do_burn(id, who, amount)?;
+			Ok(().into())
 		}
 
 		/// Move some assets from the sender account to another.
@@ -491,10 +728,20 @@ pub mod pallet {
 		/// to below the minimum for the asset, then the amount transferred is increased to take it
 		/// to zero.
 		///
-		/// Weight: `O(1)`
+		/// If `target` is the `BurnAddress`, the amount is burned instead and `Burned` is emitted.
+		/// Otherwise the burn rate of the asset, if any, is burned out of the amount first.
+		///
+		/// Weight: `O(1)`, plus a burn and paying the transfer fee, refunded according to whether
+		/// the sender was reaped, whether `target` was created, whether a share of the amount was
+		/// burned and whether the asset charges a transfer fee.
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Prior & post zombie-status
 		/// of sender; Account pre-existence of `target`.
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(
+			T::WeightInfo::transfer()
+				.saturating_add(T::WeightInfo::burn())
+				.saturating_add(Pallet::<T>::transfer_fee_weight())
+		)]
+		#[transactional]
 		pub(super) fn transfer(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
@@ -502,58 +749,28 @@ pub mod pallet {
 			#[pallet::compact] amount: T::Balance
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
-			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
-
-			let mut origin_account = Account::<T>::get(id, &origin);
-			ensure!(!origin_account.is_frozen, Error::<T>::Frozen);
-			origin_account.balance = origin_account.balance.checked_sub(&amount)
-				.ok_or(Error::<T>::BalanceLow)?;
-
 			let dest = T::Lookup::lookup(target)?;
-			Asset::<T>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-				ensure!(!details.is_frozen, Error::<T>::Frozen);
-
-				if dest == origin {
-					return Ok(().into())
-				}
-
-				let mut amount = amount;
-				if origin_account.balance < details.min_balance {
-					amount += origin_account.balance;
-					origin_account.balance = Zero::zero();
-				}
-
-				Account::<T>::try_mutate(id, &dest, |a| -> DispatchResultWithPostInfo {
-					let new_balance = a.balance.saturating_add(amount);
-					ensure!(new_balance >= details.min_balance, Error::<T>::BalanceLow);
-					if a.balance.is_zero() {
-						a.is_zombie = Self::new_account(&dest, details)?;
-					}
-					a.balance = new_balance;
-					Ok(().into())
-				})?;
-
-				match origin_account.balance.is_zero() {
-					false => {
-						Self::dezombify(&origin, details, &mut origin_account.is_zombie);
-						Account::<T>::insert(id, &origin, &origin_account)
-					}
-					true => {
-						Self::dead_account(&origin, details, origin_account.is_zombie);
-						Account::<T>::remove(id, &origin);
-					}
-				}
 
-				Self::deposit_event(Event::Transferred(id, origin, dest, amount));
-				Ok(().into())
-			})
+			let created = !Account::<T>::contains_key(id, &dest);
+			let burned = !Self::transfer_burn(id, amount).is_zero();
+			Self::do_plain_transfer(id, &origin, &dest, amount)?;
+			let reaped = !Account::<T>::contains_key(id, &origin);
+			let mut weight = Self::transfer_weight(reaped, created);
+			if burned {
+				weight = weight.saturating_add(T::WeightInfo::burn());
+			}
+			if TransferFees::<T>::contains_key(id) {
+				weight = weight.saturating_add(Self::transfer_fee_weight());
+			}
+			Ok(Some(weight).into())
 		}
 
 		/// Move some assets from one account to another.
 		///
 		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
 		///
+		/// Bails with `BalanceZero` if the `source` holds none of the asset.
+		///
 		/// - `id`: The identifier of the asset to have some amount transferred.
 		/// - `source`: The account to be debited.
 		/// - `dest`: The account to be credited.
@@ -578,51 +795,12 @@ pub mod pallet {
 			#[pallet::compact] amount: T::Balance,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
 
 			let source = T::Lookup::lookup(source)?;
-			let mut source_account = Account::<T>::get(id, &source);
-			let mut amount = amount.min(source_account.balance);
-			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
-
 			let dest = T::Lookup::lookup(dest)?;
-			if dest == source {
-				return Ok(().into())
-			}
-
-			Asset::<T>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
-
-				source_account.balance -= amount;
-				if source_account.balance < details.min_balance {
-					amount += source_account.balance;
-					source_account.balance = Zero::zero();
-				}
-
-				Account::<T>::try_mutate(id, &dest, |a| -> DispatchResultWithPostInfo {
-					let new_balance = a.balance.saturating_add(amount);
-					ensure!(new_balance >= details.min_balance, Error::<T>::BalanceLow);
-					if a.balance.is_zero() {
-						a.is_zombie = Self::new_account(&dest, details)?;
-					}
-					a.balance = new_balance;
-					Ok(().into())
-				})?;
-
-				match source_account.balance.is_zero() {
-					false => {
-						Self::dezombify(&source, details, &mut source_account.is_zombie);
-						Account::<T>::insert(id, &source, &source_account)
-					}
-					true => {
-						Self::dead_account(&source, details, source_account.is_zombie);
-						Account::<T>::remove(id, &source);
-					}
-				}
-
-				Self::deposit_event(Event::ForceTransferred(id, source, dest, amount));
-				Ok(().into())
-			})
+			Self::do_force_transfer(id, source, dest, amount)?;
+			Ok(().into())
 		}
 
 		/// Disallow further unprivileged transfers from an account.
@@ -631,6 +809,7 @@ pub mod pallet {
 		///
 		/// - `id`: The identifier of the asset to be frozen.
 		/// - `who`: The account to be frozen.
+		/// - `reason`: A code recording why the account is frozen, `0` if not given.
 		///
 		/// Emits `Frozen`.
 		///
@@ -639,7 +818,8 @@ pub mod pallet {
 		pub(super) fn freeze(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			who: <T::Lookup as StaticLookup>::Source
+			who: <T::Lookup as StaticLookup>::Source,
+			reason: Option<u8>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
@@ -647,9 +827,14 @@ pub mod pallet {
 			let who = T::Lookup::lookup(who)?;
 			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::BalanceZero);
 
-			Account::<T>::mutate(id, &who, |a| a.is_frozen = true);
+			let reason = reason.unwrap_or_default();
+			Account::<T>::mutate(id, &who, |a| {
+				a.set_frozen(true);
+				a.freeze_reason = reason;
+			});
 
-			Self::deposit_event(Event::<T>::Frozen(id, who));
+			Self::note_activity(id, ActivityKind::Frozen, &who, None);
+			Self::deposit_event(Event::<T>::Frozen(id, who, reason));
 			Ok(().into())
 		}
 
@@ -676,7 +861,10 @@ pub mod pallet {
 			let who = T::Lookup::lookup(who)?;
 			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::BalanceZero);
 
-			Account::<T>::mutate(id, &who, |a| a.is_frozen = false);
+			Account::<T>::mutate(id, &who, |a| {
+				a.set_frozen(false);
+				a.freeze_reason = 0;
+			});
 
 			Self::deposit_event(Event::<T>::Thawed(id, who));
 			Ok(().into())
@@ -687,6 +875,7 @@ pub mod pallet {
 		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
 		///
 		/// - `id`: The identifier of the asset to be frozen.
+		/// - `reason`: A code recording why the asset is frozen, `0` if not given.
 		///
 		/// Emits `Frozen`.
 		///
@@ -694,7 +883,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::freeze_asset())]
 		pub(super) fn freeze_asset(
 			origin: OriginFor<T>,
-			#[pallet::compact] id: T::AssetId
+			#[pallet::compact] id: T::AssetId,
+			reason: Option<u8>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
@@ -703,8 +893,12 @@ pub mod pallet {
 				ensure!(T::AssetAdmin::is_freezer(&origin), Error::<T>::NoPermission);
 
 				d.is_frozen = true;
+				d.freeze_reason = reason.unwrap_or_default();
+				d.frozen_until = None;
+				FrozenAssets::<T>::insert(id, ());
 
-				Self::deposit_event(Event::<T>::AssetFrozen(id));
+				Self::note_activity(id, ActivityKind::Frozen, &origin, None);
+				Self::deposit_event(Event::<T>::AssetFrozen(id, d.freeze_reason));
 				Ok(().into())
 			})
 		}
@@ -730,12 +924,54 @@ pub mod pallet {
 				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
 
 				d.is_frozen = false;
+				d.freeze_reason = 0;
+				d.frozen_until = None;
+				FrozenAssets::<T>::remove(id);
 
 				Self::deposit_event(Event::<T>::AssetThawed(id));
 				Ok(().into())
 			})
 		}
 
+		/// Disallow further unprivileged transfers for the asset class until a given block, after
+		/// which the asset thaws by itself.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// The freeze is lifted, emitting `AssetThawed`, by the first transfer after block `until`.
+		/// It may still be lifted earlier with `thaw_asset`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `until`: The last block during which the asset is frozen.
+		/// - `reason`: A code recording why the asset is frozen, `0` if not given.
+		///
+		/// Emits `AssetFrozenUntil`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_asset_until())]
+		pub(super) fn freeze_asset_until(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			until: T::BlockNumber,
+			reason: Option<u8>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(T::AssetAdmin::is_freezer(&origin), Error::<T>::NoPermission);
+
+				d.is_frozen = true;
+				d.freeze_reason = reason.unwrap_or_default();
+				d.frozen_until = Some(until);
+				FrozenAssets::<T>::insert(id, ());
+
+				Self::note_activity(id, ActivityKind::Frozen, &origin, None);
+				Self::deposit_event(Event::<T>::AssetFrozenUntil(id, d.freeze_reason, until));
+				Ok(().into())
+			})
+		}
+
 		/// Change the Owner of an asset.
 		///
 		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
@@ -747,6 +983,7 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		#[transactional]
 		pub(super) fn transfer_ownership(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
@@ -755,19 +992,32 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
 
-			Asset::<T>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
-				if details.owner == owner { return Ok(().into()) }
-
-				// Move the deposit to the new owner.
-				T::Currency::repatriate_reserved(&details.owner, &owner, details.deposit, Reserved)?;
+			Self::do_transfer_ownership(id, &origin, owner)?;
+			Ok(().into())
+		}
 
-				details.owner = owner.clone();
+		/// Change the Owner of an asset to the account of a pallet, such as a treasury or DAO.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`. The pallet
+		/// account must already exist to receive the asset deposit.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `module_id`: The `ModuleId` of the pallet whose account becomes the new Owner.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		#[transactional]
+		pub(super) fn transfer_ownership_to_pallet(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			module_id: [u8; 8],
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
 
-				Self::deposit_event(Event::OwnerChanged(id, owner));
-				Ok(().into())
-			})
+			Self::do_transfer_ownership(id, &origin, ModuleId(module_id).into_account())?;
+			Ok(().into())
 		}
 
 		/// Set the maximum number of zombie accounts for an asset.
@@ -794,19 +1044,18 @@ pub mod pallet {
 
 			Asset::<T>::try_mutate(id, |maybe_details| {
 				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
-				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
 				ensure!(max_zombies >= details.zombies, Error::<T>::TooManyZombies);
+				ensure!(
+					max_zombies.is_zero() || details.existence_model == ExistenceModel::Zombie,
+					Error::<T>::ZombiesNotAllowed,
+				);
 
-				let new_deposit = T::AssetDepositPerZombie::get()
-					.saturating_mul(max_zombies.into())
-					.saturating_add(T::AssetDepositBase::get());
+				let new_deposit = Self::create_deposit(max_zombies);
 
-				if new_deposit > details.deposit {
-					T::Currency::reserve(&origin, new_deposit - details.deposit)?;
-				} else {
-					T::Currency::unreserve(&origin, details.deposit - new_deposit);
-				}
+				Self::adjust_deposit(id, &origin, details.deposit, new_deposit)?;
 
+				details.deposit = new_deposit;
 				details.max_zombies = max_zombies;
 
 				Self::deposit_event(Event::MaxZombiesChanged(id, max_zombies));
@@ -818,7 +1067,7 @@ pub mod pallet {
 		///
 		/// NOTE: There is no `unset_metadata` call. Simply pass an empty name, symbol,
 		/// and 0 decimals to this function to remove the metadata of an asset and
-		/// return your deposit.
+		/// return your deposit. Locked decimals stay locked, at zero, once removed.
 		///
 		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
 		///
@@ -829,9 +1078,12 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset to update.
 		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
 		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
-		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		/// Together with `name`, limited in length by `MaxTotalMetadataBytes`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit. Once
+		/// non-zero decimals have been set, they can no longer be changed. Ignored in favour of
+		/// zero if the asset is indivisible.
 		///
-		/// Emits `MaxZombiesChanged`.
+		/// Emits `MetadataSet`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32))]
@@ -843,72 +1095,2725 @@ pub mod pallet {
 			decimals: u8,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
+			Self::do_set_metadata(id, &origin, name, symbol, decimals)?;
+			Ok(().into())
+		}
 
-			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
-			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+		/// Approve an amount of asset for transfer by a delegated third-party account.
+		///
+		/// Origin must be Signed.
+		///
+		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
+		/// for the purpose of holding the approval. If some non-zero amount of assets is already
+		/// approved from signing account to `delegate`, then it is topped up and the deposit is
+		/// left untouched.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
+		/// already an approval in place, then this acts additively.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub(super) fn approve_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			Self::lift_lapsed_freeze(id);
 
-			let d = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
-			ensure!(&origin == &d.owner, Error::<T>::NoPermission);
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
 
-			Metadata::<T>::try_mutate_exists(id, |metadata| {
-				let bytes_used = name.len() + symbol.len();
-				let old_deposit = match metadata {
-					Some(m) => m.deposit,
-					None => Default::default()
-				};
+				Approvals::<T>::try_mutate(id, (&owner, &delegate), |maybe_approved| -> DispatchResultWithPostInfo {
+					let mut approved = match maybe_approved.take() {
+						Some(a) => a,
+						None => {
+							let deposit = T::ApprovalDeposit::get();
+							T::Currency::reserve(&owner, deposit)?;
+							details.approvals = details.approvals.checked_add(1).ok_or(Error::<T>::Overflow)?;
+							DelegateApprovals::<T>::insert(&delegate, (id, &owner), ());
+							Approval { amount: Zero::zero(), deposit }
+						}
+					};
+					approved.amount = approved.amount.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+					*maybe_approved = Some(approved);
+					Ok(().into())
+				})?;
 
-				// Metadata is being removed
-				if bytes_used.is_zero() && decimals.is_zero() {
-					T::Currency::unreserve(&origin, old_deposit);
-					*metadata = None;
-				} else {
-					let new_deposit = T::MetadataDepositPerByte::get()
-						.saturating_mul(((name.len() + symbol.len()) as u32).into())
-						.saturating_add(T::MetadataDepositBase::get());
+				Self::deposit_event(Event::ApprovedTransfer(id, owner, delegate, amount));
+				Ok(().into())
+			})
+		}
 
-					if new_deposit > old_deposit {
-						T::Currency::reserve(&origin, new_deposit - old_deposit)?;
-					} else {
-						T::Currency::unreserve(&origin, old_deposit - new_deposit);
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be Signed and there must be an approval in place between signer and
+		/// `delegate`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub(super) fn cancel_approval(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let approval = Approvals::<T>::take(id, (&owner, &delegate)).ok_or(Error::<T>::Unknown)?;
+			DelegateApprovals::<T>::remove(&delegate, (id, &owner));
+			T::Currency::unreserve(&owner, approval.deposit);
+			Asset::<T>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+				details.approvals = details.approvals.saturating_sub(1);
+			});
+
+			Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
+			Ok(().into())
+		}
+
+		/// Transfer some asset balance from a previously delegated account to some third-party
+		/// account.
+		///
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer.
+		///
+		/// If the entire amount approved for transfer is transferred, then any deposit previously
+		/// reserved by `approve_transfer` is unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved for a transfer of at least `amount`
+		/// and from which the asset balance will be withdrawn.
+		/// - `destination`: The account to which the asset balance of `amount` will be
+		/// transferred.
+		/// - `amount`: The amount of assets to transfer.
+		///
+		/// Emits `Transferred` and `TransferredApproved` on success.
+		///
+		/// Weight: `O(1)`, plus paying the transfer fee
+		#[pallet::weight(
+			T::WeightInfo::transfer_approved().saturating_add(Pallet::<T>::transfer_fee_weight())
+		)]
+		#[transactional]
+		pub(super) fn transfer_approved(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			destination: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let destination = T::Lookup::lookup(destination)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			Approvals::<T>::try_mutate_exists(id, (&owner, &delegate), |maybe_approved| -> DispatchResultWithPostInfo {
+				let mut approved = maybe_approved.take().ok_or(Error::<T>::Unapproved)?;
+				let remaining = approved.amount.checked_sub(&amount).ok_or(Error::<T>::Unapproved)?;
+
+				Self::do_transfer_with_fee(id, &owner, &destination, amount, TransferMode::Sweep)?;
+
+				if remaining.is_zero() {
+					DelegateApprovals::<T>::remove(&delegate, (id, &owner));
+					T::Currency::unreserve(&owner, approved.deposit);
+					Asset::<T>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+						details.approvals = details.approvals.saturating_sub(1);
+					});
+				} else {
+					approved.amount = remaining;
+					*maybe_approved = Some(approved);
+				}
+				Ok(().into())
+			})?;
+
+			Self::deposit_event(Event::TransferredApproved(id, owner, delegate, destination, amount));
+			Ok(().into())
+		}
+
+		/// Cancel outstanding approvals of an asset class, returning their deposits.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max`: The maximum number of approvals to cancel in this call.
+		///
+		/// Emits `ApprovalCancelled` for every cancelled approval.
+		///
+		/// Weight: `O(max)`
+		#[pallet::weight(T::WeightInfo::force_cancel_approvals(*max))]
+		pub(super) fn force_cancel_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] max: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+
+				let keys: Vec<_> = Approvals::<T>::iter_prefix(id)
+					.take(max as usize)
+					.map(|(key, _)| key)
+					.collect();
+				for (owner, delegate) in keys {
+					if let Some(approval) = Approvals::<T>::take(id, (&owner, &delegate)) {
+						DelegateApprovals::<T>::remove(&delegate, (id, &owner));
+						T::Currency::unreserve(&owner, approval.deposit);
+						details.approvals = details.approvals.saturating_sub(1);
+						Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
+					}
+				}
+				Ok(().into())
+			})
+		}
+
+		/// Set the maximum amount any account may transfer of an asset within one
+		/// `RateLimitWindow`.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// `force_transfer` is not subject to this limit.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `limit`: The new limit, or `None` to lift it.
+		///
+		/// Emits `DailyLimitSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_daily_limit())]
+		pub(super) fn set_daily_limit(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			limit: Option<T::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+				details.daily_limit = limit;
+
+				Self::deposit_event(Event::DailyLimitSet(id, limit));
+				Ok(().into())
+			})
+		}
+
+		/// Allow a retired asset id to be used again to create a new asset.
+		///
+		/// Origin must conform to `ForceOrigin`.
+		///
+		/// - `id`: The identifier of the retired asset.
+		///
+		/// Emits `RetiredPurged` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::purge_retired())]
+		pub(super) fn purge_retired(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(RetiredAssets::<T>::contains_key(id), Error::<T>::Unknown);
+			RetiredAssets::<T>::remove(id);
+
+			Self::deposit_event(Event::RetiredPurged(id));
+			Ok(().into())
+		}
+
+		/// Cancel a list of approvals of an asset, refunding each approval deposit to the account
+		/// which made the approval.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Pairs for which no approval exists are skipped.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `pairs`: The `(owner, delegate)` pairs of the approvals to cancel. At most
+		/// `MaxBatchSize` pairs may be given.
+		///
+		/// Emits `ApprovalCancelled` for every cancelled approval.
+		///
+		/// Weight: `O(P)` where `P = pairs.len()`
+		#[pallet::weight(T::WeightInfo::owner_clear_approvals(pairs.len() as u32))]
+		pub(super) fn owner_clear_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			pairs: Vec<(T::AccountId, T::AccountId)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(pairs.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(details.owner == origin, Error::<T>::NoPermission);
+
+				for (owner, delegate) in pairs {
+					if let Some(approval) = Approvals::<T>::take(id, (&owner, &delegate)) {
+						DelegateApprovals::<T>::remove(&delegate, (id, &owner));
+						T::Currency::unreserve(&owner, approval.deposit);
+						details.approvals = details.approvals.saturating_sub(1);
+						Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
 					}
+				}
+				Ok(().into())
+			})
+		}
+
+		/// Mint assets of a particular class to several accounts at once.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// Either every mint succeeds or none of them is applied.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiaries`: The accounts to be credited, each with the amount to mint to it. At
+		/// most `MaxBatchSize` entries may be given.
+		///
+		/// Emits `Issued` for every beneficiary.
+		///
+		/// Weight: `O(N)` where `N = beneficiaries.len()`
+		#[pallet::weight(T::WeightInfo::mint_batch(beneficiaries.len() as u32))]
+		#[transactional]
+		pub(super) fn mint_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			beneficiaries: Vec<(<T::Lookup as StaticLookup>::Source, T::Balance)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(beneficiaries.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
+
+			for (beneficiary, amount) in beneficiaries {
+				let beneficiary = T::Lookup::lookup(beneficiary)?;
+				Self::do_mint(id, beneficiary, amount)?;
+			}
+			Ok(().into())
+		}
+
+		/// Reduce the balances of several accounts at once.
+		///
+		/// Origin must be Signed and the sender should be the Manager of the asset `id`.
+		///
+		/// Either every burn succeeds or none of them is applied.
+		///
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `targets`: The accounts to be debited, each with the maximum amount by which its
+		/// balance should be reduced. At most `MaxBatchSize` entries may be given.
+		///
+		/// Emits `Burned` with the actual amount burned for every target.
+		///
+		/// Weight: `O(N)` where `N = targets.len()`
+		#[pallet::weight(T::WeightInfo::burn_batch(targets.len() as u32))]
+		#[transactional]
+		pub(super) fn burn_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			targets: Vec<(<T::Lookup as StaticLookup>::Source, T::Balance)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(targets.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+			ensure!(details.burnable, Error::<T>::BurnDisabled);
+
+			for (who, amount) in targets {
+				let who = T::Lookup::lookup(who)?;
+				Self::do_burn(id, who, amount)?;
+			}
+			Ok(().into())
+		}
+
+		/// Move some assets from the sender account to several others.
+		///
+		/// Origin must be Signed.
+		///
+		/// Either every transfer succeeds or none of them is applied.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `transfers`: The accounts to be credited, each with the amount to send to it. At most
+		/// `MaxBatchSize` entries may be given.
+		///
+		/// Emits `Transferred` for every transfer.
+		///
+		/// Weight: `O(N)` where `N = transfers.len()`, plus paying the transfer fee of each
+		#[pallet::weight(
+			T::WeightInfo::transfer_multi(transfers.len() as u32).saturating_add(
+				Pallet::<T>::transfer_fee_weight().saturating_mul(transfers.len() as Weight)
+			)
+		)]
+		#[transactional]
+		pub(super) fn transfer_multi(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			transfers: Vec<(<T::Lookup as StaticLookup>::Source, T::Balance)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(transfers.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			for (target, amount) in transfers {
+				ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+				let dest = T::Lookup::lookup(target)?;
+				Self::do_transfer_with_fee(id, &origin, &dest, amount, TransferMode::Sweep)?;
+			}
+			Ok(().into())
+		}
+
+		/// Disallow further unprivileged transfers from several accounts at once.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `who`: The accounts to be frozen. At most `MaxBatchSize` accounts may be given.
+		///
+		/// Emits `Frozen` for every account.
+		///
+		/// Weight: `O(N)` where `N = who.len()`
+		#[pallet::weight(T::WeightInfo::freeze_batch(who.len() as u32))]
+		#[transactional]
+		pub(super) fn freeze_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: Vec<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(who.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			ensure!(T::AssetAdmin::is_freezer(&origin), Error::<T>::NoPermission);
+
+			for who in who {
+				let who = T::Lookup::lookup(who)?;
+				ensure!(Account::<T>::contains_key(id, &who), Error::<T>::BalanceZero);
+				Account::<T>::mutate(id, &who, |a| {
+					a.set_frozen(true);
+					a.freeze_reason = 0;
+				});
+				Self::note_activity(id, ActivityKind::Frozen, &who, None);
+				Self::deposit_event(Event::<T>::Frozen(id, who, 0));
+			}
+			Ok(().into())
+		}
+
+		/// Mint the same amount of an asset to each of a list of accounts, skipping the accounts
+		/// which cannot receive it.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// Recipients are skipped rather than failing the whole call when they would be left below
+		/// `min_balance` or when the asset has no zombie allowance left for them.
+		///
+		/// - `id`: The identifier of the asset to be airdropped.
+		/// - `recipients`: The accounts to be credited. At most `MaxBatchSize` accounts may be given.
+		/// - `amount_each`: The amount of the asset to mint to each recipient.
+		///
+		/// Emits `Issued` for every credited recipient and `AirdropCompleted` once done.
+		///
+		/// Weight: `O(N)` where `N = recipients.len()`
+		#[pallet::weight(T::WeightInfo::airdrop(recipients.len() as u32))]
+		pub(super) fn airdrop(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			recipients: Vec<<T::Lookup as StaticLookup>::Source>,
+			#[pallet::compact] amount_each: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(recipients.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
+
+			let (mut succeeded, mut skipped) = (0u32, 0u32);
+			for recipient in recipients {
+				let minted = T::Lookup::lookup(recipient)
+					.map_err(DispatchError::from)
+					.and_then(|who| Self::do_mint(id, who, amount_each));
+				if minted.is_ok() {
+					succeeded += 1;
+				} else {
+					skipped += 1;
+				}
+			}
+
+			Self::deposit_event(Event::AirdropCompleted(id, succeeded, skipped));
+			Ok(().into())
+		}
+
+		/// Recompute the supply and account counts of an asset from its accounts and report whether
+		/// they match the stored details.
+		///
+		/// Origin must be Signed. Nothing is changed; the result is only reported in an event.
+		///
+		/// - `id`: The identifier of the asset to verify.
+		/// - `max_accounts`: An upper bound on the number of accounts holding the asset. Fails with
+		/// `BadWitness` if the asset has more.
+		///
+		/// Emits `InvariantCheck`.
+		///
+		/// Weight: `O(A)` where `A = max_accounts`
+		#[pallet::weight(T::WeightInfo::verify_asset(*max_accounts))]
+		pub(super) fn verify_asset(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] max_accounts: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
 
-					*metadata = Some(AssetMetadata {
-						deposit: new_deposit,
-						name: name.clone(),
-						symbol: symbol.clone(),
-						decimals,
-					})
+			let mut supply = Escrowed::<T>::get(id);
+			let (mut accounts, mut zombies) = (0u32, 0u32);
+			for (_, account) in Account::<T>::iter_prefix(id) {
+				ensure!(accounts < max_accounts, Error::<T>::BadWitness);
+				supply = supply.saturating_add(account.balance);
+				accounts += 1;
+				if account.is_zombie() {
+					zombies += 1;
 				}
+			}
+
+			Self::deposit_event(Event::InvariantCheck(
+				id,
+				supply == details.supply,
+				accounts == details.accounts,
+				zombies == details.zombies,
+			));
+			Ok(().into())
+		}
+
+		/// Start paying some assets to another account continuously, block by block.
+		///
+		/// Origin must be Signed.
+		///
+		/// The whole amount the stream will pay, `rate_per_block` for every block from now until
+		/// `end`, is taken from the sender's balance into escrow up front.
+		///
+		/// - `id`: The identifier of the asset to be streamed.
+		/// - `to`: The account to receive the stream.
+		/// - `rate_per_block`: The amount which becomes withdrawable by `to` every block.
+		/// - `end`: The block at which the stream is fully paid out. Must be in the future.
+		///
+		/// Emits `StreamCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_stream())]
+		pub(super) fn create_stream(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			to: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] rate_per_block: T::Balance,
+			end: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			ensure!(!rate_per_block.is_zero(), Error::<T>::AmountZero);
+			let start = frame_system::Module::<T>::block_number();
+			ensure!(end > start, Error::<T>::InvalidStreamEnd);
+
+			let stream = Stream { from, to, id, rate_per_block, start, end, withdrawn: Zero::zero() };
+			let total = stream.total().ok_or(Error::<T>::Overflow)?;
+			let stream_id = NextStreamId::<T>::get();
+			let next_stream_id = stream_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			Self::do_escrow(id, &stream.from, total)?;
+			NextStreamId::<T>::put(next_stream_id);
+			Self::deposit_event(Event::StreamCreated(
+				stream_id,
+				id,
+				stream.from.clone(),
+				stream.to.clone(),
+				total,
+			));
+			Streams::<T>::insert(stream_id, stream);
+			Ok(().into())
+		}
+
+		/// Withdraw everything a stream has paid out so far.
+		///
+		/// Origin must be Signed and the sender should be the recipient of the stream.
+		///
+		/// The stream is removed once it has been fully withdrawn.
+		///
+		/// - `stream_id`: The identifier of the stream to withdraw from.
+		///
+		/// Emits `StreamWithdrawn`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::withdraw_stream())]
+		pub(super) fn withdraw_stream(
+			origin: OriginFor<T>,
+			#[pallet::compact] stream_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Streams::<T>::try_mutate_exists(stream_id, |maybe_stream| {
+				let stream = maybe_stream.as_mut().ok_or(Error::<T>::UnknownStream)?;
+				ensure!(stream.to == origin, Error::<T>::NoPermission);
 
-				Self::deposit_event(Event::MetadataSet(id, name, symbol, decimals));
+				let now = frame_system::Module::<T>::block_number();
+				let amount = stream.vested(now).saturating_sub(stream.withdrawn);
+				ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+				Self::do_release(stream.id, &origin, amount)?;
+
+				stream.withdrawn = stream.withdrawn.saturating_add(amount);
+				if now >= stream.end {
+					*maybe_stream = None;
+				}
+				Self::deposit_event(Event::StreamWithdrawn(stream_id, origin.clone(), amount));
 				Ok(().into())
 			})
 		}
 
-	}
+		/// Stop a stream, paying the recipient what it has earned so far and returning the rest
+		/// to the sender.
+		///
+		/// Origin must be Signed and the sender should be either the sender or the recipient of
+		/// the stream.
+		///
+		/// - `stream_id`: The identifier of the stream to cancel.
+		///
+		/// Emits `StreamCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_stream())]
+		#[transactional]
+		pub(super) fn cancel_stream(
+			origin: OriginFor<T>,
+			#[pallet::compact] stream_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(origin == stream.from || origin == stream.to, Error::<T>::NoPermission);
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", T::Balance = "Balance", T::AssetId = "AssetId")]
-	pub enum Event<T: Config> {
-		/// Some asset class was created. \[asset_id, creator\]
-		Created(T::AssetId, T::AccountId),
-		/// Some assets were issued. \[asset_id, owner, total_supply\]
-		Issued(T::AssetId, T::AccountId, T::Balance),
-		/// Some assets were transferred. \[asset_id, from, to, amount\]
+			let now = frame_system::Module::<T>::block_number();
+			let vested = stream.vested(now);
+			let to_recipient = vested.saturating_sub(stream.withdrawn);
+			let to_sender = stream.total().unwrap_or_else(Zero::zero).saturating_sub(vested);
+			Self::do_release(stream.id, &stream.to, to_recipient)?;
+			Self::do_release(stream.id, &stream.from, to_sender)?;
+
+			Streams::<T>::remove(stream_id);
+			Self::deposit_event(Event::StreamCancelled(stream_id, to_recipient, to_sender));
+			Ok(().into())
+		}
+
+		/// Set the transfer amount from which transfers of an asset are also reported with a
+		/// `LargeTransfer` event.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `threshold`: The new threshold, or `None` to stop reporting large transfers.
+		///
+		/// Emits `WhaleThresholdSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_whale_threshold())]
+		pub(super) fn set_whale_threshold(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			threshold: Option<T::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.whale_threshold = threshold;
+
+				Self::deposit_event(Event::WhaleThresholdSet(id, threshold));
+				Ok(().into())
+			})
+		}
+
+		/// Burn the balances below the minimum balance of their asset, across all assets.
+		///
+		/// Origin must conform to `ForceOrigin`.
+		///
+		/// Accounts are visited in storage order, resuming after the last account visited by the
+		/// previous call. Once every account has been visited the next call starts over. Accounts
+		/// created less than `MinAccountLifetime` blocks ago, accounts with funds on hold and
+		/// accounts which cannot be burned are left alone.
+		///
+		/// - `max_items`: The maximum number of accounts to visit in this call.
+		///
+		/// Emits `Burned` for every account purged and `GlobalDustPurged` once done.
+		///
+		/// Weight: `O(N)` where `N = max_items`
+		#[pallet::weight(T::WeightInfo::purge_all_dust(*max_items))]
+		pub(super) fn purge_all_dust(
+			origin: OriginFor<T>,
+			#[pallet::compact] max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let prefix = Account::<T>::final_prefix();
+			let mut cursor = DustCursor::<T>::get();
+			if cursor.is_empty() {
+				cursor = prefix.to_vec();
+			}
+			let (mut visited, mut purged) = (0u32, 0u32);
+			let mut assets_touched = Vec::new();
+			let now = frame_system::Module::<T>::block_number();
+			while visited < max_items {
+				cursor = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix)) {
+					Some(key) => key,
+					None => {
+						cursor.clear();
+						break
+					}
+				};
+				visited += 1;
+
+				let mut key_material = Blake2_128Concat::reverse(&cursor[prefix.len()..]);
+				let id = match T::AssetId::decode(&mut key_material) {
+					Ok(id) => id,
+					Err(_) => continue,
+				};
+				let who = match T::AccountId::decode(&mut Blake2_128Concat::reverse(key_material)) {
+					Ok(who) => who,
+					Err(_) => continue,
+				};
+				let min_balance = match Asset::<T>::get(id) {
+					Some(details) => Self::min_balance_for(id, &who, details.min_balance),
+					None => continue,
+				};
+				let account = Account::<T>::get(id, &who);
+				let old_enough = now.saturating_sub(account.created_at) >= T::MinAccountLifetime::get();
+				if account.balance < min_balance && account.on_hold.is_zero() && old_enough {
+					// An account which cannot be purged must not hold up the others.
+					if Self::do_burn(id, who, min_balance).is_err() {
+						continue
+					}
+					purged += 1;
+					if !assets_touched.contains(&id) {
+						assets_touched.push(id);
+					}
+				}
+			}
+			DustCursor::<T>::put(cursor);
+
+			Self::deposit_event(Event::GlobalDustPurged(purged, assets_touched.len() as u32));
+			Ok(().into())
+		}
+
+		/// Issue a new asset class owned by the sender and credit every holder of an existing
+		/// asset with a share of their balance in it.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `old_id`.
+		///
+		/// The new asset takes the deposit of `create` and the `max_zombies`, `min_balance` and
+		/// existence model of `old_id`. At most `MaxBatchSize` holders are credited by this call;
+		/// the rest are credited by `spin_off_continue`. Holders whose share would be zero, or
+		/// who cannot be credited, are skipped.
+		///
+		/// - `old_id`: The identifier of the asset to spin off from.
+		/// - `new_id`: The identifier of the new asset. Must not already be in use.
+		/// - `ratio`: The share of each balance of `old_id` to credit in `new_id`.
+		/// - `feature_code`: The feature code of the new asset.
+		///
+		/// Emits `Created`, then `Issued` for every holder credited and `SpunOff` once every
+		/// holder has been visited.
+		///
+		/// Weight: `O(N)` where `N = MaxBatchSize`
+		#[pallet::weight(T::WeightInfo::spin_off(T::MaxBatchSize::get()))]
+		#[transactional]
+		pub(super) fn spin_off(
+			origin: OriginFor<T>,
+			#[pallet::compact] old_id: T::AssetId,
+			#[pallet::compact] new_id: T::AssetId,
+			ratio: Permill,
+			feature_code: u32,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let details = Asset::<T>::get(old_id).ok_or(Error::<T>::Unknown)?;
+			ensure!(owner == details.owner, Error::<T>::NoPermission);
+
+			Self::do_create(
+				&owner,
+				new_id,
+				details.max_zombies,
+				details.min_balance,
+				feature_code,
+				details.existence_model,
+				false,
+			)?;
+			Self::deposit_event(Event::Created(new_id, owner));
+
+			SpinOffs::<T>::insert(new_id, SpinOff { old_id, ratio, cursor: Vec::new() });
+			Self::spin_off_step(new_id)?;
+			Ok(().into())
+		}
+
+		/// Credit the next holders of a spin-off started by `spin_off`.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `new_id`: The identifier of the asset being spun off.
+		///
+		/// Emits `Issued` for every holder credited and `SpunOff` once every holder has been
+		/// visited.
+		///
+		/// Weight: `O(N)` where `N = MaxBatchSize`
+		#[pallet::weight(T::WeightInfo::spin_off_continue(T::MaxBatchSize::get()))]
+		pub(super) fn spin_off_continue(
+			origin: OriginFor<T>,
+			#[pallet::compact] new_id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			Self::spin_off_step(new_id)?;
+			Ok(().into())
+		}
+
+		/// Set the number of blocks an account must wait after transferring some of an asset
+		/// before it may transfer it again.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// `force_transfer` is not subject to the cooldown.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `cooldown`: The new cooldown, or `None` to lift it.
+		///
+		/// Emits `TransferCooldownSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_transfer_cooldown())]
+		pub(super) fn set_transfer_cooldown(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			cooldown: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.transfer_cooldown = cooldown;
+
+				Self::deposit_event(Event::TransferCooldownSet(id, cooldown));
+				Ok(().into())
+			})
+		}
+
+		/// Let any account mint a fixed amount of an asset for itself once per period.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `community_mint`: The amount and period, or `None` to stop community minting.
+		///
+		/// Emits `CommunityMintSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_community_mint())]
+		pub(super) fn set_community_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			community_mint: Option<CommunityMint<T::Balance, T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.community_mint = community_mint.clone();
+
+				Self::deposit_event(Event::CommunityMintSet(id, community_mint));
+				Ok(().into())
+			})
+		}
+
+		/// Mint the community amount of an asset to the sender.
+		///
+		/// Origin must be Signed. The sender may do so once per period set by the Owner.
+		///
+		/// - `id`: The identifier of the asset to mint.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::community_mint())]
+		pub(super) fn community_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			let community_mint = details.community_mint.ok_or(Error::<T>::NoCommunityMint)?;
+
+			let now = frame_system::Module::<T>::block_number();
+			if let Some(last) = LastCommunityMint::<T>::get(id, &origin) {
+				ensure!(
+					now >= last.saturating_add(community_mint.period_blocks),
+					Error::<T>::CommunityMintTooSoon,
+				);
+			}
+			Self::do_mint(id, origin.clone(), community_mint.per_period)?;
+			LastCommunityMint::<T>::insert(id, origin, now);
+			Ok(().into())
+		}
+
+		/// Start copying the balances of every holder of an asset into a new snapshot.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `id`.
+		///
+		/// At most `MaxBatchSize` holders are copied by this call; the rest are copied by
+		/// `take_snapshot_continue`. Balances are copied as they are when their page is
+		/// processed, so the asset should be frozen until the snapshot is complete if it must
+		/// reflect a single block.
+		///
+		/// Every holder copied stores one more `SnapshotBalances` entry, for which no deposit is
+		/// taken. Use `discard_snapshot` to remove the snapshot once it is no longer needed.
+		///
+		/// - `id`: The identifier of the asset to snapshot.
+		///
+		/// Emits `SnapshotStarted` with the identifier of the new snapshot, and
+		/// `SnapshotCompleted` once every holder has been copied.
+		///
+		/// Weight: `O(N)` where `N = MaxBatchSize`
+		#[pallet::weight(T::WeightInfo::take_snapshot(T::MaxBatchSize::get()))]
+		pub(super) fn take_snapshot(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T>::NoPermission);
+			}
+
+			let snapshot_id = NextSnapshotId::<T>::get();
+			NextSnapshotId::<T>::put(snapshot_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			let block = frame_system::Module::<T>::block_number();
+			Snapshots::<T>::insert(snapshot_id, Snapshot {
+				id,
+				block,
+				holders: 0,
+				cursor: Vec::new(),
+				complete: false,
+			});
+			Self::deposit_event(Event::SnapshotStarted(snapshot_id, id, block));
+
+			Self::snapshot_step(snapshot_id)?;
+			Ok(().into())
+		}
+
+		/// Copy the next holders of a snapshot started by `take_snapshot`.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `snapshot_id`: The identifier of the snapshot.
+		///
+		/// Emits `SnapshotCompleted` once every holder has been copied.
+		///
+		/// Weight: `O(N)` where `N = MaxBatchSize`
+		#[pallet::weight(T::WeightInfo::take_snapshot_continue(T::MaxBatchSize::get()))]
+		pub(super) fn take_snapshot_continue(
+			origin: OriginFor<T>,
+			#[pallet::compact] snapshot_id: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			Self::snapshot_step(snapshot_id)?;
+			Ok(().into())
+		}
+
+		/// Remove a snapshot and every balance copied into it.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the snapshotted asset.
+		///
+		/// - `snapshot_id`: The identifier of the snapshot.
+		/// - `holders`: The number of holders copied into the snapshot, which must be at least
+		/// the number actually copied.
+		///
+		/// Emits `SnapshotDiscarded`.
+		///
+		/// Weight: `O(H)` where `H = holders`
+		#[pallet::weight(T::WeightInfo::discard_snapshot(*holders))]
+		pub(super) fn discard_snapshot(
+			origin: OriginFor<T>,
+			#[pallet::compact] snapshot_id: u64,
+			#[pallet::compact] holders: u32,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let snapshot = Snapshots::<T>::get(snapshot_id).ok_or(Error::<T>::UnknownSnapshot)?;
+			if let Some(check_owner) = maybe_check_owner {
+				let owner = Asset::<T>::get(snapshot.id).map(|details| details.owner);
+				ensure!(owner == Some(check_owner), Error::<T>::NoPermission);
+			}
+			ensure!(snapshot.holders <= holders, Error::<T>::BadWitness);
+
+			SnapshotBalances::<T>::remove_prefix(snapshot_id);
+			Snapshots::<T>::remove(snapshot_id);
+			Self::deposit_event(Event::SnapshotDiscarded(snapshot_id));
+			Ok(().into())
+		}
+
+		/// Prevent the decimals of an asset from changing, even while they are zero.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `DecimalsLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_decimals())]
+		pub(super) fn lock_decimals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.decimals_locked = true;
+
+				Self::deposit_event(Event::DecimalsLocked(id));
+				Ok(().into())
+			})
+		}
+
+		/// Allow an account to mint an asset up to a total amount until some block.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Granting again replaces the previous grant, including the amount minted under it.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to become a temporary issuer.
+		/// - `until`: The last block at which `who` may mint.
+		/// - `cap`: The total amount `who` may mint.
+		///
+		/// Emits `TempIssuerGranted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::grant_temp_issuer())]
+		pub(super) fn grant_temp_issuer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			until: T::BlockNumber,
+			#[pallet::compact] cap: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+			TempIssuers::<T>::insert(id, &who, TempIssuer { until, cap, minted: Zero::zero() });
+			Self::deposit_event(Event::TempIssuerGranted(id, who, until, cap));
+			Ok(().into())
+		}
+
+		/// Take back the minting rights of a temporary issuer.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The temporary issuer.
+		///
+		/// Emits `TempIssuerRevoked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::revoke_temp_issuer())]
+		pub(super) fn revoke_temp_issuer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+			TempIssuers::<T>::take(id, &who).ok_or(Error::<T>::NotTempIssuer)?;
+			Self::deposit_event(Event::TempIssuerRevoked(id, who));
+			Ok(().into())
+		}
+
+		/// Put some of an account's assets on hold for a reason, such as a dispute.
+		///
+		/// Origin must be `HoldOrigin`. The reason `DEPOSIT_HOLD_REASON` is kept for deposits.
+		///
+		/// Funds on hold still belong to the account but can be neither transferred nor burned,
+		/// and the account is kept alive while it has any. Holds for different reasons add up.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose funds are put on hold.
+		/// - `amount`: The amount to put on hold. Must be greater than zero.
+		/// - `reason`: The reason code of the hold.
+		///
+		/// Emits `Held`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::hold())]
+		pub(super) fn hold(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			reason: u8,
+		) -> DispatchResultWithPostInfo {
+			T::HoldOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(reason != DEPOSIT_HOLD_REASON, Error::<T>::NoPermission);
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+
+			Self::do_hold(id, &who, amount, reason)?;
+			Self::deposit_event(Event::Held(id, who, amount, reason));
+			Ok(().into())
+		}
+
+		/// Release some of an account's assets held for a reason.
+		///
+		/// Origin must be `HoldOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose funds are released.
+		/// - `amount`: The amount to release.
+		/// - `reason`: The reason code of the hold.
+		///
+		/// Emits `HoldReleased`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::release_hold())]
+		pub(super) fn release_hold(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			reason: u8,
+		) -> DispatchResultWithPostInfo {
+			T::HoldOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(reason != DEPOSIT_HOLD_REASON, Error::<T>::NoPermission);
+
+			Self::do_release_hold(id, &who, amount, reason)?;
+			Self::deposit_event(Event::HoldReleased(id, who, amount, reason));
+			Ok(().into())
+		}
+
+		/// Settle assets held for a reason by transferring them to another account.
+		///
+		/// Origin must be `HoldOrigin`.
+		///
+		/// The funds are released from the hold and then transferred under the usual rules, so
+		/// they cannot move while the asset or `from` is frozen.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `from`: The account whose funds are on hold.
+		/// - `to`: The account to be credited.
+		/// - `amount`: The amount to settle. Must be greater than zero.
+		/// - `reason`: The reason code of the hold.
+		///
+		/// Emits `Transferred` and `HoldTransferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_on_hold())]
+		#[transactional]
+		pub(super) fn transfer_on_hold(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			reason: u8,
+		) -> DispatchResultWithPostInfo {
+			T::HoldOrigin::ensure_origin(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(reason != DEPOSIT_HOLD_REASON, Error::<T>::NoPermission);
+
+			Self::do_release_hold(id, &from, amount, reason)?;
+			let amount = Self::do_transfer(id, &from, &to, amount)?;
+			Self::deposit_event(Event::HoldTransferred(id, from, to, amount, reason));
+			Ok(().into())
+		}
+
+		/// Mint assets of a particular class to any number of accounts, spread over as many calls
+		/// as it takes.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// The beneficiaries are stored as a pending batch and credited in order until
+		/// `BatchWeightLimit` is spent. Whatever is left is credited by `continue_batch`. Unlike
+		/// `mint_batch`, a beneficiary who cannot be credited is skipped rather than failing the
+		/// batch.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiaries`: The accounts to be credited, each with the amount to mint to it.
+		///
+		/// Emits `BatchStarted`, `Issued` for every credited beneficiary, and either
+		/// `BatchProgress` or `BatchCompleted`.
+		///
+		/// Weight: `O(N)` where `N = beneficiaries.len()`, plus at most `BatchWeightLimit`
+		#[pallet::weight(
+			T::WeightInfo::start_mint_batch(beneficiaries.len() as u32)
+				.saturating_add(T::BatchWeightLimit::get())
+		)]
+		pub(super) fn start_mint_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			beneficiaries: Vec<(<T::Lookup as StaticLookup>::Source, T::Balance)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
+
+			let total = beneficiaries.len() as u32;
+			let items = beneficiaries.into_iter()
+				.map(|(who, amount)| Ok((T::Lookup::lookup(who)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			let batch_id = NextBatchId::<T>::mutate(|next| {
+				let batch_id = *next;
+				*next = next.saturating_add(1);
+				batch_id
+			});
+			PendingBatches::<T>::insert(batch_id, PendingBatch { id, items, next: 0, succeeded: 0, skipped: 0 });
+			Self::deposit_event(Event::BatchStarted(batch_id, id, total));
+
+			let used = Self::batch_step(batch_id)?;
+			Ok(Some(T::WeightInfo::start_mint_batch(total).saturating_add(used)).into())
+		}
+
+		/// Credit the next beneficiaries of a batch started by `start_mint_batch`.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `batch_id`: The identifier of the pending batch.
+		///
+		/// Emits `Issued` for every credited beneficiary, and either `BatchProgress` or
+		/// `BatchCompleted`.
+		///
+		/// Weight: at most `BatchWeightLimit`
+		#[pallet::weight(T::WeightInfo::continue_batch().saturating_add(T::BatchWeightLimit::get()))]
+		pub(super) fn continue_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] batch_id: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let used = Self::batch_step(batch_id)?;
+			Ok(Some(T::WeightInfo::continue_batch().saturating_add(used)).into())
+		}
+
+		/// Allow or forbid burning the balances of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Forbidding burns stops `burn`, `burn_batch`, `convert`, `redeem`, transfers to
+		/// `BurnAddress` and the burning of the burn rate out of transfers. Dust purges and
+		/// destroying the whole asset class are unaffected.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `burnable`: Whether the balances of the asset may be burned.
+		///
+		/// Emits `BurnableSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_burnable())]
+		pub(super) fn set_burnable(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			burnable: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.burnable = burnable;
+
+				Self::deposit_event(Event::BurnableSet(id, burnable));
+				Ok(().into())
+			})
+		}
+
+		/// Move some assets from the sender account to another, failing unless at least
+		/// `min_received` arrives.
+		///
+		/// Origin must be Signed.
+		///
+		/// The amount delivered is the amount actually transferred less the transfer fee, including
+		/// any remainder of the sender's balance swept along with it.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced. Must
+		/// be greater than zero.
+		/// - `min_received`: The least amount `target` must be credited with.
+		///
+		/// Emits `Transferred` with the actual amount transferred.
+		///
+		/// Weight: `O(1)`, plus paying the transfer fee
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T>::transfer_fee_weight())
+		)]
+		#[transactional]
+		pub(super) fn transfer_min_received(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			#[pallet::compact] min_received: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(Self::asset_exists(id), Error::<T>::Unknown);
+			let dest = T::Lookup::lookup(target)?;
+
+			let received =
+				Self::do_transfer_with_fee(id, &origin, &dest, amount, TransferMode::Sweep)?;
+			ensure!(received >= min_received, Error::<T>::SlippageExceeded);
+			Ok(().into())
+		}
+
+		/// Let holders of a deprecated asset convert it into its successor at a fixed rate.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `old_id`: The identifier of the deprecated asset.
+		/// - `new_id`: The identifier of the asset to convert into. Must exist.
+		/// - `rate`: The amount of `new_id` minted for each unit of `old_id` burned.
+		///
+		/// Emits `ConversionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_conversion())]
+		pub(super) fn set_conversion(
+			origin: OriginFor<T>,
+			#[pallet::compact] old_id: T::AssetId,
+			#[pallet::compact] new_id: T::AssetId,
+			rate: Permill,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Asset::<T>::contains_key(old_id), Error::<T>::Unknown);
+			ensure!(Asset::<T>::contains_key(new_id), Error::<T>::Unknown);
+
+			Conversions::<T>::insert(old_id, (new_id, rate));
+			Self::deposit_event(Event::ConversionSet(old_id, new_id, rate));
+			Ok(().into())
+		}
+
+		/// Convert some of a deprecated asset held by the sender into its successor.
+		///
+		/// Origin must be Signed.
+		///
+		/// `amount` of `old_id` is burned from the sender, or more if the remainder would be below
+		/// the minimum balance, and the burned amount times the conversion rate of `new_id` is
+		/// minted to the sender.
+		///
+		/// - `old_id`: The identifier of the deprecated asset.
+		/// - `amount`: The amount of `old_id` to convert. Must be greater than zero.
+		///
+		/// Emits `Burned`, `Issued` and `Converted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::convert())]
+		#[transactional]
+		pub(super) fn convert(
+			origin: OriginFor<T>,
+			#[pallet::compact] old_id: T::AssetId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			let (new_id, rate) = Conversions::<T>::get(old_id).ok_or(Error::<T>::NoConversion)?;
+			Self::lift_lapsed_freeze(old_id);
+			let details = Asset::<T>::get(old_id).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.burnable, Error::<T>::BurnDisabled);
+			ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+			ensure!(!Account::<T>::get(old_id, &origin).is_frozen(), Error::<T>::AccountFrozen);
+
+			let burned = Self::do_burn(old_id, origin.clone(), amount)?;
+			let minted = rate * burned;
+			if !minted.is_zero() {
+				Self::do_mint(new_id, origin.clone(), minted)?;
+			}
+			Self::deposit_event(Event::Converted(origin, old_id, new_id, burned, minted));
+			Ok(().into())
+		}
+
+		/// Restrict transfers of an asset to the accounts in its destination allowlist, or lift the
+		/// restriction.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// `force_transfer` and minting are never restricted. The owner, issuer, admin and freezer
+		/// may always receive the asset unless `set_strict_whitelist` says otherwise.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `enabled`: Whether transfers may only credit allowed destinations.
+		///
+		/// Emits `DestinationWhitelistSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_destination_whitelist())]
+		pub(super) fn set_destination_whitelist(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			enabled: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				details.destination_whitelist = enabled;
+
+				Self::deposit_event(Event::DestinationWhitelistSet(id, enabled));
+				Ok(().into())
+			})
+		}
+
+		/// Allow transfers of an asset to credit an account while its destination whitelist is on.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to allow.
+		///
+		/// Emits `DestinationAdded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_destination())]
+		pub(super) fn add_destination(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+			DestinationAllowlist::<T>::insert(id, &who, ());
+			Self::deposit_event(Event::DestinationAdded(id, who));
+			Ok(().into())
+		}
+
+		/// Stop allowing transfers of an asset to credit an account while its destination
+		/// whitelist is on.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to disallow.
+		///
+		/// Emits `DestinationRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_destination())]
+		pub(super) fn remove_destination(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+			DestinationAllowlist::<T>::take(id, &who).ok_or(Error::<T>::NotAllowed)?;
+			Self::deposit_event(Event::DestinationRemoved(id, who));
+			Ok(().into())
+		}
+
+		/// Set the block from which an asset can no longer be minted or transferred.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Burning and destroying the asset keep working after it expires, so that it can be
+		/// wound down.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `expires_at`: The expiry block, or `None` for the asset never to expire.
+		///
+		/// Emits `ExpirySet`, and `AssetExpired` once the asset expires.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_expiry())]
+		pub(super) fn set_expiry(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				if let Some(old) = details.expires_at {
+					Expiries::<T>::mutate(old, |ids| ids.retain(|x| *x != id));
+				}
+				details.expires_at = expires_at;
+				Self::deposit_event(Event::ExpirySet(id, expires_at));
+
+				if let Some(at) = expires_at {
+					if frame_system::Module::<T>::block_number() >= at {
+						Self::deposit_event(Event::AssetExpired(id));
+					} else {
+						Expiries::<T>::append(at, id);
+					}
+				}
+				Ok(().into())
+			})
+		}
+
+		/// Move a share of the sender's balance of an asset to another account.
+		///
+		/// Origin must be Signed.
+		///
+		/// The amount is `percent` of the sender's balance, rounded down and limited to what the
+		/// sender may transfer. With `keep_alive`, it is further limited so that at least the
+		/// minimum balance stays behind. Without it, a remainder below the minimum balance is
+		/// swept to `target` as well, so that 100% empties the sender's account.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `percent`: The share of the sender's balance to transfer.
+		/// - `keep_alive`: Whether the sender's account must survive the transfer.
+		///
+		/// Emits `Transferred` with the actual amount transferred.
+		///
+		/// Weight: `O(1)`, plus paying the transfer fee
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T>::transfer_fee_weight())
+		)]
+		#[transactional]
+		pub(super) fn transfer_percent(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			percent: Permill,
+			keep_alive: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			let dest = T::Lookup::lookup(target)?;
+
+			let account = Account::<T>::get(id, &origin);
+			let mut amount = percent.mul_floor(account.balance).min(account.reducible(details.min_balance));
+			if keep_alive {
+				amount = amount.min(account.balance.saturating_sub(details.min_balance));
+			}
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			let created = !Account::<T>::contains_key(id, &dest);
+			Self::do_transfer_with_fee(id, &origin, &dest, amount, TransferMode::Sweep)?;
+			let reaped = !Account::<T>::contains_key(id, &origin);
+			Ok(Some(Self::transfer_weight(reaped, created)).into())
+		}
+
+		/// Set the metadata for an asset without taking a deposit.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Any deposit the owner paid for earlier metadata is returned, and the new metadata is
+		/// stored with a zero deposit, so nothing is unreserved for it when the asset is
+		/// destroyed. Decimals may be changed even once they are locked.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// Together with `name`, limited in length by `MaxTotalMetadataBytes`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32))]
+		pub(super) fn force_set_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+			ensure!(
+				name.len() + symbol.len() <= T::MaxTotalMetadataBytes::get() as usize,
+				Error::<T>::BadMetadata,
+			);
+
+			let mut d = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			let decimals = if d.indivisible { 0 } else { decimals };
+			ensure!(Self::checked_pow10(decimals).is_some(), Error::<T>::DecimalsOverflow);
+			let old_deposit = Metadata::<T>::get(id).deposit;
+			Self::unreserve_deposit(id, &d.owner, old_deposit);
+
+			Metadata::<T>::insert(id, AssetMetadata {
+				deposit: Zero::zero(),
+				name: name.clone(),
+				symbol: symbol.clone(),
+				decimals,
+			});
+			if !d.decimals_locked && !decimals.is_zero() {
+				d.decimals_locked = true;
+				Asset::<T>::insert(id, &d);
+			}
+
+			Self::deposit_event(Event::MetadataSet(id, name, symbol, decimals));
+			Ok(().into())
+		}
+
+		/// Set the amount of native currency paid for each unit of an asset redeemed, or stop
+		/// redemptions.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `rate`: The native currency paid per unit redeemed, or `None` to stop redemptions.
+		///
+		/// Emits `RedemptionRateSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_redemption_rate())]
+		pub(super) fn set_redemption_rate(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			rate: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+			RedemptionRate::<T>::mutate_exists(id, |r| *r = rate);
+
+			Self::deposit_event(Event::RedemptionRateSet(id, rate));
+			Ok(().into())
+		}
+
+		/// Add native currency to the reserve which pays for redemptions of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`. The funds
+		/// are reserved on the Owner's account and follow the asset to a new Owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `amount`: The native currency to add to the reserve.
+		///
+		/// Emits `RedemptionFunded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::fund_redemption())]
+		pub(super) fn fund_redemption(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			T::Currency::reserve(&origin, amount)?;
+			RedemptionReserve::<T>::mutate(id, |r| *r = r.saturating_add(amount));
+
+			Self::deposit_event(Event::RedemptionFunded(id, amount));
+			Ok(().into())
+		}
+
+		/// Burn some of an asset held by the sender in exchange for native currency from the
+		/// redemption reserve, at the asset's redemption rate.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to redeem.
+		/// - `amount`: The amount to burn. More is burned if the remainder would be below the
+		/// minimum balance.
+		///
+		/// Fails with `NotRedeemable` if the asset has no redemption rate, with `BurnDisabled` if
+		/// its balances may not be burned and with `InsufficientRedemptionReserve` if the reserve
+		/// cannot cover the payout.
+		///
+		/// Emits `Redeemed` with the amount burned and the native currency paid.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::redeem())]
+		#[transactional]
+		pub(super) fn redeem(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			let rate = RedemptionRate::<T>::get(id).ok_or(Error::<T>::NotRedeemable)?;
+			Self::lift_lapsed_freeze(id);
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.burnable, Error::<T>::BurnDisabled);
+			ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+			ensure!(!Account::<T>::get(id, &origin).is_frozen(), Error::<T>::AccountFrozen);
+
+			let burned = Self::do_burn(id, origin.clone(), amount)?;
+			let paid: BalanceOf<T> = rate.saturated_into::<u128>()
+				.saturating_mul(burned.saturated_into::<u128>())
+				.saturated_into();
+			let reserve = RedemptionReserve::<T>::get(id);
+			ensure!(paid <= reserve, Error::<T>::InsufficientRedemptionReserve);
+
+			let unpaid = T::Currency::unreserve(&details.owner, paid);
+			ensure!(unpaid.is_zero(), Error::<T>::InsufficientRedemptionReserve);
+			T::Currency::transfer(&details.owner, &origin, paid, ExistenceRequirement::AllowDeath)?;
+			RedemptionReserve::<T>::insert(id, reserve - paid);
+
+			Self::deposit_event(Event::Redeemed(id, origin, burned, paid));
+			Ok(().into())
+		}
+
+		/// Charge a fee on transfers of an asset, paid to its Owner out of the amount sent, or stop
+		/// charging one.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The fee is `rate` of the amount, rounded down. Transfers of at least `min_fee_threshold`
+		/// pay no less than `min_fee`, so they cannot avoid the fee by being split into amounts
+		/// whose fee rounds down to zero. `min_fee` may not exceed `min_fee_threshold`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `fee`: The new transfer fee, or `None` to stop charging one.
+		///
+		/// Emits `TransferFeeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_transfer_fee())]
+		pub(super) fn set_transfer_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			fee: Option<TransferFee<T::Balance>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+			if let Some(fee) = &fee {
+				ensure!(fee.min_fee <= fee.min_fee_threshold, Error::<T>::BadTransferFee);
+			}
+
+			TransferFees::<T>::mutate_exists(id, |f| *f = fee.clone());
+
+			Self::deposit_event(Event::TransferFeeSet(id, fee));
+			Ok(().into())
+		}
+
+		/// Move the whole account of `old` for an asset to `new`, for when an account has to be
+		/// replaced.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// The balance, freeze and freeze reason move with the account. `old` loses its reference
+		/// or zombie status and `new` gains whichever its own existence allows. Accounts with
+		/// funds on hold cannot be migrated until the holds are released.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `old`: The account to migrate from. It is removed.
+		/// - `new`: The account to migrate to. Must not hold the asset yet.
+		///
+		/// Emits `AccountMigrated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_migrate_account())]
+		#[transactional]
+		pub(super) fn force_migrate_account(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			old: <T::Lookup as StaticLookup>::Source,
+			new: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let old = T::Lookup::lookup(old)?;
+			let new = T::Lookup::lookup(new)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(Account::<T>::contains_key(id, &old), Error::<T>::BalanceZero);
+				ensure!(!Account::<T>::contains_key(id, &new), Error::<T>::AccountExists);
+
+				let mut account = Account::<T>::take(id, &old);
+				ensure!(account.on_hold.is_zero(), Error::<T>::FundsOnHold);
+				Self::dead_account(&old, details, account.is_zombie());
+				Self::new_account(&new, details, &mut account)?;
+				Account::<T>::insert(id, &new, account);
+
+				Self::deposit_event(Event::AccountMigrated(id, old, new));
+				Ok(().into())
+			})
+		}
+
+		/// Recompute the account count, zombie count and supply of an asset from its accounts and
+		/// escrow, and correct its details once every account has been counted.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Accounts are visited in storage order, resuming after the last account visited by the
+		/// previous call. Accounts changed behind the cursor while a recount is in progress are
+		/// counted as they were, so recount again if the asset was in use meanwhile.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_scan`: The maximum number of accounts to visit in this call.
+		///
+		/// Emits `AssetRecounted` with the corrected values once done.
+		///
+		/// Weight: `O(N)` where `N = max_scan`
+		#[pallet::weight(T::WeightInfo::recount(*max_scan))]
+		pub(super) fn recount(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] max_scan: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::asset_exists(id), Error::<T>::Unknown);
+
+			let prefix = Self::accounts_prefix(id);
+			let mut recount = Recounts::<T>::get(id).unwrap_or_default();
+			if recount.cursor.is_empty() {
+				recount.cursor = prefix.clone();
+			}
+			for _ in 0..max_scan {
+				recount.cursor = match sp_io::storage::next_key(&recount.cursor)
+					.filter(|key| key.starts_with(&prefix))
+				{
+					Some(key) => key,
+					None => {
+						// Escrowed funds left the accounts but are still part of the supply.
+						let supply = recount.supply.saturating_add(Escrowed::<T>::get(id));
+						Asset::<T>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+							details.accounts = recount.accounts;
+							details.zombies = recount.zombies;
+							details.supply = supply;
+						});
+						Recounts::<T>::remove(id);
+						Self::deposit_event(
+							Event::AssetRecounted(id, recount.accounts, recount.zombies, supply)
+						);
+						return Ok(().into())
+					}
+				};
+				let mut key_material = Blake2_128Concat::reverse(&recount.cursor[prefix.len()..]);
+				if let Ok(who) = T::AccountId::decode(&mut key_material) {
+					let account = Account::<T>::get(id, &who);
+					recount.accounts = recount.accounts.saturating_add(1);
+					if account.is_zombie() {
+						recount.zombies = recount.zombies.saturating_add(1);
+					}
+					recount.supply = recount.supply.saturating_add(account.balance);
+				}
+			}
+			Recounts::<T>::insert(id, recount);
+			Ok(().into())
+		}
+
+		/// Install an asset class exported from a legacy assets pallet, to be followed by its
+		/// holders through `import_accounts`.
+		///
+		/// Origin must conform to `ForceOrigin`.
+		///
+		/// The asset starts with no accounts and no supply; the holders imported later must add up
+		/// to exactly `details.supply`. The deposit is taken over as it stands, so it must still be
+		/// reserved on the owner's account.
+		///
+		/// - `id`: The identifier of the asset. Must not be in use, retired, premium or claimed by
+		/// another account than the owner.
+		/// - `details`: The details of the asset in the legacy pallet.
+		/// - `feature_code`: The feature code of the asset.
+		///
+		/// Emits `LegacyImported`, and `LegacyImportCompleted` if the asset has no supply.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::import_from_legacy())]
+		pub(super) fn import_from_legacy(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			details: LegacyAssetDetails<T::Balance, T::AccountId, BalanceOf<T>>,
+			feature_code: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!RetiredAssets::<T>::contains_key(id), Error::<T>::AssetRetired);
+			ensure!(
+				Self::live_claim(id).filter(|c| c.who != details.owner).is_none(),
+				Error::<T>::IdClaimed,
+			);
+			ensure!(!T::PremiumAssetIds::filter(&id), Error::<T>::PremiumId);
+			ensure!(!details.min_balance.is_zero(), Error::<T>::MinBalanceZero);
+			ensure!(details.min_balance <= T::MaxMinBalance::get(), Error::<T>::MinBalanceTooHigh);
+			ensure!(!feature_code.is_zero(), Error::<T>::BadFeaturePoint);
+			ensure!(
+				T::Currency::reserved_balance(&details.owner) >= details.deposit,
+				Error::<T>::DepositNotReserved,
+			);
+
+			if let Some(claim) = ClaimedAssetIds::<T>::take(id) {
+				T::Currency::unreserve(&claim.who, claim.deposit);
+			}
+			OwnerAssets::<T>::insert(&details.owner, id, ());
+			CreatorAssets::<T>::insert(&details.owner, id, ());
+			Asset::<T>::insert(id, AssetDetails {
+				owner: details.owner.clone(),
+				supply: Zero::zero(),
+				deposit: details.deposit,
+				max_zombies: details.max_zombies,
+				min_balance: details.min_balance,
+				zombies: Zero::zero(),
+				accounts: Zero::zero(),
+				approvals: Zero::zero(),
+				is_frozen: details.is_frozen,
+				is_featured: true,
+				daily_limit: None,
+				existence_model: ExistenceModel::Zombie,
+				whale_threshold: None,
+				transfer_cooldown: None,
+				community_mint: None,
+				decimals_locked: false,
+				burnable: true,
+				destination_whitelist: false,
+				expires_at: None,
+				freeze_reason: 0,
+				clawback_window: None,
+				indivisible: false,
+				max_transfer: None,
+				frozen_until: None,
+				cross_chain: false,
+				strict_whitelist: false,
+				burn_rate_bps: 0,
+				creator: details.owner.clone(),
+			});
+			if details.is_frozen {
+				FrozenAssets::<T>::insert(id, ());
+			}
+			Self::insert_feature(id, Self::new_feature_detail(feature_code));
+
+			Self::deposit_event(Event::LegacyImported(id, details.owner));
+			if details.supply.is_zero() {
+				Self::deposit_event(Event::LegacyImportCompleted(id));
+			} else {
+				LegacyImports::<T>::insert(id, details.supply);
+			}
+			Ok(().into())
+		}
+
+		/// Import a batch of the holders of an asset installed by `import_from_legacy`.
+		///
+		/// Origin must conform to `ForceOrigin`.
+		///
+		/// Every holder is credited as if minted to, so account and zombie counts follow from the
+		/// accounts actually created. The import completes once the balances add up to the legacy
+		/// supply, and fails if they would exceed it.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `accounts`: The holders to import, each with its balance. At most `MaxBatchSize`
+		/// entries may be given, none of which may hold the asset already.
+		///
+		/// Emits `Issued` for every holder, `LegacyAccountsImported` and `LegacyImportCompleted`
+		/// once every holder is imported.
+		///
+		/// Weight: `O(N)` where `N = accounts.len()`
+		#[pallet::weight(T::WeightInfo::import_accounts(accounts.len() as u32))]
+		#[transactional]
+		pub(super) fn import_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			accounts: Vec<(T::AccountId, T::Balance)>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(accounts.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			let mut remaining = LegacyImports::<T>::get(id).ok_or(Error::<T>::NotImporting)?;
+
+			let count = accounts.len() as u32;
+			for (who, balance) in accounts {
+				ensure!(!Account::<T>::contains_key(id, &who), Error::<T>::AccountExists);
+				remaining = remaining.checked_sub(&balance).ok_or(Error::<T>::ImportExceedsSupply)?;
+				Self::do_mint(id, who, balance)?;
+			}
+
+			Self::deposit_event(Event::LegacyAccountsImported(id, count));
+			if remaining.is_zero() {
+				LegacyImports::<T>::remove(id);
+				Self::deposit_event(Event::LegacyImportCompleted(id));
+			} else {
+				LegacyImports::<T>::insert(id, remaining);
+			}
+			Ok(().into())
+		}
+
+		/// Set the number of blocks during which balances minted of an asset may be clawed back, or
+		/// stop recording mints.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `window`: The new clawback window, or `None` for none.
+		///
+		/// Emits `ClawbackWindowSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_clawback_window())]
+		pub(super) fn set_clawback_window(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			window: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				details.clawback_window = window;
+
+				Self::deposit_event(Event::ClawbackWindowSet(id, window));
+				Ok(().into())
+			})
+		}
+
+		/// Burn balances recently minted to an account, while they are within the clawback window
+		/// of the asset.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// The most recent mints are clawed back first. Mints older than the window are forgotten.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to claw back from.
+		/// - `amount`: The most to claw back. Limited to the balance minted within the window.
+		///
+		/// Emits `ClawedBack` with the amount actually burned.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clawback())]
+		pub(super) fn clawback(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+			let mut mints = Self::recent_mints(id, &who, details.clawback_window.unwrap_or_else(Zero::zero));
+			let clawable = mints.iter().fold(T::Balance::zero(), |sum, (minted, _)| sum.saturating_add(*minted));
+			let amount = amount.min(clawable);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClawback);
+
+			let burned = Self::do_burn(id, who.clone(), amount)?;
+			let mut left = burned;
+			while let Some((minted, at)) = mints.pop() {
+				if left < minted {
+					mints.push((minted - left, at));
+					break
+				}
+				left -= minted;
+			}
+			RecentMints::<T>::mutate_exists(id, &who, |m| *m = Some(mints).filter(|m| !m.is_empty()));
+
+			Self::deposit_event(Event::ClawedBack(id, who, burned));
+			Ok(().into())
+		}
+
+		/// Mark an asset as indivisible, so its metadata always has zero decimals, or divisible
+		/// again. The flag is purely informational for clients.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `indivisible`: Whether the asset is indivisible.
+		///
+		/// Emits `IndivisibleSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_indivisible())]
+		pub(super) fn set_indivisible(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			indivisible: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+				if indivisible {
+					Metadata::<T>::try_mutate_exists(id, |maybe_metadata| -> Result<(), DispatchError> {
+						if let Some(metadata) = maybe_metadata {
+							ensure!(
+								!details.decimals_locked || metadata.decimals.is_zero(),
+								Error::<T>::DecimalsLocked,
+							);
+							metadata.decimals = 0;
+						}
+						Ok(())
+					})?;
+				}
+				details.indivisible = indivisible;
+
+				Self::deposit_event(Event::IndivisibleSet(id, indivisible));
+				Ok(().into())
+			})
+		}
+
+		/// Set the metadata of several assets at once, failing as a whole if any of them can not be
+		/// set.
+		///
+		/// Origin must be Signed and the sender should be the Owner of every asset given.
+		///
+		/// Every item is applied like `set_metadata`, reserving or refunding the metadata deposit
+		/// of its asset as needed.
+		///
+		/// - `items`: The identifier, name, symbol and decimals of each asset. At most
+		/// `MaxBatchSize` items may be given.
+		///
+		/// Emits `MetadataSet` for every item.
+		///
+		/// Weight: `O(N + B)` where `N = items.len()` and `B` is the total length of the names and
+		/// symbols.
+		#[pallet::weight(T::WeightInfo::set_metadata_batch(
+			items.len() as u32,
+			items.iter().map(|(_, name, symbol, _)| name.len() + symbol.len()).sum::<usize>() as u32,
+		))]
+		#[transactional]
+		pub(super) fn set_metadata_batch(
+			origin: OriginFor<T>,
+			items: Vec<MetadataItemOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(items.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			for (id, name, symbol, decimals) in items {
+				Self::do_set_metadata(id, &origin, name, symbol, decimals)?;
+			}
+			Ok(().into())
+		}
+
+		/// Set the most any single transfer of an asset may move, or lift the cap.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// Unlike the daily limit, the cap applies to each transfer on its own. `force_transfer` is
+		/// not subject to it.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max`: The new cap, or `None` to lift it.
+		///
+		/// Emits `MaxTransferSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_max_transfer())]
+		pub(super) fn set_max_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max: Option<T::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+				details.max_transfer = max;
+
+				Self::deposit_event(Event::MaxTransferSet(id, max));
+				Ok(().into())
+			})
+		}
+
+		/// Move the whole spendable balance of every holder of an asset to its Owner, in case of
+		/// an emergency.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// Accounts are visited in storage order, resuming after the last account visited by the
+		/// previous call, and each is drained like `force_transfer`. Funds on hold stay put, and
+		/// accounts which cannot be drained are skipped and counted.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_accounts`: The maximum number of accounts to visit in this call.
+		///
+		/// Emits `ForceTransferred` for every account drained, and `Drained` once done.
+		///
+		/// Weight: `O(N)` where `N = max_accounts`
+		#[pallet::weight(T::WeightInfo::drain_to_owner(*max_accounts))]
+		#[transactional]
+		pub(super) fn drain_to_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] max_accounts: u32,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+
+			let prefix = Self::accounts_prefix(id);
+			let mut drain = Drains::<T>::get(id).unwrap_or_default();
+			if drain.cursor.is_empty() {
+				drain.cursor = prefix.clone();
+			}
+			for _ in 0..max_accounts {
+				drain.cursor = match sp_io::storage::next_key(&drain.cursor)
+					.filter(|key| key.starts_with(&prefix))
+				{
+					Some(key) => key,
+					None => {
+						Drains::<T>::remove(id);
+						Self::deposit_event(Event::Drained(
+							id,
+							drain.accounts,
+							drain.total,
+							drain.failed,
+						));
+						return Ok(().into())
+					}
+				};
+				let mut key_material = Blake2_128Concat::reverse(&drain.cursor[prefix.len()..]);
+				if let Ok(who) = T::AccountId::decode(&mut key_material) {
+					let reducible = Account::<T>::get(id, &who).reducible(details.min_balance);
+					if who != details.owner && !reducible.is_zero() {
+						// An account which cannot be drained must not hold up the others.
+						match Self::do_force_transfer(id, who, details.owner.clone(), reducible) {
+							Ok(drained) => {
+								drain.accounts = drain.accounts.saturating_add(1);
+								drain.total = drain.total.saturating_add(drained);
+							}
+							Err(_) => drain.failed = drain.failed.saturating_add(1),
+						}
+					}
+				}
+			}
+			Drains::<T>::insert(id, drain);
+			Ok(().into())
+		}
+
+		/// Reserve an asset id to the sender for `ClaimPeriod` blocks, so nobody else can create
+		/// an asset with it in the meantime.
+		///
+		/// Origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// `ClaimDeposit` is reserved from the sender and returned once the asset is created, or
+		/// once the expired claim is replaced. An expired claim may be replaced by anyone.
+		///
+		/// - `id`: The identifier to claim. Must not be in use, retired or claimed by another
+		/// account.
+		///
+		/// Emits `AssetIdClaimed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_asset_id())]
+		pub(super) fn claim_asset_id(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!RetiredAssets::<T>::contains_key(id), Error::<T>::AssetRetired);
+			ensure!(Self::live_claim(id).is_none(), Error::<T>::IdClaimed);
+			ensure!(!T::PremiumAssetIds::filter(&id), Error::<T>::PremiumId);
+
+			let deposit = T::ClaimDeposit::get();
+			T::Currency::reserve(&origin, deposit)?;
+			if let Some(expired) = ClaimedAssetIds::<T>::take(id) {
+				T::Currency::unreserve(&expired.who, expired.deposit);
+			}
+			let now = frame_system::Module::<T>::block_number();
+			let expires_at = now.saturating_add(T::ClaimPeriod::get());
+			let claim = AssetIdClaim { who: origin.clone(), deposit, expires_at };
+			ClaimedAssetIds::<T>::insert(id, claim);
+
+			Self::deposit_event(Event::AssetIdClaimed(id, origin, expires_at));
+			Ok(().into())
+		}
+
+		/// Create an asset with an id the sender claimed, before the claim expires.
+		///
+		/// This behaves exactly like `create`, except that it fails unless the sender holds a
+		/// claim on `id` which has not expired. The claim deposit is returned.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::complete_claimed_create())]
+		pub(super) fn complete_claimed_create(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_zombies: u32,
+			min_balance: T::Balance,
+			feature_code: u32,
+			existence_model: ExistenceModel,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let claim = Self::live_claim(id).ok_or(Error::<T>::NotClaimed)?;
+			ensure!(claim.who == owner, Error::<T>::NotClaimed);
+
+			Self::do_create(
+				&owner,
+				id,
+				max_zombies,
+				min_balance,
+				feature_code,
+				existence_model,
+				false,
+			)?;
+
+			Self::deposit_event(Event::Created(id, owner));
+			Ok(().into())
+		}
+
+		/// Move exactly some amount of assets from the sender account to another.
+		///
+		/// Origin must be Signed.
+		///
+		/// Unlike `transfer`, a remainder below the minimum balance is never swept along: the
+		/// transfer fails instead, so the sender's balance is reduced by exactly `amount`.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced. Must
+		/// be greater than zero.
+		///
+		/// Emits `Transferred`.
+		///
+		/// Weight: `O(1)`, plus paying the transfer fee
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T>::transfer_fee_weight())
+		)]
+		#[transactional]
+		pub(super) fn transfer_exact(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(Self::asset_exists(id), Error::<T>::Unknown);
+			let dest = T::Lookup::lookup(target)?;
+
+			Self::do_transfer_with_fee(id, &origin, &dest, amount, TransferMode::Exact)?;
+			Ok(().into())
+		}
+
+		/// Bid for a premium asset id, which may only be created by winning an auction for it.
+		///
+		/// Origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// The first bid opens an auction which takes bids for `IdAuctionDuration` blocks. Each bid
+		/// must exceed the highest bid so far. The bid is reserved from the sender and the bid it
+		/// beats is returned to its bidder.
+		///
+		/// - `id`: The identifier to bid for. Must be premium and not in use or retired.
+		/// - `amount`: The bid.
+		///
+		/// Emits `IdBid`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::bid_for_id())]
+		pub(super) fn bid_for_id(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(T::PremiumAssetIds::filter(&id), Error::<T>::NotPremiumId);
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!RetiredAssets::<T>::contains_key(id), Error::<T>::AssetRetired);
+
+			let now = frame_system::Module::<T>::block_number();
+			let auction = match IdAuctions::<T>::get(id) {
+				Some(auction) => {
+					ensure!(now < auction.ends_at, Error::<T>::AuctionEnded);
+					ensure!(amount > auction.bid, Error::<T>::BidTooLow);
+					T::Currency::reserve(&origin, amount)?;
+					T::Currency::unreserve(&auction.bidder, auction.bid);
+					IdAuction { bidder: origin.clone(), bid: amount, ..auction }
+				}
+				None => {
+					ensure!(!amount.is_zero(), Error::<T>::BidTooLow);
+					T::Currency::reserve(&origin, amount)?;
+					let ends_at = now.saturating_add(T::IdAuctionDuration::get());
+					IdAuction { bidder: origin.clone(), bid: amount, ends_at }
+				}
+			};
+			IdAuctions::<T>::insert(id, auction);
+
+			Self::deposit_event(Event::IdBid(id, origin, amount));
+			Ok(().into())
+		}
+
+		/// Create an asset with a premium id the sender won the auction for.
+		///
+		/// This behaves exactly like `create`, except that it fails unless the auction for `id` has
+		/// ended with the highest bid placed by the sender, no more than `IdAuctionClaimPeriod`
+		/// blocks ago. The bid is kept as a fee and goes to `IdAuctionProceeds`.
+		///
+		/// Emits `Created` and `IdAuctionWon` when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_won_id())]
+		#[transactional]
+		pub(super) fn claim_won_id(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_zombies: u32,
+			min_balance: T::Balance,
+			feature_code: u32,
+			existence_model: ExistenceModel,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let auction = Self::ended_auction(id).ok_or(Error::<T>::AuctionOngoing)?;
+			ensure!(auction.bidder == owner, Error::<T>::NotAuctionWinner);
+			ensure!(!Self::auction_lapsed(&auction), Error::<T>::AuctionLapsed);
+
+			Self::do_create(
+				&owner,
+				id,
+				max_zombies,
+				min_balance,
+				feature_code,
+				existence_model,
+				true,
+			)?;
+			IdAuctions::<T>::remove(id);
+			let (imbalance, _) = T::Currency::slash_reserved(&owner, auction.bid);
+			T::IdAuctionProceeds::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::Created(id, owner.clone()));
+			Self::deposit_event(Event::IdAuctionWon(id, owner, auction.bid));
+			Ok(().into())
+		}
+
+		/// Close an auction for a premium asset id whose winner did not claim the id in time.
+		///
+		/// Origin must be Signed.
+		///
+		/// The auction must have ended more than `IdAuctionClaimPeriod` blocks ago. The winning bid
+		/// is returned to its bidder and the id may be auctioned again.
+		///
+		/// - `id`: The identifier of the auction to close.
+		///
+		/// Emits `IdAuctionLapsed` when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::close_lapsed_auction())]
+		pub(super) fn close_lapsed_auction(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let auction = Self::ended_auction(id)
+				.filter(Self::auction_lapsed)
+				.ok_or(Error::<T>::AuctionNotLapsed)?;
+
+			IdAuctions::<T>::remove(id);
+			T::Currency::unreserve(&auction.bidder, auction.bid);
+
+			Self::deposit_event(Event::IdAuctionLapsed(id, auction.bidder, auction.bid));
+			Ok(().into())
+		}
+
+		/// Remove the `Feature` and `Metadata` entries of assets which no longer exist, as left
+		/// behind by destroying assets before those entries were removed along with them.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Feature entries are visited first, then metadata entries, in storage order, resuming
+		/// after the last entry visited by the previous call. The deposits held for orphaned
+		/// metadata are left reserved, as they can no longer be traced to an asset.
+		///
+		/// - `max_scan`: The maximum number of entries to visit in this call.
+		///
+		/// Emits `OrphansCleared` with the number of entries removed once every entry was visited.
+		///
+		/// Weight: `O(N)` where `N = max_scan`
+		#[pallet::weight(T::WeightInfo::force_clear_orphans(*max_scan))]
+		pub(super) fn force_clear_orphans(
+			origin: OriginFor<T>,
+			#[pallet::compact] max_scan: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let features = Feature::<T>::final_prefix().to_vec();
+			let metadata = Metadata::<T>::final_prefix().to_vec();
+			let mut sweep = OrphanSweeps::<T>::get().unwrap_or_default();
+			if sweep.cursor.is_empty() {
+				sweep.cursor = features.clone();
+			}
+			let mut scanned = 0;
+			while scanned < max_scan {
+				let prefix = if sweep.cursor.starts_with(&metadata) { &metadata } else { &features };
+				sweep.cursor = match sp_io::storage::next_key(&sweep.cursor)
+					.filter(|key| key.starts_with(prefix))
+				{
+					Some(key) => key,
+					None if prefix == &features => {
+						sweep.cursor = metadata.clone();
+						continue
+					}
+					None => {
+						OrphanSweeps::<T>::kill();
+						Self::deposit_event(Event::OrphansCleared(sweep.cleared));
+						return Ok(().into())
+					}
+				};
+				scanned += 1;
+				let mut key_material = Blake2_128Concat::reverse(&sweep.cursor[prefix.len()..]);
+				if let Ok(id) = T::AssetId::decode(&mut key_material) {
+					if !Asset::<T>::contains_key(id) {
+						if prefix == &features {
+							Self::remove_feature(id);
+						} else {
+							Metadata::<T>::remove(id);
+						}
+						sweep.cleared = sweep.cleared.saturating_add(1);
+					}
+				}
+			}
+			OrphanSweeps::<T>::put(sweep);
+			Ok(().into())
+		}
+
+		/// Flag an asset as cross-chain, so its transfers and burns are reported to
+		/// `CrossChainNotifier`, or clear the flag.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `cross_chain`: Whether the asset is cross-chain.
+		///
+		/// Emits `CrossChainSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_cross_chain())]
+		pub(super) fn set_cross_chain(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			cross_chain: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+				details.cross_chain = cross_chain;
+
+				Self::deposit_event(Event::CrossChainSet(id, cross_chain));
+				Ok(().into())
+			})
+		}
+
+		/// Exempt an account, such as a treasury or a pool, from the minimum balance of an asset,
+		/// or lift the exemption.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// An exempt account may hold any non-zero balance and is never swept or reaped as dust.
+		/// It still counts towards `accounts` and `zombies` like any other holder, and is reaped
+		/// once its balance reaches zero. Lifting the exemption leaves a balance already below the
+		/// minimum alone until the account next moves funds.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to exempt.
+		/// - `exempt`: Whether the account is exempt.
+		///
+		/// Emits `ExemptionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_exempt())]
+		pub(super) fn set_exempt(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			exempt: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(Self::asset_exists(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+
+			if exempt {
+				ExemptAccounts::<T>::insert(id, &who, ());
+			} else {
+				ExemptAccounts::<T>::remove(id, &who);
+			}
+
+			Self::deposit_event(Event::ExemptionSet(id, who, exempt));
+			Ok(().into())
+		}
+
+		/// Mint an amount of an asset split between a list of accounts in proportion to their
+		/// shares.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// Each recipient is credited `amount * shares / total_shares`, rounded down, and what the
+		/// rounding leaves over goes to the first recipient, so exactly `amount` is minted. Unlike
+		/// `airdrop`, the call fails as a whole if any recipient cannot be credited.
+		///
+		/// - `id`: The identifier of the asset to be distributed.
+		/// - `amount`: The total amount of the asset to mint.
+		/// - `recipients`: The accounts to be credited with their shares. At most `MaxBatchSize`
+		/// recipients may be given, and their shares must not all be zero.
+		///
+		/// Emits `Issued` for every recipient credited with a non-zero amount.
+		///
+		/// Weight: `O(N)` where `N = recipients.len()`
+		#[pallet::weight(T::WeightInfo::distribute(recipients.len() as u32))]
+		#[transactional]
+		pub(super) fn distribute(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] amount: T::Balance,
+			recipients: Vec<(<T::Lookup as StaticLookup>::Source, u32)>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(recipients.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			ensure!(T::AssetAdmin::is_issuer(&origin), Error::<T>::NoPermission);
+
+			let total_shares = recipients.iter().map(|(_, shares)| *shares as u128).sum::<u128>();
+			ensure!(total_shares > 0, Error::<T>::NoShares);
+			let total: u128 = amount.saturated_into();
+			let mut parts = Vec::with_capacity(recipients.len());
+			for (recipient, shares) in recipients {
+				let who = T::Lookup::lookup(recipient)?;
+				// `shares <= total_shares`, so the part never exceeds `total`.
+				let part = multiply_by_rational(total, shares as u128, total_shares).unwrap_or(0);
+				parts.push((who, part));
+			}
+			let remainder = total - parts.iter().map(|(_, part)| part).sum::<u128>();
+			parts[0].1 += remainder;
+
+			for (who, part) in parts {
+				if part > 0 {
+					Self::do_mint(id, who, part.saturated_into())?;
+				}
+			}
+			Ok(().into())
+		}
+
+		/// Make the owner, issuer, admin and freezer of an asset need an entry in its destination
+		/// allowlist like any other account, or let them always receive it again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `strict`: Whether the team of the asset loses its exemption from the allowlist.
+		///
+		/// Emits `StrictWhitelistSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_strict_whitelist())]
+		pub(super) fn set_strict_whitelist(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			strict: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(T::AssetAdmin::is_admin(&origin), Error::<T>::NoPermission);
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				details.strict_whitelist = strict;
+
+				Self::deposit_event(Event::StrictWhitelistSet(id, strict));
+				Ok(().into())
+			})
+		}
+
+		/// Burn a share of every transfer of an asset, on top of any transfer fee.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The burned share is `burn_rate_bps` basis points of the amount sent, rounded down, so
+		/// transfers small enough for it to round to zero burn nothing. The rest is delivered as
+		/// usual.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `burn_rate_bps`: The share to burn in basis points. At most `MaxBurnRateBps`.
+		///
+		/// Emits `BurnRateSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_burn_rate())]
+		pub(super) fn set_burn_rate(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			burn_rate_bps: u16,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(burn_rate_bps <= T::MaxBurnRateBps::get(), Error::<T>::BurnRateTooHigh);
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T>::NoPermission);
+				details.burn_rate_bps = burn_rate_bps;
+
+				Self::deposit_event(Event::BurnRateSet(id, burn_rate_bps));
+				Ok(().into())
+			})
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", T::Balance = "Balance", T::AssetId = "AssetId")]
+	pub enum Event<T: Config> {
+		/// Some asset class was created. \[asset_id, creator\]
+		Created(T::AssetId, T::AccountId),
+		/// Some assets were issued. \[asset_id, owner, total_supply\]
+		Issued(T::AssetId, T::AccountId, T::Balance),
+		/// Some assets were transferred. \[asset_id, from, to, amount\]
 		Transferred(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// Some assets were transferred, with the amount split by the decimals of the asset.
+		/// \[asset_id, from, to, integer_part, fractional_part, decimals\]
+		TransferredHuman(T::AssetId, T::AccountId, T::AccountId, T::Balance, T::Balance, u8),
 		/// Some assets were destroyed. \[asset_id, owner, balance\]
 		Burned(T::AssetId, T::AccountId, T::Balance),
 		/// The owner changed \[asset_id, owner\]
 		OwnerChanged(T::AssetId, T::AccountId),
 		/// Some assets was transferred by an admin. \[asset_id, from, to, amount\]
 		ForceTransferred(T::AssetId, T::AccountId, T::AccountId, T::Balance),
-		/// Some account `who` was frozen. \[asset_id, who\]
-		Frozen(T::AssetId, T::AccountId),
+		/// Some account `who` was frozen. \[asset_id, who, reason\]
+		Frozen(T::AssetId, T::AccountId, u8),
 		/// Some account `who` was thawed. \[asset_id, who\]
 		Thawed(T::AssetId, T::AccountId),
-		/// Some asset `asset_id` was frozen. \[asset_id\]
-		AssetFrozen(T::AssetId),
+		/// Some asset `asset_id` was frozen. \[asset_id, reason\]
+		AssetFrozen(T::AssetId, u8),
 		/// Some asset `asset_id` was thawed. \[asset_id\]
 		AssetThawed(T::AssetId),
 		/// An asset class was destroyed.
@@ -919,173 +3824,2583 @@ pub mod pallet {
 		MaxZombiesChanged(T::AssetId, u32),
 		/// New metadata has been set for an asset. \[asset_id, name, symbol, decimals\]
 		MetadataSet(T::AssetId, Vec<u8>, Vec<u8>, u8),
+		/// Some asset class was created with a derived identifier. \[asset_id, creator, salt\]
+		DeterministicCreated(T::AssetId, T::AccountId, [u8; 32]),
+		/// (Additional) funds have been approved for transfer to a destination account.
+		/// \[asset_id, source, delegate, amount\]
+		ApprovedTransfer(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// An approval for account `delegate` was cancelled by `owner`.
+		/// \[asset_id, owner, delegate\]
+		ApprovalCancelled(T::AssetId, T::AccountId, T::AccountId),
+		/// An `amount` was transferred in its entirety from `owner` to `destination` by
+		/// the approved `delegate`.
+		/// \[asset_id, owner, delegate, destination, amount\]
+		TransferredApproved(T::AssetId, T::AccountId, T::AccountId, T::AccountId, T::Balance),
+		/// The transfer limit per window of an asset has changed. \[asset_id, limit\]
+		DailyLimitSet(T::AssetId, Option<T::Balance>),
+		/// A retired asset id was released for reuse. \[asset_id\]
+		RetiredPurged(T::AssetId),
+		/// An airdrop has finished. \[asset_id, succeeded, skipped\]
+		AirdropCompleted(T::AssetId, u32, u32),
+		/// The invariants of an asset were checked. \[asset_id, supply_ok, accounts_ok, zombies_ok\]
+		InvariantCheck(T::AssetId, bool, bool, bool),
+		/// Part of the deposits of a destroyed asset was kept as a fee. \[asset_id, fee\]
+		DestroyFeePaid(T::AssetId, BalanceOf<T>),
+		/// A payment stream was started. \[stream_id, asset_id, from, to, total\]
+		StreamCreated(u64, T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// Some assets were withdrawn from a payment stream. \[stream_id, to, amount\]
+		StreamWithdrawn(u64, T::AccountId, T::Balance),
+		/// A payment stream was cancelled. \[stream_id, paid_to_recipient, returned_to_sender\]
+		StreamCancelled(u64, T::Balance, T::Balance),
+		/// The large transfer threshold of an asset has changed. \[asset_id, threshold\]
+		WhaleThresholdSet(T::AssetId, Option<T::Balance>),
+		/// A transfer reached the large transfer threshold of the asset. \[asset_id, from, to, amount\]
+		LargeTransfer(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// Dust balances were purged across assets. \[accounts, assets\]
+		GlobalDustPurged(u32, u32),
+		/// Every holder of an asset was credited in an asset spun off from it. \[old_asset_id, new_asset_id\]
+		SpunOff(T::AssetId, T::AssetId),
+		/// The transfer cooldown of an asset has changed. \[asset_id, cooldown\]
+		TransferCooldownSet(T::AssetId, Option<T::BlockNumber>),
+		/// The community minting of an asset has changed. \[asset_id, community_mint\]
+		CommunityMintSet(T::AssetId, Option<CommunityMint<T::Balance, T::BlockNumber>>),
+		/// A snapshot of the balances of an asset was started. \[snapshot_id, asset_id, block\]
+		SnapshotStarted(u64, T::AssetId, T::BlockNumber),
+		/// Every holder was copied into a snapshot. \[snapshot_id\]
+		SnapshotCompleted(u64),
+		/// A snapshot was removed. \[snapshot_id\]
+		SnapshotDiscarded(u64),
+		/// More was burned than the recorded supply of an asset, which was clamped to zero.
+		/// \[asset_id, supply, burned\]
+		SupplyUnderflow(T::AssetId, T::Balance, T::Balance),
+		/// The decimals of an asset can no longer change. \[asset_id\]
+		DecimalsLocked(T::AssetId),
+		/// An account may mint an asset for a while. \[asset_id, who, until, cap\]
+		TempIssuerGranted(T::AssetId, T::AccountId, T::BlockNumber, T::Balance),
+		/// An account may no longer mint an asset. \[asset_id, who\]
+		TempIssuerRevoked(T::AssetId, T::AccountId),
+		/// Some assets of an account were put on hold. \[asset_id, who, amount, reason\]
+		Held(T::AssetId, T::AccountId, T::Balance, u8),
+		/// Some assets of an account were released from hold. \[asset_id, who, amount, reason\]
+		HoldReleased(T::AssetId, T::AccountId, T::Balance, u8),
+		/// Some assets on hold were settled to another account. \[asset_id, from, to, amount, reason\]
+		HoldTransferred(T::AssetId, T::AccountId, T::AccountId, T::Balance, u8),
+		/// A resumable batch was started. \[batch_id, asset_id, items\]
+		BatchStarted(u64, T::AssetId, u32),
+		/// A resumable batch ran out of weight and must be continued. \[batch_id, processed, items\]
+		BatchProgress(u64, u32, u32),
+		/// A resumable batch has processed all its items. \[batch_id, succeeded, skipped\]
+		BatchCompleted(u64, u32, u32),
+		/// Burning the balances of an asset was allowed or forbidden. \[asset_id, burnable\]
+		BurnableSet(T::AssetId, bool),
+		/// A deprecated asset may be converted into another. \[old_id, new_id, rate\]
+		ConversionSet(T::AssetId, T::AssetId, Permill),
+		/// Some of a deprecated asset was converted. \[who, old_id, new_id, burned, minted\]
+		Converted(T::AccountId, T::AssetId, T::AssetId, T::Balance, T::Balance),
+		/// Transfers of an asset were restricted to allowed destinations or freed. \[asset_id, enabled\]
+		DestinationWhitelistSet(T::AssetId, bool),
+		/// An account was allowed as a transfer destination. \[asset_id, who\]
+		DestinationAdded(T::AssetId, T::AccountId),
+		/// An account is no longer allowed as a transfer destination. \[asset_id, who\]
+		DestinationRemoved(T::AssetId, T::AccountId),
+		/// The expiry of an asset was set. \[asset_id, expires_at\]
+		ExpirySet(T::AssetId, Option<T::BlockNumber>),
+		/// An asset has expired and can no longer be minted or transferred. \[asset_id\]
+		AssetExpired(T::AssetId),
+		/// The redemption rate of an asset was set. \[asset_id, rate\]
+		RedemptionRateSet(T::AssetId, Option<BalanceOf<T>>),
+		/// The redemption reserve of an asset was funded. \[asset_id, amount\]
+		RedemptionFunded(T::AssetId, BalanceOf<T>),
+		/// Some assets were redeemed for native currency. \[asset_id, who, burned, paid\]
+		Redeemed(T::AssetId, T::AccountId, T::Balance, BalanceOf<T>),
+		/// The supply of some assets at a heartbeat. \[block, \[(asset_id, supply)\]\]
+		SupplyReport(T::BlockNumber, Vec<(T::AssetId, T::Balance)>),
+		/// The transfer fee of an asset was set. \[asset_id, fee\]
+		TransferFeeSet(T::AssetId, Option<TransferFee<T::Balance>>),
+		/// A transfer fee was paid to the Owner. \[asset_id, payer, fee\]
+		TransferFeeCharged(T::AssetId, T::AccountId, T::Balance),
+		/// An account was migrated to a new account. \[asset_id, old, new\]
+		AccountMigrated(T::AssetId, T::AccountId, T::AccountId),
+		/// The counters of an asset were recomputed from its accounts.
+		/// \[asset_id, accounts, zombies, supply\]
+		AssetRecounted(T::AssetId, u32, u32, T::Balance),
+		/// An asset class was imported from a legacy pallet. \[asset_id, owner\]
+		LegacyImported(T::AssetId, T::AccountId),
+		/// Some holders of a legacy asset were imported. \[asset_id, count\]
+		LegacyAccountsImported(T::AssetId, u32),
+		/// Every holder of a legacy asset was imported. \[asset_id\]
+		LegacyImportCompleted(T::AssetId),
+		/// The clawback window of an asset was set. \[asset_id, window\]
+		ClawbackWindowSet(T::AssetId, Option<T::BlockNumber>),
+		/// Recently minted balances were clawed back. \[asset_id, who, amount\]
+		ClawedBack(T::AssetId, T::AccountId, T::Balance),
+		/// Whether an asset is indivisible was set. \[asset_id, indivisible\]
+		IndivisibleSet(T::AssetId, bool),
+		/// The cap on single transfers of an asset was set. \[asset_id, max\]
+		MaxTransferSet(T::AssetId, Option<T::Balance>),
+		/// The holders of an asset were drained to its Owner.
+		/// \[asset_id, accounts, total, failed\]
+		Drained(T::AssetId, u32, T::Balance, u32),
+		/// An asset id was claimed ahead of its creation. \[asset_id, who, expires_at\]
+		AssetIdClaimed(T::AssetId, T::AccountId, T::BlockNumber),
+		/// Some asset `asset_id` was frozen until a block. \[asset_id, reason, until\]
+		AssetFrozenUntil(T::AssetId, u8, T::BlockNumber),
+		/// An asset id was bid for. \[asset_id, who, amount\]
+		IdBid(T::AssetId, T::AccountId, BalanceOf<T>),
+		/// An asset id auction was won and the asset created. \[asset_id, who, amount\]
+		IdAuctionWon(T::AssetId, T::AccountId, BalanceOf<T>),
+		/// An asset id auction was closed unclaimed and the bid returned. \[asset_id, who, amount\]
+		IdAuctionLapsed(T::AssetId, T::AccountId, BalanceOf<T>),
+		/// Feature and metadata entries left behind by destroyed assets were removed. \[count\]
+		OrphansCleared(u32),
+		/// The cross-chain flag of an asset was set. \[asset_id, cross_chain\]
+		CrossChainSet(T::AssetId, bool),
+		/// The exemption of an account from the minimum balance of an asset was set.
+		/// \[asset_id, who, exempt\]
+		ExemptionSet(T::AssetId, T::AccountId, bool),
+		/// Whether the team of an asset needs to be in its destination allowlist changed.
+		/// \[asset_id, strict\]
+		StrictWhitelistSet(T::AssetId, bool),
+		/// A share of each transfer of an asset is burned. \[asset_id, burn_rate_bps\]
+		BurnRateSet(T::AssetId, u16),
+	}
+
+	#[deprecated(note = "use `Event` instead")]
+	pub type RawEvent<T> = Event<T>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Transfer amount should be non-zero.
+		AmountZero,
+		/// Account balance must be greater than or equal to the transfer amount.
+		BalanceLow,
+		/// Balance should be non-zero.
+		BalanceZero,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The origin account is frozen.
+		AccountFrozen,
+		/// The asset is frozen.
+		AssetFrozen,
+		/// The asset ID is already taken.
+		InUse,
+		/// Too many zombie accounts in use.
+		TooManyZombies,
+		/// Attempt to destroy an asset class when non-zombie, reference-bearing accounts exist.
+		RefsLeft,
+		/// Invalid witness data given.
+		BadWitness,
+		/// Minimum balance should be non-zero.
+		MinBalanceZero,
+		/// A mint operation lead to an overflow.
+		Overflow,
+		/// Some internal state is broken.
+		BadState,
+		/// Invalid metadata given.
+		BadMetadata,
+		/// Invalid feature point.
+		BadFeaturePoint,
+		/// The transfer was rejected by the transfer policy.
+		PolicyViolation,
+		/// No approval exists that would allow the transfer.
+		Unapproved,
+		/// The transfer would exceed the sender's limit for the current window.
+		DailyLimitExceeded,
+		/// The asset id belonged to a destroyed asset and may not be reused.
+		AssetRetired,
+		/// Too many items were passed to a batched call.
+		BatchTooLarge,
+		/// Only assets using the `Zombie` existence model may have zombie accounts.
+		ZombiesNotAllowed,
+		/// The account does not exist in the system and the asset cannot provide for it.
+		NoProvider,
+		/// The minimum balance is above `MaxMinBalance`.
+		MinBalanceTooHigh,
+		/// The given payment stream ID is unknown.
+		UnknownStream,
+		/// A payment stream must end after the current block.
+		InvalidStreamEnd,
+		/// No spin-off into the given asset is in progress.
+		UnknownSpinOff,
+		/// The account transferred this asset too recently.
+		TransferCooldown,
+		/// The asset does not allow community minting.
+		NoCommunityMint,
+		/// The account already minted this asset in the current period.
+		CommunityMintTooSoon,
+		/// The given snapshot ID is unknown.
+		UnknownSnapshot,
+		/// Every holder was already copied into the snapshot.
+		SnapshotComplete,
+		/// The decimals of the asset can no longer change.
+		DecimalsLocked,
+		/// The temporary issuer would mint more than its cap.
+		TempIssuerCapExceeded,
+		/// The account is not a temporary issuer of the asset.
+		NotTempIssuer,
+		/// The account has less on hold for the reason than requested.
+		InsufficientHold,
+		/// The batch is unknown or already complete.
+		UnknownBatch,
+		/// The balances of the asset may not be burned.
+		BurnDisabled,
+		/// Less than the minimum amount would be received.
+		SlippageExceeded,
+		/// The asset has no conversion set.
+		NoConversion,
+		/// The account is not an allowed transfer destination of the asset.
+		NotAllowed,
+		/// The asset has expired.
+		AssetExpired,
+		/// The asset cannot be redeemed.
+		NotRedeemable,
+		/// The redemption reserve cannot cover the payout.
+		InsufficientRedemptionReserve,
+		/// The minimum transfer fee exceeds the amount from which it applies.
+		BadTransferFee,
+		/// The account already holds the asset.
+		AccountExists,
+		/// The account has funds on hold.
+		FundsOnHold,
+		/// The asset is not being imported.
+		NotImporting,
+		/// The imported balances exceed the legacy supply.
+		ImportExceedsSupply,
+		/// Nothing was minted to the account within the clawback window.
+		NothingToClawback,
+		/// The amount is above the most a single transfer of the asset may move.
+		AboveMaxTransfer,
+		/// The asset id is claimed by another account.
+		IdClaimed,
+		/// The sender holds no claim on the asset id which has not expired.
+		NotClaimed,
+		/// The asset id may only be created by winning an auction for it.
+		PremiumId,
+		/// The asset id is not auctioned.
+		NotPremiumId,
+		/// The bid does not exceed the highest bid so far.
+		BidTooLow,
+		/// The auction for the asset id no longer takes bids.
+		AuctionEnded,
+		/// The auction for the asset id still takes bids, or there is none.
+		AuctionOngoing,
+		/// The sender did not win the auction for the asset id.
+		NotAuctionWinner,
+		/// The winner of the auction for the asset id may no longer claim it.
+		AuctionLapsed,
+		/// The winner of the auction for the asset id may still claim it, or there is no auction
+		/// which ended.
+		AuctionNotLapsed,
+		/// A balance mutation is in progress, so the pallet may not be re-entered.
+		Reentrancy,
+		/// The recipients of a distribution hold no shares between them.
+		NoShares,
+		/// The decimals are too many for one unit to fit in the balance type.
+		DecimalsOverflow,
+		/// The burn rate is above `MaxBurnRateBps`.
+		BurnRateTooHigh,
+		/// The deposit of a legacy asset is not reserved on the account of its owner.
+		DepositNotReserved,
+	}
+
+	#[pallet::storage]
+	/// Details of an asset.
+	pub(super) type Asset<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>
+	>;
+	#[pallet::storage]
+	/// The Feature of an asset
+	pub(super) type Feature<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetFeature
+	>;
+	#[pallet::storage]
+	/// The number of assets with each destiny rank.
+	pub(super) type FeatureHistogram<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		FeatureDestinyRank,
+		u32,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The number of units of assets held by any given account.
+	pub(super) type Account<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		AssetBalanceOf<T>,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// Approved balance transfers. First balance is the amount approved for transfer. Second
+	/// is the amount of `T::Currency` reserved for storing this.
+	pub(super) type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		Approval<T::Balance, BalanceOf<T>>,
+	>;
+	#[pallet::storage]
+	/// Metadata of an asset.
+	pub(super) type Metadata<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetMetadata<BalanceOf<T>>,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The amount transferred out by an account within the current rate-limiting window, and
+	/// the block at which that window started.
+	pub(super) type TransferredToday<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::Balance, T::BlockNumber),
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The ids of destroyed assets which may not be reused.
+	pub(super) type RetiredAssets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+	>;
+	#[pallet::storage]
+	/// The payment streams which have not been fully withdrawn yet.
+	pub(super) type Streams<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Stream<T::AccountId, T::AssetId, T::Balance, T::BlockNumber>,
+	>;
+	#[pallet::storage]
+	/// The identifier of the next payment stream.
+	pub(super) type NextStreamId<T: Config> = StorageValue<_, u64, ValueQuery>;
+	#[pallet::storage]
+	/// The amount of an asset held in escrow by payment streams. It is part of the supply but
+	/// belongs to no account.
+	pub(super) type Escrowed<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		T::Balance,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The assets which are currently frozen.
+	pub(super) type FrozenAssets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+	>;
+	#[pallet::storage]
+	/// The storage key of the last account visited by `purge_all_dust`, or empty to start from
+	/// the first account.
+	pub(super) type DustCursor<T: Config> = StorageValue<_, Vec<u8>, ValueQuery>;
+	#[pallet::storage]
+	/// The spin-offs which have not credited every holder yet, by the new asset.
+	pub(super) type SpinOffs<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		SpinOff<T::AssetId>,
+	>;
+	#[pallet::storage]
+	/// The block at which an account last transferred an asset with a transfer cooldown.
+	pub(super) type LastTransfer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+	#[pallet::storage]
+	/// The block at which an account last minted an asset through `community_mint`.
+	pub(super) type LastCommunityMint<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+	#[pallet::storage]
+	/// The snapshots of asset balances.
+	pub(super) type Snapshots<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Snapshot<T::AssetId, T::BlockNumber>,
+	>;
+	#[pallet::storage]
+	/// The identifier of the next snapshot.
+	pub(super) type NextSnapshotId<T: Config> = StorageValue<_, u64, ValueQuery>;
+	#[pallet::storage]
+	/// The balances copied into a snapshot.
+	pub(super) type SnapshotBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The accounts which may mint an asset for a limited time and amount.
+	pub(super) type TempIssuers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		TempIssuer<T::Balance, T::BlockNumber>,
+	>;
+	#[pallet::storage]
+	/// The funds of an account on hold for each reason.
+	pub(super) type HoldReasons<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		(T::AccountId, u8),
+		T::Balance,
+		ValueQuery,
+	>;
+	#[pallet::storage]
+	/// The resumable batches which still have items left to process.
+	pub(super) type PendingBatches<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		PendingBatch<T::AssetId, T::AccountId, T::Balance>,
+	>;
+
+	#[pallet::storage]
+	/// The identifier of the next resumable batch.
+	pub(super) type NextBatchId<T: Config> = StorageValue<_, u64, ValueQuery>;
+	#[pallet::storage]
+	/// The asset each deprecated asset may be converted into, and at what rate.
+	pub(super) type Conversions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		(T::AssetId, Permill),
+	>;
+	#[pallet::storage]
+	/// The accounts which transfers may credit while an asset has its destination whitelist on.
+	pub(super) type DestinationAllowlist<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+	#[pallet::storage]
+	/// The assets expiring at each block.
+	pub(super) type Expiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Vec<T::AssetId>,
+		ValueQuery,
+	>;
+	#[pallet::storage]
+	/// The approvals held by each delegate, as the asset and the owner who approved it.
+	pub(super) type DelegateApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::AssetId, T::AccountId),
+		(),
+	>;
+	#[pallet::storage]
+	/// The native currency paid for each unit of an asset redeemed.
+	pub(super) type RedemptionRate<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BalanceOf<T>
+	>;
+	#[pallet::storage]
+	/// The native currency reserved on the Owner's account to pay for redemptions of an asset.
+	pub(super) type RedemptionReserve<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BalanceOf<T>,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// The storage key of the last asset reported by the supply heartbeat, empty to start over.
+	pub(super) type SupplyReportCursor<T: Config> = StorageValue<_, Vec<u8>, ValueQuery>;
+	#[pallet::storage]
+	/// The fee charged on transfers of an asset, if any.
+	pub(super) type TransferFees<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		TransferFee<T::Balance>
+	>;
+	#[pallet::storage]
+	/// The recounts of assets in progress.
+	pub(super) type Recounts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Recount<T::Balance>,
+	>;
+	#[pallet::storage]
+	/// The legacy supply of an asset still to be imported.
+	pub(super) type LegacyImports<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		T::Balance,
+	>;
+	#[pallet::storage]
+	/// The mints to an account, with the block they happened at, while the asset has a clawback
+	/// window. Mints which left the window are dropped lazily.
+	pub(super) type RecentMints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		Vec<(T::Balance, T::BlockNumber)>,
+		ValueQuery,
+	>;
+	#[pallet::storage]
+	/// The drains of assets back to their Owner in progress.
+	pub(super) type Drains<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Drain<T::Balance>,
+	>;
+	#[pallet::storage]
+	/// The claims on asset ids which are yet to be created.
+	pub(super) type ClaimedAssetIds<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetIdClaim<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	>;
+	#[pallet::storage]
+	/// The assets owned by each account.
+	pub(super) type OwnerAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+	>;
+	#[pallet::storage]
+	/// The assets created by each account, whoever owns them now.
+	pub(super) type CreatorAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+	>;
+	#[pallet::storage]
+	/// The auctions for premium asset ids, with their highest bid.
+	pub(super) type IdAuctions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		IdAuction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	>;
+	#[pallet::storage]
+	/// The sweep for orphaned feature and metadata entries in progress.
+	pub(super) type OrphanSweeps<T: Config> = StorageValue<_, OrphanSweep>;
+	#[pallet::storage]
+	/// The latest activity of each asset, oldest first, if `RecordActivity` is set.
+	pub(super) type RecentActivity<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Vec<ActivityEntry<T::AccountId, T::BlockNumber>>,
+		ValueQuery
+	>;
+	#[pallet::storage]
+	/// Set while a balance mutation is in progress, so the hooks it calls cannot re-enter the
+	/// pallet.
+	pub(super) type MutationInProgress<T: Config> = StorageValue<_, bool, ValueQuery>;
+	#[pallet::storage]
+	/// The accounts which may hold any non-zero balance of an asset, however far below its
+	/// minimum balance, without being swept or reaped as dust.
+	pub(super) type ExemptAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The assets to create, each with the supply and account counts its seeded accounts must
+		/// add up to.
+		pub assets: Vec<GenesisAsset<T::AssetId, T::AccountId, T::Balance>>,
+		/// The balance of each account in each asset: `(asset_id, who, balance)`.
+		pub accounts: Vec<(T::AssetId, T::AccountId, T::Balance)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				assets: Default::default(),
+				accounts: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for asset in &self.assets {
+				let id = asset.id;
+				assert!(!Asset::<T>::contains_key(id), "asset {:?} is seeded twice", id);
+				assert!(
+					asset.max_zombies.is_zero() || asset.existence_model == ExistenceModel::Zombie,
+					"only zombie assets may have zombies, which asset {:?} is not",
+					id,
+				);
+				OwnerAssets::<T>::insert(&asset.owner, id, ());
+				CreatorAssets::<T>::insert(&asset.owner, id, ());
+				Asset::<T>::insert(id, AssetDetails {
+					owner: asset.owner.clone(),
+					supply: asset.supply,
+					deposit: Zero::zero(),
+					max_zombies: asset.max_zombies,
+					min_balance: asset.min_balance,
+					zombies: Zero::zero(),
+					accounts: Zero::zero(),
+					approvals: Zero::zero(),
+					is_frozen: false,
+					is_featured: true,
+					daily_limit: None,
+					existence_model: asset.existence_model,
+					whale_threshold: None,
+					transfer_cooldown: None,
+					community_mint: None,
+					decimals_locked: false,
+					burnable: true,
+					destination_whitelist: false,
+					expires_at: None,
+					freeze_reason: 0,
+					clawback_window: None,
+					indivisible: false,
+					max_transfer: None,
+					frozen_until: None,
+					cross_chain: false,
+					strict_whitelist: false,
+					burn_rate_bps: 0,
+					creator: asset.owner.clone(),
+				});
+				let feature = Pallet::<T>::new_feature_detail(asset.feature_code);
+				Pallet::<T>::insert_feature(id, feature);
+			}
+
+			for (id, who, balance) in &self.accounts {
+				let mut details = Asset::<T>::get(id)
+					.unwrap_or_else(|| panic!("account {:?} holds unknown asset {:?}", who, id));
+				assert!(
+					!Account::<T>::contains_key(id, who),
+					"account {:?} of asset {:?} is seeded twice",
+					who,
+					id,
+				);
+				assert!(
+					*balance >= details.min_balance,
+					"account {:?} holds less than the minimum balance of asset {:?}",
+					who,
+					id,
+				);
+				let mut account = AssetBalance { balance: *balance, ..Default::default() };
+				if let Err(e) = Pallet::<T>::new_account(who, &mut details, &mut account) {
+					panic!("account {:?} cannot hold asset {:?}: {:?}", who, id, e);
+				}
+				Account::<T>::insert(id, who, account);
+				Asset::<T>::insert(id, details);
+			}
+
+			// Catch a chain spec whose totals disagree with its accounts now, rather than leave the
+			// chain to run on broken accounting.
+			for asset in &self.assets {
+				let details = Asset::<T>::get(asset.id).expect("inserted above; qed");
+				let held = Account::<T>::iter_prefix_values(asset.id)
+					.fold(T::Balance::zero(), |sum, account| sum.saturating_add(account.balance));
+				assert!(
+					details.supply == held,
+					"supply of asset {:?} is {:?}, but its accounts hold {:?}",
+					asset.id,
+					details.supply,
+					held,
+				);
+				assert!(
+					details.accounts == asset.accounts,
+					"asset {:?} declares {} accounts, but {} are seeded",
+					asset.id,
+					asset.accounts,
+					details.accounts,
+				);
+				assert!(
+					details.zombies == asset.zombies,
+					"asset {:?} declares {} zombies, but {} are seeded",
+					asset.id,
+					asset.zombies,
+					details.zombies,
+				);
+			}
+		}
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct AssetDetails<
+	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
+	AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
+	DepositBalance: Encode + Decode + Clone + Debug + Eq + PartialEq,
+	BlockNumber: Encode + Decode + Clone + Debug + Eq + PartialEq,
+> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
+	owner: AccountId,
+	/// The total supply across all accounts.
+	supply: Balance,
+	/// The balance deposited for this asset.
+	///
+	/// This pays for the data stored here together with any virtual accounts.
+	deposit: DepositBalance,
+	/// The number of balance-holding accounts that this asset may have, excluding those that were
+	/// created when they had a system-level ED.
+	max_zombies: u32,
+	/// The ED for virtual accounts.
+	min_balance: Balance,
+	/// The current number of zombie accounts.
+	zombies: u32,
+	/// The total number of accounts.
+	accounts: u32,
+	/// The total number of approvals.
+	approvals: u32,
+	/// Whether the asset is frozen for permissionless transfers.
+	is_frozen: bool,
+	/// Whether the asset is a featured asset
+	is_featured: bool,
+	/// The most an account may transfer out within one `RateLimitWindow`, if limited.
+	daily_limit: Option<Balance>,
+	/// How accounts holding the asset are kept in existence.
+	existence_model: ExistenceModel,
+	/// The transfer amount from which a `LargeTransfer` event is emitted, if any.
+	whale_threshold: Option<Balance>,
+	/// The number of blocks an account must wait between two transfers, if any.
+	transfer_cooldown: Option<BlockNumber>,
+	/// How much any account may mint of the asset for itself per period, if anything.
+	community_mint: Option<CommunityMint<Balance, BlockNumber>>,
+	/// Whether the decimals of the asset may no longer change.
+	decimals_locked: bool,
+	/// Whether the balances of the asset may be burned.
+	burnable: bool,
+	/// Whether transfers may only credit the accounts in `DestinationAllowlist`.
+	destination_whitelist: bool,
+	/// The block from which the asset can no longer be minted or transferred.
+	expires_at: Option<BlockNumber>,
+	/// Why the asset is frozen, if it is.
+	freeze_reason: u8,
+	/// The number of blocks during which minted balances may be clawed back, if any.
+	clawback_window: Option<BlockNumber>,
+	/// Whether the asset can not be divided, so its metadata always has zero decimals.
+	indivisible: bool,
+	/// The most that may be moved by a single transfer, if limited.
+	max_transfer: Option<Balance>,
+	/// The last block during which the asset stays frozen, if the freeze lifts by itself.
+	frozen_until: Option<BlockNumber>,
+	/// Whether transfers and burns of the asset are reported to `CrossChainNotifier`.
+	cross_chain: bool,
+	/// Whether the team of the asset needs to be in `DestinationAllowlist` like anyone else.
+	strict_whitelist: bool,
+	/// The share of each transfer burned, in basis points.
+	burn_rate_bps: u16,
+	/// The account which created the asset, kept when ownership moves.
+	creator: AccountId,
+}
+
+/// How much of an asset any account may mint for itself.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct CommunityMint<Balance, BlockNumber> {
+	/// The amount an account mints each time.
+	pub per_period: Balance,
+	/// The number of blocks an account must wait between two mints.
+	pub period_blocks: BlockNumber,
+}
+
+/// The minting rights of a temporary issuer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct TempIssuer<Balance, BlockNumber> {
+	/// The last block at which the issuer may mint.
+	until: BlockNumber,
+	/// The total amount the issuer may mint.
+	cap: Balance,
+	/// The amount the issuer has minted so far.
+	minted: Balance,
+}
+
+/// How the accounts holding an asset are kept in existence.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ExistenceModel {
+	/// Accounts without a system-level existence become zombies, paid for by the asset deposit
+	/// and limited by `max_zombies`.
+	Zombie,
+	/// Holding the asset is enough to keep an account in existence.
+	Sufficient,
+	/// Only accounts which already exist in the system may hold the asset.
+	SystemRefOnly,
+}
+
+/// Marks a balance mutation as in progress until dropped. Acquiring it while another mutation is
+/// in progress fails, which stops hooks from re-entering the pallet.
+struct MutationGuard<T: Config>(PhantomData<T>);
+
+impl<T: Config> MutationGuard<T> {
+	fn acquire() -> Result<Self, DispatchError> {
+		ensure!(!MutationInProgress::<T>::get(), Error::<T>::Reentrancy);
+		MutationInProgress::<T>::put(true);
+		Ok(Self(PhantomData))
+	}
+}
+
+impl<T: Config> Drop for MutationGuard<T> {
+	fn drop(&mut self) {
+		MutationInProgress::<T>::kill();
+	}
+}
+
+/// An asset created at genesis.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GenesisAsset<AssetId, AccountId, Balance> {
+	/// The identifier of the asset.
+	pub id: AssetId,
+	/// The owner of the asset.
+	pub owner: AccountId,
+	/// The most accounts which may hold the asset without a system-level existence.
+	pub max_zombies: u32,
+	/// The minimum balance of any account holding the asset.
+	pub min_balance: Balance,
+	/// How accounts holding the asset are kept in existence.
+	pub existence_model: ExistenceModel,
+	/// The code the feature of the asset is derived from.
+	pub feature_code: u32,
+	/// The total supply, which must equal the sum of the seeded balances.
+	pub supply: Balance,
+	/// The number of seeded accounts.
+	pub accounts: u32,
+	/// The number of seeded accounts which are zombies.
+	pub zombies: u32,
+}
+
+/// What happened to an asset in an entry of its recent activity.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ActivityKind {
+	/// Some of the asset was transferred.
+	Transferred,
+	/// Some of the asset was minted.
+	Issued,
+	/// Some of the asset was burned.
+	Burned,
+	/// An account or the whole asset was frozen.
+	Frozen,
+}
+
+/// An entry of the recent activity of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ActivityEntry<AccountId, BlockNumber> {
+	/// What happened.
+	pub kind: ActivityKind,
+	/// The block it happened in.
+	pub block: BlockNumber,
+	/// The account it happened to: the sender, the beneficiary, the account burned from or the
+	/// account frozen, or the freezer when the whole asset was frozen.
+	pub who: AccountId,
+	/// The recipient of a transfer.
+	pub counterparty: Option<AccountId>,
+}
+
+/// What a transfer does when it would leave the source with a balance below the minimum.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+enum TransferMode {
+	/// The remainder is moved along with the amount, taking the source balance to zero.
+	Sweep,
+	/// The transfer fails, so exactly the amount asked for is moved or nothing at all.
+	Exact,
+}
+
+/// Why an account cannot currently be credited with an asset.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum ReceiveError {
+	/// The asset does not exist.
+	UnknownAsset,
+	/// The account would be a zombie and the asset has no zombie allowance left.
+	TooManyZombies,
+	/// The account does not exist in the system and the asset cannot provide for it.
+	NoProvider,
+	/// The asset has expired.
+	Expired,
+	/// The asset only credits the accounts on its destination whitelist, which lacks the account.
+	NotAllowlisted,
+}
+
+/// The predicted outcome of a transfer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct TransferSimulation<Balance> {
+	/// The amount the recipient would be credited with, after fees and including any remainder
+	/// swept along.
+	pub delivered: Balance,
+	/// Whether the sender would be left with nothing and reaped.
+	pub sender_collapses: bool,
+	/// Whether the recipient would start holding the asset.
+	pub recipient_created: bool,
+	/// Why the transfer would fail, if it would.
+	pub error: Option<DispatchError>,
+}
+
+/// Whether an account can currently be credited with an asset, and if not, why.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct CanReceive {
+	/// Whether crediting the account would succeed.
+	pub possible: bool,
+	/// Why crediting the account would fail, if it would.
+	pub reason: Option<ReceiveError>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct AssetBalance<
+	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
+	BlockNumber,
+	Extra,
+> {
+	/// The balance.
+	balance: Balance,
+	/// Whether the account is frozen (`FROZEN`) and whether it is a zombie (`ZOMBIE`). If not a
+	/// zombie, then it has a reference.
+	flags: u8,
+	/// The part of `balance` on hold, summed over all hold reasons.
+	on_hold: Balance,
+	/// Why the account is frozen, if it is.
+	freeze_reason: u8,
+	/// The block the account was created at.
+	created_at: BlockNumber,
+	/// Data kept with the account on behalf of the runtime.
+	extra: Extra,
+}
+
+impl<Balance: Encode + Decode + Clone + Debug + Eq + PartialEq, BlockNumber, Extra>
+	AssetBalance<Balance, BlockNumber, Extra>
+{
+	/// The flag set on frozen accounts.
+	const FROZEN: u8 = 1 << 0;
+	/// The flag set on zombie accounts.
+	const ZOMBIE: u8 = 1 << 1;
+
+	/// Whether the account is frozen.
+	pub fn is_frozen(&self) -> bool {
+		self.flags & Self::FROZEN != 0
+	}
+
+	/// Whether the account is a zombie. If not, then it has a reference.
+	pub fn is_zombie(&self) -> bool {
+		self.flags & Self::ZOMBIE != 0
+	}
+
+	fn set_frozen(&mut self, frozen: bool) {
+		self.set_flag(Self::FROZEN, frozen)
+	}
+
+	fn set_zombie(&mut self, zombie: bool) {
+		self.set_flag(Self::ZOMBIE, zombie)
+	}
+
+	fn set_flag(&mut self, flag: u8, value: bool) {
+		if value {
+			self.flags |= flag;
+		} else {
+			self.flags &= !flag;
+		}
+	}
+}
+
+impl<Balance: AtLeast32BitUnsigned + Encode + Decode + Clone + Debug + Copy, BlockNumber, Extra>
+	AssetBalance<Balance, BlockNumber, Extra>
+{
+	/// The part of the balance which may leave the account. An account with funds on hold keeps
+	/// both those funds and at least `min_balance`, so it is never reaped.
+	fn reducible(&self, min_balance: Balance) -> Balance {
+		if self.on_hold.is_zero() {
+			self.balance
+		} else {
+			self.balance.saturating_sub(self.on_hold.max(min_balance))
+		}
+	}
+}
+
+/// Data concerning an approval.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct Approval<Balance, DepositBalance> {
+	/// The amount of funds approved for the balance transfer from the owner to some delegated
+	/// target.
+	amount: Balance,
+	/// The amount reserved on the owner's account to hold this item in storage.
+	deposit: DepositBalance,
+}
+
+/// Everything a frontend needs to show about one asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetOverview<Balance, AccountId> {
+	/// The owner of the asset.
+	pub owner: AccountId,
+	/// The total supply across all accounts.
+	pub supply: Balance,
+	/// The ED for virtual accounts.
+	pub min_balance: Balance,
+	/// The total number of accounts.
+	pub accounts: u32,
+	/// The current number of zombie accounts.
+	pub zombies: u32,
+	/// The number of zombie accounts the asset may have.
+	pub max_zombies: u32,
+	/// Whether the asset is frozen for permissionless transfers.
+	pub is_frozen: bool,
+	/// How accounts holding the asset are kept in existence.
+	pub existence_model: ExistenceModel,
+	/// The feature of the asset.
+	pub feature: Option<AssetFeature>,
+	/// The user friendly name of the asset.
+	pub name: Vec<u8>,
+	/// The ticker symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals the asset uses to represent one unit.
+	pub decimals: u8,
+	/// Whether the asset can not be divided, so its decimals are always zero.
+	pub indivisible: bool,
+}
+
+/// A payment of an asset from one account to another, paid out evenly every block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct Stream<AccountId, AssetId, Balance, BlockNumber> {
+	/// The account paying the stream.
+	from: AccountId,
+	/// The account receiving the stream.
+	to: AccountId,
+	/// The asset being streamed.
+	id: AssetId,
+	/// The amount which becomes withdrawable every block.
+	rate_per_block: Balance,
+	/// The block the stream started at.
+	start: BlockNumber,
+	/// The block at which the stream is fully paid out.
+	end: BlockNumber,
+	/// The amount the recipient has withdrawn so far.
+	withdrawn: Balance,
+}
+
+impl<AccountId, AssetId, Balance, BlockNumber> Stream<AccountId, AssetId, Balance, BlockNumber> where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The amount paid out over the blocks from `start` until `until`, or `None` on overflow.
+	fn paid_until(&self, until: BlockNumber) -> Option<Balance> {
+		let blocks = until.min(self.end).saturating_sub(self.start);
+		self.rate_per_block.checked_mul(&blocks.saturated_into::<u128>().saturated_into())
+	}
+
+	/// The amount the stream pays out in total, or `None` on overflow.
+	fn total(&self) -> Option<Balance> {
+		self.paid_until(self.end)
+	}
+
+	/// The amount the stream has paid out by block `now`, withdrawn or not.
+	fn vested(&self, now: BlockNumber) -> Balance {
+		self.paid_until(now).unwrap_or_else(Zero::zero)
+	}
+}
+
+/// A spin-off in progress, crediting the holders of `old_id`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct SpinOff<AssetId> {
+	/// The asset whose holders are credited.
+	old_id: AssetId,
+	/// The share of each balance of `old_id` which is credited.
+	ratio: Permill,
+	/// The storage key of the last account visited, or empty if none was visited yet.
+	cursor: Vec<u8>,
+}
+
+/// A resumable batch of mints, processed over as many calls as its weight requires.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct PendingBatch<AssetId, AccountId, Balance> {
+	/// The asset being minted.
+	id: AssetId,
+	/// The accounts to be credited, each with the amount to mint to it.
+	items: Vec<(AccountId, Balance)>,
+	/// The index of the next item to process.
+	next: u32,
+	/// The number of items credited so far.
+	succeeded: u32,
+	/// The number of items which could not be credited so far.
+	skipped: u32,
+}
+
+/// A copy of the balances of the holders of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct Snapshot<AssetId, BlockNumber> {
+	/// The asset whose balances are copied.
+	id: AssetId,
+	/// The block the snapshot was started at.
+	block: BlockNumber,
+	/// The number of holders copied so far.
+	holders: u32,
+	/// The storage key of the last account copied, or empty if none was copied yet.
+	cursor: Vec<u8>,
+	/// Whether every holder has been copied.
+	complete: bool,
+}
+
+/// The counters of an asset recomputed so far by a recount in progress.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct Recount<Balance> {
+	/// The number of accounts counted so far.
+	accounts: u32,
+	/// The number of zombie accounts counted so far.
+	zombies: u32,
+	/// The sum of the balances counted so far.
+	supply: Balance,
+	/// The storage key of the last account counted, or empty if none was counted yet.
+	cursor: Vec<u8>,
+}
+
+/// A claim on an asset id, reserving it to an account for a while before the asset is created.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct AssetIdClaim<AccountId, DepositBalance, BlockNumber> {
+	/// The account the asset id is reserved to.
+	who: AccountId,
+	/// The funds reserved from `who` for the claim.
+	deposit: DepositBalance,
+	/// The block from which the claim no longer holds.
+	expires_at: BlockNumber,
+}
+
+/// An auction for a premium asset id, as it stands after its highest bid.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct IdAuction<AccountId, DepositBalance, BlockNumber> {
+	/// The account which placed the highest bid.
+	bidder: AccountId,
+	/// The highest bid, reserved from `bidder`.
+	bid: DepositBalance,
+	/// The block from which no more bids are taken.
+	ends_at: BlockNumber,
+}
+
+/// The progress of a drain of an asset back to its Owner.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct Drain<Balance> {
+	/// The number of accounts drained so far.
+	accounts: u32,
+	/// The amount drained so far.
+	total: Balance,
+	/// The number of accounts which could not be drained so far.
+	failed: u32,
+	/// The storage key of the last account visited, or empty if none was visited yet.
+	cursor: Vec<u8>,
+}
+
+/// The progress of a sweep for feature and metadata entries of assets which no longer exist.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct OrphanSweep {
+	/// The number of entries removed so far.
+	cleared: u32,
+	/// The storage key of the last entry visited, or empty if none was visited yet.
+	cursor: Vec<u8>,
+}
+
+/// The details of an asset class in a legacy assets pallet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct LegacyAssetDetails<Balance, AccountId, DepositBalance> {
+	/// The owner of the asset.
+	pub owner: AccountId,
+	/// The total supply across all accounts.
+	pub supply: Balance,
+	/// The balance deposited for this asset, still reserved on the owner's account.
+	pub deposit: DepositBalance,
+	/// The number of balance-holding accounts that this asset may have, excluding those that
+	/// were created when they had a system-level ED.
+	pub max_zombies: u32,
+	/// The ED for virtual accounts.
+	pub min_balance: Balance,
+	/// Whether the asset is frozen for permissionless transfers.
+	pub is_frozen: bool,
+}
+
+/// The fee charged on transfers of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct TransferFee<Balance> {
+	/// The share of the amount transferred taken as a fee, rounded down.
+	pub rate: Permill,
+	/// The least fee paid by a transfer of at least `min_fee_threshold`.
+	pub min_fee: Balance,
+	/// The amount from which `min_fee` applies.
+	pub min_fee_threshold: Balance,
+}
+
+/// Witness data for the destroy transactions.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct DestroyWitness {
+	/// The number of zombie accounts holding the asset.
+	#[codec(compact)]
+	pub zombies: u32,
+	/// The number of transfer approvals of the asset.
+	#[codec(compact)]
+	pub approvals: u32,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+pub struct AssetMetadata<DepositBalance> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	deposit: DepositBalance,
+	/// The user friendly name of this asset. Limited in length by `StringLimit`.
+	name: Vec<u8>,
+	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
+	symbol: Vec<u8>,
+	/// The number of decimals this asset uses to represent one unit.
+	decimals: u8,
+}
+
+// Featured Part for asset
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetFeature {
+	/// The level of this asset
+	destiny: FeatureDestinyRank,
+	/// The 'hue' identity of this asset
+	elements: FeatureElements,
+	/// The 'saturation' of this asset
+	saturation: FeatureRankedLevel,
+	/// The 'lightness' of this asset
+	lightness: FeatureLevel
+}
+
+/// Derives asset identifiers by hashing `(owner, salt)` with the system hasher.
+pub struct HashedAssetId<T>(PhantomData<T>);
+impl<T: Config> AssetIdDeriver<T::AccountId, T::AssetId> for HashedAssetId<T> {
+	fn derive_asset_id(owner: &T::AccountId, salt: &[u8; 32]) -> T::AssetId {
+		let hash = T::Hashing::hash_of(&(owner, salt));
+		T::AssetId::decode(&mut TrailingZeroInput::new(hash.as_ref())).unwrap_or_default()
+	}
+}
+
+/// Maps `u32` or `u128` asset identifiers to addresses holding the big-endian identifier in the
+/// last four or sixteen bytes, all other bytes being zero.
+pub struct ZeroPaddedAssetId;
+impl Convert<u32, [u8; 20]> for ZeroPaddedAssetId {
+	fn convert(id: u32) -> [u8; 20] {
+		let mut address = [0u8; 20];
+		address[16..].copy_from_slice(&id.to_be_bytes());
+		address
+	}
+}
+impl Convert<[u8; 20], Option<u32>> for ZeroPaddedAssetId {
+	fn convert(address: [u8; 20]) -> Option<u32> {
+		if address[..16].iter().any(|byte| *byte != 0) {
+			return None
+		}
+		let mut id = [0u8; 4];
+		id.copy_from_slice(&address[16..]);
+		Some(u32::from_be_bytes(id))
+	}
+}
+impl Convert<u128, [u8; 20]> for ZeroPaddedAssetId {
+	fn convert(id: u128) -> [u8; 20] {
+		let mut address = [0u8; 20];
+		address[4..].copy_from_slice(&id.to_be_bytes());
+		address
+	}
+}
+impl Convert<[u8; 20], Option<u128>> for ZeroPaddedAssetId {
+	fn convert(address: [u8; 20]) -> Option<u128> {
+		if address[..4].iter().any(|byte| *byte != 0) {
+			return None
+		}
+		let mut id = [0u8; 16];
+		id.copy_from_slice(&address[4..]);
+		Some(u128::from_be_bytes(id))
+	}
+}
+
+// The main implementation block for the module.
+impl<T: Config> Pallet<T> {
+	// Public immutables
+
+	/// Whether the asset `id` exists.
+	pub fn asset_exists(id: T::AssetId) -> bool {
+		Asset::<T>::contains_key(id)
+	}
+
+	/// Get the asset `id` balance of `who`.
+	pub fn balance(id: T::AssetId, who: T::AccountId) -> T::Balance {
+		Account::<T>::get(id, who).balance
+	}
+
+	/// Why the account `who` is frozen for the asset `id`, or `None` if it is not frozen.
+	pub fn freeze_reason(id: T::AssetId, who: T::AccountId) -> Option<u8> {
+		let account = Account::<T>::get(id, who);
+		if account.is_frozen() { Some(account.freeze_reason) } else { None }
+	}
+
+	/// Why the asset `id` is frozen, or `None` if it is not frozen or does not exist.
+	pub fn asset_freeze_reason(id: T::AssetId) -> Option<u8> {
+		Asset::<T>::get(id).filter(|d| Self::freeze_in_force(d)).map(|d| d.freeze_reason)
+	}
+
+	/// Whether the asset `id` is indivisible, so its metadata always has zero decimals.
+	pub fn is_indivisible(id: T::AssetId) -> bool {
+		Asset::<T>::get(id).filter(|d| d.indivisible).is_some()
+	}
+
+	/// Get the total supply of an asset `id`, or `None` if it does not exist.
+	pub fn try_total_supply(id: T::AssetId) -> Option<T::Balance> {
+		Asset::<T>::get(id).map(|x| x.supply)
+	}
+
+	/// Get the total supply of an asset `id`.
+	pub fn total_supply(id: T::AssetId) -> T::Balance {
+		Asset::<T>::get(id).map(|x| x.supply).unwrap_or_else(Zero::zero)
+	}
+
+	/// Check the number of zombies allow yet for an asset.
+	pub fn zombie_allowance(id: T::AssetId) -> u32 {
+		Asset::<T>::get(id).map(|x| x.max_zombies - x.zombies).unwrap_or_else(Zero::zero)
+	}
+
+	/// Get the feature info of the asset
+	pub fn feature(id: T::AssetId) -> Option<AssetFeature> {
+		Feature::<T>::get(id)
+	}
+
+	/// Whether the asset `id` is featured together with its feature, or `None` if it does not
+	/// exist. A featured asset without a feature points to inconsistent storage.
+	pub fn feature_status(id: T::AssetId) -> Option<(bool, Option<AssetFeature>)> {
+		Asset::<T>::get(id).map(|d| (d.is_featured, Feature::<T>::get(id)))
+	}
+
+	/// The assets created by `creator` which still exist, even if their ownership has moved since.
+	///
+	/// Weight: `O(A)` where `A` is the number of assets created by `creator`.
+	pub fn assets_created_by(creator: T::AccountId) -> Vec<T::AssetId> {
+		CreatorAssets::<T>::iter_prefix(&creator).map(|(id, ())| id).collect()
+	}
+
+	/// The sum of the asset and metadata deposits of every asset owned by `owner`.
+	///
+	/// Weight: `O(A)` where `A` is the number of assets owned by `owner`.
+	pub fn total_deposit_of(owner: T::AccountId) -> BalanceOf<T> {
+		OwnerAssets::<T>::iter_prefix(&owner)
+			.filter_map(|(id, ())| Asset::<T>::get(id).map(|d| (id, d.deposit)))
+			.fold(Zero::zero(), |total: BalanceOf<T>, (id, deposit)| {
+				total.saturating_add(deposit).saturating_add(Metadata::<T>::get(id).deposit)
+			})
+	}
+
+	/// The number of assets with each destiny rank, from the lowest rank to the highest.
+	pub fn feature_histogram() -> Vec<(FeatureDestinyRank, u32)> {
+		(0..4u8).map(FeatureDestinyRank::from)
+			.map(|rank| (rank.clone(), FeatureHistogram::<T>::get(rank)))
+			.collect()
+	}
+
+	/// Get up to `limit` of the currently frozen assets, continuing after `start_after` if given.
+	///
+	/// Assets are returned in storage order, which is stable as long as the set of frozen assets
+	/// does not change. Nothing is returned if `start_after` is no longer frozen.
+	pub fn frozen_assets(start_after: Option<T::AssetId>, limit: u32) -> Vec<T::AssetId> {
+		let mut ids = FrozenAssets::<T>::iter().map(|(id, _)| id);
+		if let Some(start_after) = start_after {
+			if !ids.any(|id| id == start_after) {
+				return Vec::new()
+			}
+		}
+		ids.take(limit as usize).collect()
+	}
+
+	/// Get up to `limit` approvals held by `delegate`, after the approval of asset `start_after.0`
+	/// by owner `start_after.1` if given, with the amount still approved.
+	///
+	/// Approvals are returned in storage order, which is stable as long as the approvals of
+	/// `delegate` do not change. Nothing is returned if `start_after` is no longer approved.
+	pub fn approvals_for_delegate(
+		delegate: T::AccountId,
+		start_after: Option<(T::AssetId, T::AccountId)>,
+		limit: u32,
+	) -> Vec<(T::AssetId, T::AccountId, T::Balance)> {
+		let mut keys = DelegateApprovals::<T>::iter_prefix(&delegate).map(|(key, _)| key);
+		if let Some(start_after) = start_after {
+			if !keys.any(|key| key == start_after) {
+				return Vec::new()
+			}
+		}
+		keys.take(limit as usize)
+			.map(|(id, owner)| {
+				let amount = Approvals::<T>::get(id, (&owner, &delegate)).map(|a| a.amount).unwrap_or_default();
+				(id, owner, amount)
+			})
+			.collect()
+	}
+
+	/// Get the details, feature and metadata of asset `id` at once.
+	pub fn asset_overview(id: T::AssetId) -> Option<AssetOverview<T::Balance, T::AccountId>> {
+		let details = Asset::<T>::get(id)?;
+		let metadata = Metadata::<T>::get(id);
+		let is_frozen = Self::freeze_in_force(&details);
+		Some(AssetOverview {
+			owner: details.owner,
+			supply: details.supply,
+			min_balance: details.min_balance,
+			accounts: details.accounts,
+			zombies: details.zombies,
+			max_zombies: details.max_zombies,
+			is_frozen,
+			existence_model: details.existence_model,
+			feature: Feature::<T>::get(id),
+			name: metadata.name,
+			symbol: metadata.symbol,
+			decimals: metadata.decimals,
+			indivisible: details.indivisible,
+		})
+	}
+
+	/// Get the Ethereum style address of asset `id`.
+	pub fn eth_address_of(id: T::AssetId) -> [u8; 20] {
+		<T::AssetIdConverter as Convert<T::AssetId, [u8; 20]>>::convert(id)
+	}
+
+	/// Get the asset at the Ethereum style `address`, if there is one.
+	pub fn asset_of_eth_address(address: [u8; 20]) -> Option<T::AssetId> {
+		<T::AssetIdConverter as Convert<[u8; 20], Option<T::AssetId>>>::convert(address)
+			.filter(|id| Asset::<T>::contains_key(id))
+	}
+
+	/// Get the balance of `who` copied into snapshot `snapshot_id`.
+	pub fn snapshot_balance(snapshot_id: u64, who: T::AccountId) -> T::Balance {
+		SnapshotBalances::<T>::get(snapshot_id, who)
+	}
+
+	/// Get the amount of asset `id` that `who` is able to transfer right now, accounting for
+	/// freezes, funds on hold, the transfer limit of the current window and the dust left behind.
+	pub fn transferable_now(id: T::AssetId, who: T::AccountId) -> T::Balance {
+		let details = match Asset::<T>::get(id) {
+			Some(details) => details,
+			None => return Zero::zero(),
+		};
+		let account = Account::<T>::get(id, &who);
+		if details.is_frozen || account.is_frozen() {
+			return Zero::zero();
+		}
+
+		let reducible = account.reducible(details.min_balance);
+		let remaining = match details.daily_limit {
+			Some(limit) => limit.saturating_sub(Self::window_outflow(id, &who).0),
+			None => return reducible,
+		};
+		if remaining >= reducible {
+			reducible
+		} else {
+			// Leaving less than `min_balance` behind would sweep the dust into the transfer too.
+			remaining.min(account.balance.saturating_sub(details.min_balance))
+		}
+	}
+
+	/// Get the feature code which `new_feature_detail` decodes into `feature`.
+	pub fn feature_code_of(feature: &AssetFeature) -> u32 {
+		let feature = feature.clone();
+		let destiny: u8 = feature.destiny.into();
+		let lightness: u8 = feature.lightness.into();
+		let saturation: u8 = feature.saturation.into();
+		let elements: u16 = feature.elements.into();
+		(destiny as u32) << 28 | (lightness as u32) << 24 | (saturation as u32) << 16 | elements as u32
+	}
+
+	/// Predict the outcome of `from` transferring `amount` of asset `id` to `to` with `transfer`.
+	///
+	/// The transfer is carried out in a storage transaction which is always rolled back, so the
+	/// prediction takes every fee, sweep, restriction and hook into account and changes nothing.
+	pub fn simulate_transfer(
+		id: T::AssetId,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: T::Balance,
+	) -> TransferSimulation<T::Balance> {
+		with_transaction(|| {
+			let existed = Account::<T>::contains_key(id, &to);
+			let simulation = match Self::do_plain_transfer(id, &from, &to, amount) {
+				Ok(delivered) => TransferSimulation {
+					delivered,
+					sender_collapses: !Account::<T>::contains_key(id, &from),
+					recipient_created: !existed && Account::<T>::contains_key(id, &to),
+					error: None,
+				},
+				Err(error) => TransferSimulation {
+					delivered: Zero::zero(),
+					sender_collapses: false,
+					recipient_created: false,
+					error: Some(error),
+				},
+			};
+			TransactionOutcome::Rollback(simulation)
+		})
+	}
+
+	/// Predict whether `who` can currently be credited with asset `id` by a mint or transfer.
+	pub fn can_receive(id: T::AssetId, who: T::AccountId) -> CanReceive {
+		let reason = match Asset::<T>::get(id) {
+			None => Some(ReceiveError::UnknownAsset),
+			Some(details) if Self::has_expired(&details) => Some(ReceiveError::Expired),
+			Some(details) if !Self::allowlisted(id, &details, &who) => {
+				Some(ReceiveError::NotAllowlisted)
+			}
+			Some(_) if Account::<T>::contains_key(id, &who) => None,
+			Some(details) => {
+				let exists = frame_system::Module::<T>::account_exists(&who);
+				match details.existence_model {
+					ExistenceModel::Sufficient => None,
+					_ if exists => None,
+					ExistenceModel::Zombie if details.zombies < details.max_zombies => None,
+					ExistenceModel::Zombie => Some(ReceiveError::TooManyZombies),
+					ExistenceModel::SystemRefOnly => Some(ReceiveError::NoProvider),
+				}
+			}
+		};
+		CanReceive { possible: reason.is_none(), reason }
+	}
+
+	/// Whether crediting `who` with asset `id` would make them a zombie, an account of the asset
+	/// without an existence of their own in the system. `None` if `who` already holds the asset
+	/// or the asset does not exist.
+	///
+	/// Only assets under the `Zombie` existence model have zombies; whether `who` may receive the
+	/// asset at all is up to `can_receive`.
+	pub fn would_be_zombie(id: T::AssetId, who: T::AccountId) -> Option<bool> {
+		let details = Asset::<T>::get(id)?;
+		if Account::<T>::contains_key(id, &who) {
+			return None
+		}
+		Some(
+			details.existence_model == ExistenceModel::Zombie
+				&& !frame_system::Module::<T>::account_exists(&who),
+		)
+	}
+
+	/// Get the deposit reserved from the owner for creating an asset with `max_zombies`.
+	pub fn create_deposit(max_zombies: u32) -> BalanceOf<T> {
+		T::AssetDepositPerZombie::get()
+			.saturating_mul(max_zombies.into())
+			.saturating_add(T::AssetDepositBase::get())
+	}
+
+	/// Get the deposit reserved from the owner for metadata with a name and a symbol of the given
+	/// lengths.
+	pub fn metadata_deposit(name_len: u32, symbol_len: u32) -> BalanceOf<T> {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul(name_len.saturating_add(symbol_len).into())
+			.saturating_add(T::MetadataDepositBase::get())
+	}
+
+	/// The amount burned out of a transfer of `amount` of asset `id`, rounded down. Nothing is
+	/// burned while the balances of the asset may not be burned.
+	pub fn transfer_burn(id: T::AssetId, amount: T::Balance) -> T::Balance {
+		let bps = Asset::<T>::get(id)
+			.filter(|d| d.burnable)
+			.map(|d| d.burn_rate_bps)
+			.unwrap_or_default();
+		Permill::from_parts(u32::from(bps) * 100).mul_floor(amount)
+	}
+
+	/// The fee charged on a transfer of `amount` of asset `id`.
+	pub fn transfer_fee(id: T::AssetId, amount: T::Balance) -> T::Balance {
+		let fee = match TransferFees::<T>::get(id) {
+			Some(fee) if !fee.rate.is_zero() => fee,
+			_ => return Zero::zero(),
+		};
+		let charged = fee.rate.mul_floor(amount);
+		if amount >= fee.min_fee_threshold {
+			charged.max(fee.min_fee)
+		} else {
+			charged
+		}
+	}
+
+	/// The text form of the asset identifier `id`.
+	pub fn format_asset_id(id: T::AssetId) -> Vec<u8> {
+		T::AssetIdDisplay::to_string(&id)
+	}
+
+	/// The asset identifier written as `s`, if it is one.
+	pub fn parse_asset_id(s: Vec<u8>) -> Option<T::AssetId> {
+		T::AssetIdDisplay::from_string(&s)
+	}
+
+	/// Split `amount` of asset `id` into whole units and the remainder, according to the decimals
+	/// in its metadata, which are returned alongside.
+	pub fn scaled_amount(id: T::AssetId, amount: T::Balance) -> (T::Balance, T::Balance, u8) {
+		let decimals = Metadata::<T>::get(id).decimals;
+		// A unit too large for the balance type is more than any amount.
+		match Self::checked_pow10(decimals) {
+			Some(unit) => (amount / unit, amount % unit, decimals),
+			None => (Zero::zero(), amount, decimals),
+		}
+	}
+
+	/// One unit of an asset with `decimals` decimals, i.e. `10^decimals`, or `None` if it does not
+	/// fit in the balance type.
+	pub fn checked_pow10(decimals: u8) -> Option<T::Balance> {
+		let ten = T::Balance::from(10u32);
+		(0..decimals).try_fold(T::Balance::from(1u32), |unit, _| unit.checked_mul(&ten))
+	}
+
+	/// The latest activity of the asset `id`, oldest first. Empty unless `RecordActivity` is set.
+	pub fn recent_activity(id: T::AssetId) -> Vec<ActivityEntry<T::AccountId, T::BlockNumber>> {
+		RecentActivity::<T>::get(id)
+	}
+
+	/// Check the accounting of every asset: at most as many zombies as accounts, as many accounts
+	/// as stored in `Account`, a supply equal to the balances and escrow of those accounts and a
+	/// feature for every featured asset.
+	///
+	/// Meant for try-runtime style tooling after executing blocks. Iterates over all accounts of
+	/// all assets, so it must never be called on-chain.
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (id, details) in Asset::<T>::iter() {
+			ensure!(details.zombies <= details.accounts, "an asset has more zombies than accounts");
+
+			let mut supply = Escrowed::<T>::get(id);
+			let mut accounts = 0u32;
+			for account in Account::<T>::iter_prefix_values(id) {
+				supply = supply.saturating_add(account.balance);
+				accounts = accounts.saturating_add(1);
+			}
+			ensure!(accounts == details.accounts, "the account count of an asset is wrong");
+			ensure!(supply == details.supply, "the supply of an asset is not held by its accounts");
+			ensure!(
+				!details.is_featured || Feature::<T>::contains_key(id),
+				"a featured asset has no feature",
+			);
+		}
+		Ok(())
+	}
+
+	/// Reserve the creation deposit from `owner` and register a new featured asset `id`.
+	///
+	/// A premium id is only accepted when `won_auction`, as set by `claim_won_id` alone.
+	fn do_create(
+		owner: &T::AccountId,
+		id: T::AssetId,
+		max_zombies: u32,
+		min_balance: T::Balance,
+		feature_code: u32,
+		existence_model: ExistenceModel,
+		won_auction: bool,
+	) -> Result<(), DispatchError> {
+		ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+		ensure!(!RetiredAssets::<T>::contains_key(id), Error::<T>::AssetRetired);
+		ensure!(Self::live_claim(id).filter(|c| &c.who != owner).is_none(), Error::<T>::IdClaimed);
+		ensure!(won_auction || !T::PremiumAssetIds::filter(&id), Error::<T>::PremiumId);
+		ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+		ensure!(min_balance <= T::MaxMinBalance::get(), Error::<T>::MinBalanceTooHigh);
+		ensure!(!feature_code.is_zero(), Error::<T>::BadFeaturePoint);
+		ensure!(
+			max_zombies.is_zero() || existence_model == ExistenceModel::Zombie,
+			Error::<T>::ZombiesNotAllowed,
+		);
+
+		let deposit = Self::create_deposit(max_zombies);
+		Self::reserve_deposit(id, owner, deposit)?;
+		if let Some(claim) = ClaimedAssetIds::<T>::take(id) {
+			T::Currency::unreserve(&claim.who, claim.deposit);
+		}
+
+		OwnerAssets::<T>::insert(owner, id, ());
+		CreatorAssets::<T>::insert(owner, id, ());
+		Asset::<T>::insert(id, AssetDetails {
+			owner: owner.clone(),
+			supply: Zero::zero(),
+			deposit,
+			max_zombies,
+			min_balance,
+			zombies: Zero::zero(),
+			accounts: Zero::zero(),
+			approvals: Zero::zero(),
+			is_frozen: false,
+			is_featured: true,
+			daily_limit: None,
+			existence_model,
+			whale_threshold: None,
+			transfer_cooldown: None,
+			community_mint: None,
+			decimals_locked: false,
+			burnable: true,
+			destination_whitelist: false,
+			expires_at: None,
+			freeze_reason: 0,
+			clawback_window: None,
+			indivisible: false,
+			max_transfer: None,
+			frozen_until: None,
+			cross_chain: false,
+			strict_whitelist: false,
+			burn_rate_bps: 0,
+			creator: owner.clone(),
+		});
+		// add feature info
+		Self::insert_feature(id, Self::new_feature_detail(feature_code));
+		Ok(())
+	}
+
+	/// Destroy the asset `id`, returning all deposits and cancelling its approvals.
+	///
+	/// If `maybe_check_owner` is given, it must be the owner of the asset.
+	fn do_destroy(
+		id: T::AssetId,
+		witness: DestroyWitness,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> Result<(), DispatchError> {
+		Asset::<T>::try_mutate_exists(id, |maybe_details| {
+			let details = maybe_details.take().ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T>::NoPermission);
+			}
+			ensure!(details.accounts == details.zombies, Error::<T>::RefsLeft);
+			ensure!(Escrowed::<T>::get(id).is_zero(), Error::<T>::RefsLeft);
+			ensure!(details.zombies <= witness.zombies, Error::<T>::BadWitness);
+			ensure!(details.approvals <= witness.approvals, Error::<T>::BadWitness);
+
+			let metadata = Metadata::<T>::take(&id);
+			let deposit = details.deposit.saturating_add(metadata.deposit);
+			let fee = deposit.saturating_sub(T::DestroyRefundRatio::get() * deposit);
+			match Self::deposit_asset_for(id) {
+				None => {
+					if !fee.is_zero() {
+						let (imbalance, _) = T::Currency::slash_reserved(&details.owner, fee);
+						T::DestroyFeeDestination::on_unbalanced(imbalance);
+					}
+					T::Currency::unreserve(&details.owner, deposit.saturating_sub(fee));
+				}
+				Some(deposit_id) => {
+					// A fee kept from a deposit held in the deposit asset is burned.
+					Self::unreserve_deposit(id, &details.owner, deposit);
+					if !fee.is_zero() {
+						Self::do_burn(deposit_id, details.owner.clone(), Self::deposit_in_asset(fee))?;
+					}
+				}
+			}
+			if !fee.is_zero() {
+				Self::deposit_event(Event::DestroyFeePaid(id, fee));
+			}
+			T::Currency::unreserve(&details.owner, RedemptionReserve::<T>::take(id));
+			RedemptionRate::<T>::remove(id);
+
+			{
+				let _guard = MutationGuard::<T>::acquire()?;
+				T::OnDestroy::on_destroyed(id);
+			}
+			OwnerAssets::<T>::remove(&details.owner, id);
+			CreatorAssets::<T>::remove(&details.creator, id);
+			*maybe_details = None;
+			Account::<T>::remove_prefix(&id);
+			Self::remove_feature(id);
+			TransferredToday::<T>::remove_prefix(id);
+			LastTransfer::<T>::remove_prefix(id);
+			LastCommunityMint::<T>::remove_prefix(id);
+			TempIssuers::<T>::remove_prefix(id);
+			HoldReasons::<T>::remove_prefix(id);
+			Conversions::<T>::remove(id);
+			TransferFees::<T>::remove(id);
+			Recounts::<T>::remove(id);
+			Drains::<T>::remove(id);
+			RecentActivity::<T>::remove(id);
+			ExemptAccounts::<T>::remove_prefix(id);
+			LegacyImports::<T>::remove(id);
+			RecentMints::<T>::remove_prefix(id);
+			DestinationAllowlist::<T>::remove_prefix(id);
+			FrozenAssets::<T>::remove(id);
+			if !T::ReusableAssetIds::get() {
+				RetiredAssets::<T>::insert(id, ());
+			}
+			for ((owner, delegate), approval) in Approvals::<T>::drain_prefix(id) {
+				DelegateApprovals::<T>::remove(&delegate, (id, &owner));
+				T::Currency::unreserve(&owner, approval.deposit);
+				Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
+			}
+			Self::deposit_event(Event::Destroyed(id));
+			Ok(())
+		})
+	}
+
+	/// Make `owner` the Owner of asset `id`, moving the asset deposit and the redemption reserve
+	/// along with it. `origin` must be the current Owner.
+	fn do_transfer_ownership(
+		id: T::AssetId,
+		origin: &T::AccountId,
+		owner: T::AccountId,
+	) -> Result<(), DispatchError> {
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == &details.owner, Error::<T>::NoPermission);
+			if details.owner == owner { return Ok(()) }
+
+			// Move the deposit to the new owner.
+			match Self::deposit_asset_for(id) {
+				None => {
+					T::Currency::repatriate_reserved(&details.owner, &owner, details.deposit, Reserved)?;
+				}
+				Some(_) if details.deposit.is_zero() => (),
+				Some(deposit_id) => {
+					let amount = Self::deposit_in_asset(details.deposit);
+					Self::do_release_hold(deposit_id, &details.owner, amount, DEPOSIT_HOLD_REASON)?;
+					let moved = Self::do_transfer(deposit_id, &details.owner, &owner, amount)?;
+					Self::do_hold(deposit_id, &owner, moved, DEPOSIT_HOLD_REASON)?;
+				}
+			}
+
+			// The redemption reserve is always held in the native currency.
+			let reserve = RedemptionReserve::<T>::get(id);
+			if !reserve.is_zero() {
+				T::Currency::repatriate_reserved(&details.owner, &owner, reserve, Reserved)?;
+			}
+
+			OwnerAssets::<T>::remove(&details.owner, id);
+			OwnerAssets::<T>::insert(&owner, id, ());
+			details.owner = owner.clone();
+
+			Self::deposit_event(Event::OwnerChanged(id, owner));
+			Ok(())
+		})
+	}
+
+	/// Set the metadata of asset `id` on behalf of `origin`, which must be its Owner, adjusting the
+	/// metadata deposit to match.
+	fn do_set_metadata(
+		id: T::AssetId,
+		origin: &T::AccountId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+	) -> Result<(), DispatchError> {
+		ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+		ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+		ensure!(
+			name.len() + symbol.len() <= T::MaxTotalMetadataBytes::get() as usize,
+			Error::<T>::BadMetadata,
+		);
+
+		let mut d = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(origin == &d.owner, Error::<T>::NoPermission);
+		let decimals = if d.indivisible { 0 } else { decimals };
+		ensure!(Self::checked_pow10(decimals).is_some(), Error::<T>::DecimalsOverflow);
+
+		Metadata::<T>::try_mutate_exists(id, |metadata| {
+			let bytes_used = name.len() + symbol.len();
+			let old_deposit = metadata.as_ref().map(|m| m.deposit).unwrap_or_default();
+			let old_decimals = metadata.as_ref().map(|m| m.decimals).unwrap_or_default();
+
+			// Metadata is being removed, which the decimals lock does not prevent.
+			if bytes_used.is_zero() && decimals.is_zero() {
+				Self::adjust_deposit(id, origin, old_deposit, Zero::zero())?;
+				*metadata = None;
+			} else {
+				ensure!(!d.decimals_locked || decimals == old_decimals, Error::<T>::DecimalsLocked);
+				let new_deposit = Self::metadata_deposit(name.len() as u32, symbol.len() as u32);
+
+				// Nothing is written unless the deposit could be adjusted.
+				Self::adjust_deposit(id, origin, old_deposit, new_deposit)?;
+
+				*metadata = Some(AssetMetadata {
+					deposit: new_deposit,
+					name: name.clone(),
+					symbol: symbol.clone(),
+					decimals,
+				})
+			}
+			if !d.decimals_locked && !decimals.is_zero() {
+				d.decimals_locked = true;
+				Asset::<T>::insert(id, &d);
+			}
+
+			Self::deposit_event(Event::MetadataSet(id, name, symbol, decimals));
+			Ok(())
+		})
+	}
+
+	/// Move up to `amount` of asset `id` from `source` to `dest`, ignoring freezes, limits and
+	/// fees, and returning the amount actually moved. Permissions are left to the caller.
+	///
+	/// The amount is capped at what `source` may spend, and grows to take the `source` balance to
+	/// zero rather than below the minimum balance.
+	fn do_force_transfer(
+		id: T::AssetId,
+		source: T::AccountId,
+		dest: T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		ensure!(Account::<T>::contains_key(id, &source), Error::<T>::BalanceZero);
+		let mut source_account = Account::<T>::get(id, &source);
+		let min_balance = Asset::<T>::get(id).map_or_else(Zero::zero, |d| d.min_balance);
+		let source_min = Self::min_balance_for(id, &source, min_balance);
+		let mut amount = amount.min(source_account.reducible(source_min));
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+		if dest == source {
+			return Ok(Zero::zero())
+		}
+
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			T::TransferPolicy::can_transfer(id, &source, &dest, amount)
+				.map_err(|_| Error::<T>::PolicyViolation)?;
+
+			source_account.balance -= amount;
+			if source_account.balance < source_min {
+				amount += source_account.balance;
+				source_account.balance = Zero::zero();
+			}
+
+			Account::<T>::try_mutate(id, &dest, |a| -> Result<(), DispatchError> {
+				let new_balance = a.balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+				let min_balance = Self::min_balance_for(id, &dest, details.min_balance);
+				ensure!(new_balance >= min_balance, Error::<T>::BalanceLow);
+				if a.balance.is_zero() {
+					Self::new_account(&dest, details, a)?;
+				}
+				a.balance = new_balance;
+				Self::touch(id, &dest, a);
+				Ok(())
+			})?;
+
+			match source_account.balance.is_zero() {
+				false => {
+					Self::dezombify(&source, details, &mut source_account);
+					Self::touch(id, &source, &mut source_account);
+					Account::<T>::insert(id, &source, &source_account)
+				}
+				true => {
+					Self::dead_account(&source, details, source_account.is_zombie());
+					Account::<T>::remove(id, &source);
+				}
+			}
+
+			Self::note_activity(id, ActivityKind::Transferred, &source, Some(&dest));
+			Self::deposit_event(Event::ForceTransferred(id, source.clone(), dest.clone(), amount));
+			Self::note_large_transfer(id, details, source, dest, amount);
+			Ok(amount)
+		})
+	}
+
+	/// Move `amount` of asset `id` from `source` to `dest` exactly as `transfer` does, returning
+	/// the amount delivered to `dest`.
+	fn do_plain_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+		ensure!(Self::asset_exists(id), Error::<T>::Unknown);
+		if T::BurnAddress::get().as_ref() == Some(dest) {
+			return Self::do_burn_from_transfer(id, source, amount)
+		}
+		let burn = Self::transfer_burn(id, amount);
+		if !burn.is_zero() {
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(
+				details.max_transfer.filter(|max| amount > *max).is_none(),
+				Error::<T>::AboveMaxTransfer,
+			);
+			if burn == amount {
+				return Self::do_burn_from_transfer(id, source, amount).map(|_| Zero::zero())
+			}
+			Self::do_burn_share(id, source, amount, burn)?;
+		}
+		Self::do_transfer_with_fee(id, source, dest, amount - burn, TransferMode::Sweep)
+	}
+
+	/// Burn `burn` of asset `id` from `source` as the burned share of a transfer of `amount`,
+	/// which `source` must be able to send in full. Emits `Burned`.
+	///
+	/// `burn` must be less than `amount`. The account is neither reaped nor swept, since the
+	/// transfer of the rest of `amount` that follows does that.
+	fn do_burn_share(
+		id: T::AssetId,
+		source: &T::AccountId,
+		amount: T::Balance,
+		burn: T::Balance,
+	) -> Result<(), DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		Self::lift_lapsed_freeze(id);
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+			ensure!(!Self::has_expired(details), Error::<T>::AssetExpired);
+			let mut account = Account::<T>::get(id, source);
+			ensure!(!account.is_frozen(), Error::<T>::AccountFrozen);
+			let source_min = Self::min_balance_for(id, source, details.min_balance);
+			ensure!(amount <= account.reducible(source_min), Error::<T>::BalanceLow);
+
+			account.balance -= burn;
+			Self::touch(id, source, &mut account);
+			Account::<T>::insert(id, source, &account);
+			details.supply = details.supply.saturating_sub(burn);
+			if details.cross_chain {
+				T::CrossChainNotifier::on_burn(id, source, burn);
+			}
+
+			Self::note_activity(id, ActivityKind::Burned, source, None);
+			Self::deposit_event(Event::Burned(id, source.clone(), burn));
+			Ok(())
+		})
+	}
+
+	/// Burn `amount` of asset `id` from `source` in place of transferring it to `BurnAddress`,
+	/// honouring the freezes and expiry a transfer would. Any dust left below the minimum balance
+	/// is burned as well. Returns the amount burned and emits `Burned`.
+	fn do_burn_from_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::lift_lapsed_freeze(id);
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+		ensure!(!Self::has_expired(&details), Error::<T>::AssetExpired);
+		ensure!(details.burnable, Error::<T>::BurnDisabled);
+		let account = Account::<T>::get(id, source);
+		ensure!(!account.is_frozen(), Error::<T>::AccountFrozen);
+		let source_min = Self::min_balance_for(id, source, details.min_balance);
+		ensure!(amount <= account.reducible(source_min), Error::<T>::BalanceLow);
+		Self::do_burn(id, source.clone(), amount)
+	}
+
+	/// Move `amount` of asset `id` from `source` to `dest` like `do_transfer`, first paying the
+	/// transfer fee of the asset out of it as `FeeRouter` decides. Returns the amount delivered to
+	/// `dest`.
+	///
+	/// Fails if `amount` is above the single transfer cap of the asset. The fee moves along with
+	/// the rest in `mode`.
+	fn do_transfer_with_fee(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		mode: TransferMode,
+	) -> Result<T::Balance, DispatchError> {
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(details.max_transfer.filter(|max| amount > *max).is_none(), Error::<T>::AboveMaxTransfer);
+		let fee = Self::transfer_fee(id, amount);
+		let mut shares = Vec::new();
+		if !fee.is_zero() {
+			let mut left = fee;
+			let routed = {
+				let _guard = MutationGuard::<T>::acquire()?;
+				T::FeeRouter::route(id, &details.owner, fee)
+			};
+			for (to, share) in routed.into_iter().take(T::MaxFeeShares::get() as usize) {
+				let share = share.min(left);
+				if share.is_zero() { continue }
+				left -= share;
+				shares.push((to, share));
+			}
+			if !left.is_zero() {
+				shares.push((Some(details.owner), left));
+			}
+		}
+		Self::do_transfer_in_mode(id, source, dest, amount, shares, mode)
+	}
+
+	/// Increase the balance of `beneficiary` by `amount` assets of `id`, creating its account if
+	/// needed. Fails once the asset has expired. Permissions are left to the caller.
+	fn do_mint(id: T::AssetId, beneficiary: T::AccountId, amount: T::Balance) -> Result<(), DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(!Self::has_expired(details), Error::<T>::AssetExpired);
+			details.supply = details.supply.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+
+			Account::<T>::try_mutate(id, &beneficiary, |t| -> Result<(), DispatchError> {
+				let new_balance = t.balance.saturating_add(amount);
+				let min_balance = Self::min_balance_for(id, &beneficiary, details.min_balance);
+				ensure!(new_balance >= min_balance, Error::<T>::BalanceLow);
+				if t.balance.is_zero() {
+					Self::new_account(&beneficiary, details, t)?;
+				}
+				t.balance = new_balance;
+				Self::touch(id, &beneficiary, t);
+				Ok(())
+			})?;
+			if let Some(window) = details.clawback_window {
+				let mut mints = Self::recent_mints(id, &beneficiary, window);
+				mints.push((amount, frame_system::Module::<T>::block_number()));
+				RecentMints::<T>::insert(id, &beneficiary, mints);
+			}
+			Self::note_activity(id, ActivityKind::Issued, &beneficiary, None);
+			Self::deposit_event(Event::Issued(id, beneficiary, amount));
+			Ok(())
+		})
+	}
+
+	/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`, returning
+	/// the amount actually burned. Funds on hold are never burned. Permissions are left to the
+	/// caller.
+	///
+	/// The amount burned exceeds `amount` when the remainder would be below the minimum balance.
+	pub fn do_burn(id: T::AssetId, who: T::AccountId, amount: T::Balance) -> Result<T::Balance, DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let d = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+
+			let burned = Account::<T>::try_mutate_exists(
+				id,
+				&who,
+				|maybe_account| -> Result<T::Balance, DispatchError> {
+					let mut account = maybe_account.take().ok_or(Error::<T>::BalanceZero)?;
+					let min_balance = Self::min_balance_for(id, &who, d.min_balance);
+					let mut burned = amount.min(account.reducible(min_balance));
+					account.balance -= burned;
+					*maybe_account = if account.balance.is_zero() || account.balance < min_balance {
+						burned += account.balance;
+						Self::dead_account(&who, d, account.is_zombie());
+						None
+					} else {
+						Self::touch(id, &who, &mut account);
+						Some(account)
+					};
+					Ok(burned)
+				}
+			)?;
+
+			if burned > d.supply {
+				// The accounts hold more than the supply, which is an accounting bug. Keep going,
+				// but make it visible.
+				Self::deposit_event(Event::SupplyUnderflow(id, d.supply, burned));
+			}
+			d.supply = d.supply.saturating_sub(burned);
+			if d.cross_chain {
+				T::CrossChainNotifier::on_burn(id, &who, burned);
+			}
+
+			Self::note_activity(id, ActivityKind::Burned, &who, None);
+			Self::deposit_event(Event::Burned(id, who, burned));
+			Ok(burned)
+		})
+	}
+
+	/// Move `amount` of asset `id` from `source` to `dest`, honouring freezes, expiry, the
+	/// transfer policy and the destination whitelist.
+	///
+	/// If `source` would be left with less than the minimum balance, the remainder is swept to
+	/// `dest` as well. Returns the amount actually transferred and emits `Transferred`.
+	fn do_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_transfer_in_mode(id, source, dest, amount, Vec::new(), TransferMode::Sweep)
+	}
+
+	/// Move `amount` of asset `id` from `source` to `dest` like `do_transfer`, with `mode`
+	/// deciding what happens when `source` would be left with less than the minimum balance.
+	///
+	/// `fee` are the shares of a transfer fee paid out of `amount`, each credited to an account or
+	/// burned. They move in the same mutation as the rest, so the freezes, limits, cooldown and
+	/// policy of the transfer apply to it once, and the destination allowlist only to `dest`.
+	/// Shares due to `source` itself stay where they are. Returns the amount delivered to `dest`.
+	fn do_transfer_in_mode(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		fee: Vec<(Option<T::AccountId>, T::Balance)>,
+		mode: TransferMode,
+	) -> Result<T::Balance, DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		Self::lift_lapsed_freeze(id);
+		let mut source_account = Account::<T>::get(id, source);
+
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+			ensure!(!Self::has_expired(details), Error::<T>::AssetExpired);
+			ensure!(!source_account.is_frozen(), Error::<T>::AccountFrozen);
+			let source_min = Self::min_balance_for(id, source, details.min_balance);
+			ensure!(amount <= source_account.reducible(source_min), Error::<T>::BalanceLow);
+
+			let sum = |shares: &[(Option<T::AccountId>, T::Balance)]| shares.iter()
+				.fold(T::Balance::zero(), |sum, (_, share)| sum.saturating_add(*share));
+			let charged = sum(&fee);
+			let fee: Vec<_> = fee.into_iter()
+				.filter(|(to, _)| to.as_ref() != Some(source))
+				.collect();
+			let mut amount = amount.saturating_sub(charged);
+			let mut outflow = sum(&fee);
+			if dest != source {
+				outflow = outflow.saturating_add(amount);
+			}
+			if outflow.is_zero() {
+				return Ok(amount)
+			}
+			source_account.balance -= outflow;
+
+			if dest != source {
+				T::TransferPolicy::can_transfer(id, source, dest, amount)
+					.map_err(|_| Error::<T>::PolicyViolation)?;
+				ensure!(Self::allowlisted(id, details, dest), Error::<T>::NotAllowed);
+			}
+
+			if source_account.balance < source_min {
+				let sweep = mode == TransferMode::Sweep && dest != source;
+				ensure!(sweep || source_account.balance.is_zero(), Error::<T>::BalanceLow);
+				amount += source_account.balance;
+				outflow += source_account.balance;
+				source_account.balance = Zero::zero();
+			}
+			let transferred_today = match details.daily_limit {
+				Some(limit) => Some(Self::checked_window_outflow(id, source, outflow, limit)?),
+				None => None,
+			};
+			let now = frame_system::Module::<T>::block_number();
+			if let Some(cooldown) = details.transfer_cooldown {
+				if let Some(last) = LastTransfer::<T>::get(id, source) {
+					ensure!(now >= last.saturating_add(cooldown), Error::<T>::TransferCooldown);
+				}
+			}
+
+			for (to, share) in fee {
+				match to {
+					Some(to) => {
+						Self::credit(id, &to, details, share)?;
+						let event = Event::Transferred(id, source.clone(), to.clone(), share);
+						Self::deposit_event(event);
+						if details.cross_chain {
+							T::CrossChainNotifier::on_transfer(id, source, &to, share);
+						}
+					}
+					None => {
+						details.supply = details.supply.saturating_sub(share);
+						Self::deposit_event(Event::Burned(id, source.clone(), share));
+						if details.cross_chain {
+							T::CrossChainNotifier::on_burn(id, source, share);
+						}
+					}
+				}
+			}
+			if !charged.is_zero() {
+				Self::deposit_event(Event::TransferFeeCharged(id, source.clone(), charged));
+			}
+			let delivered = dest != source && !amount.is_zero();
+			if delivered {
+				Self::credit(id, dest, details, amount)?;
+			}
+
+			match source_account.balance.is_zero() {
+				false => {
+					Self::dezombify(source, details, &mut source_account);
+					Self::touch(id, source, &mut source_account);
+					Account::<T>::insert(id, source, &source_account)
+				}
+				true => {
+					Self::dead_account(source, details, source_account.is_zombie());
+					Account::<T>::remove(id, source);
+				}
+			}
+			if let Some(window) = transferred_today {
+				TransferredToday::<T>::insert(id, source, window);
+			}
+			if details.transfer_cooldown.is_some() {
+				LastTransfer::<T>::insert(id, source, now);
+			}
+
+			if delivered {
+				Self::note_activity(id, ActivityKind::Transferred, source, Some(dest));
+				Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), amount));
+				if T::EmitScaledAmounts::get() {
+					let (integer, fraction, decimals) = Self::scaled_amount(id, amount);
+					Self::deposit_event(Event::TransferredHuman(
+						id, source.clone(), dest.clone(), integer, fraction, decimals,
+					));
+				}
+				Self::note_large_transfer(id, details, source.clone(), dest.clone(), amount);
+				if details.cross_chain {
+					T::CrossChainNotifier::on_transfer(id, source, dest, amount);
+				}
+			}
+			Ok(amount)
+		})
+	}
+
+	/// Credit `amount` of asset `id` to `who` as part of a transfer, creating its account if
+	/// needed. The supply is left as it is.
+	fn credit(
+		id: T::AssetId,
+		who: &T::AccountId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		amount: T::Balance,
+	) -> Result<(), DispatchError> {
+		// Saturating here would silently burn part of the amount, dust included.
+		Account::<T>::try_mutate(id, who, |a| -> Result<(), DispatchError> {
+			let new_balance = a.balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			let min_balance = Self::min_balance_for(id, who, details.min_balance);
+			ensure!(new_balance >= min_balance, Error::<T>::BalanceLow);
+			if a.balance.is_zero() {
+				Self::new_account(who, details, a)?;
+			}
+			a.balance = new_balance;
+			Self::touch(id, who, a);
+			Ok(())
+		})
+	}
+
+	/// Move `amount` of asset `id` out of the balance of `who` into escrow, honouring freezes.
+	///
+	/// `who` may not be left with a non-zero balance below the minimum balance.
+	fn do_escrow(id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> Result<(), DispatchError> {
+		let _guard = MutationGuard::<T>::acquire()?;
+		Self::lift_lapsed_freeze(id);
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			ensure!(!details.is_frozen, Error::<T>::AssetFrozen);
+
+			Account::<T>::try_mutate_exists(id, who, |maybe_account| -> Result<(), DispatchError> {
+				let mut account = maybe_account.take().ok_or(Error::<T>::BalanceZero)?;
+				ensure!(!account.is_frozen(), Error::<T>::AccountFrozen);
+				account.balance = account.balance.checked_sub(&amount).ok_or(Error::<T>::BalanceLow)?;
+				*maybe_account = if account.balance.is_zero() {
+					Self::dead_account(who, details, account.is_zombie());
+					None
+				} else {
+					let min_balance = Self::min_balance_for(id, who, details.min_balance);
+					ensure!(account.balance >= min_balance, Error::<T>::BalanceLow);
+					Self::touch(id, who, &mut account);
+					Some(account)
+				};
+				Ok(())
+			})?;
+			Escrowed::<T>::mutate(id, |escrowed| *escrowed = escrowed.saturating_add(amount));
+			Ok(())
+		})
+	}
+
+	/// Move `amount` of asset `id` out of escrow into the balance of `who`, creating its account
+	/// if needed.
+	fn do_release(id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> Result<(), DispatchError> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let _guard = MutationGuard::<T>::acquire()?;
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+
+			Account::<T>::try_mutate(id, who, |a| -> Result<(), DispatchError> {
+				let new_balance = a.balance.saturating_add(amount);
+				let min_balance = Self::min_balance_for(id, who, details.min_balance);
+				ensure!(new_balance >= min_balance, Error::<T>::BalanceLow);
+				if a.balance.is_zero() {
+					Self::new_account(who, details, a)?;
+				}
+				a.balance = new_balance;
+				Self::touch(id, who, a);
+				Ok(())
+			})?;
+			Escrowed::<T>::mutate(id, |escrowed| *escrowed = escrowed.saturating_sub(amount));
+			Ok(())
+		})
 	}
 
-	#[deprecated(note = "use `Event` instead")]
-	pub type RawEvent<T> = Event<T>;
+	/// Let `OnAccountTouched` update the extra data of `who` after a change of its balance.
+	fn touch(id: T::AssetId, who: &T::AccountId, account: &mut AssetBalanceOf<T>) {
+		T::OnAccountTouched::on_touched(id, who, account.balance, &mut account.extra);
+	}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Transfer amount should be non-zero.
-		AmountZero,
-		/// Account balance must be greater than or equal to the transfer amount.
-		BalanceLow,
-		/// Balance should be non-zero.
-		BalanceZero,
-		/// The signing account has no permission to do the operation.
-		NoPermission,
-		/// The given asset ID is unknown.
-		Unknown,
-		/// The origin account is frozen.
-		Frozen,
-		/// The asset ID is already taken.
-		InUse,
-		/// Too many zombie accounts in use.
-		TooManyZombies,
-		/// Attempt to destroy an asset class when non-zombie, reference-bearing accounts exist.
-		RefsLeft,
-		/// Invalid witness data given.
-		BadWitness,
-		/// Minimum balance should be non-zero.
-		MinBalanceZero,
-		/// A mint operation lead to an overflow.
-		Overflow,
-		/// Some internal state is broken.
-		BadState,
-		/// Invalid metadata given.
-		BadMetadata,
-		/// Invalid feature point.
-		BadFeaturePoint,
+	/// The storage key prefix of the accounts holding asset `id`.
+	fn accounts_prefix(id: T::AssetId) -> Vec<u8> {
+		let mut prefix = Account::<T>::final_prefix().to_vec();
+		id.using_encoded(|id| prefix.extend(Blake2_128Concat::hash(id)));
+		prefix
 	}
 
-	#[pallet::storage]
-	/// Details of an asset.
-	pub(super) type Asset<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		T::AssetId,
-		AssetDetails<T::Balance, T::AccountId, BalanceOf<T>>
-	>;
-	#[pallet::storage]
-	/// The Feature of an asset
-	pub(super) type Feature<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		T::AssetId,
-		AssetFeature
-	>;
-	#[pallet::storage]
-	/// The number of units of assets held by any given account.
-	pub(super) type Account<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		T::AssetId,
-		Blake2_128Concat,
-		T::AccountId,
-		AssetBalance<T::Balance>,
-		ValueQuery
-	>;
-	#[pallet::storage]
-	/// Metadata of an asset.
-	pub(super) type Metadata<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		T::AssetId,
-		AssetMetadata<BalanceOf<T>>,
-		ValueQuery
-	>;
-}
+	/// Copy up to `MaxBatchSize` more holders into snapshot `snapshot_id`, completing it once
+	/// every holder has been copied.
+	fn snapshot_step(snapshot_id: u64) -> Result<(), DispatchError> {
+		let mut snapshot = Snapshots::<T>::get(snapshot_id).ok_or(Error::<T>::UnknownSnapshot)?;
+		ensure!(!snapshot.complete, Error::<T>::SnapshotComplete);
+		let prefix = Self::accounts_prefix(snapshot.id);
+		if snapshot.cursor.is_empty() {
+			snapshot.cursor = prefix.clone();
+		}
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
-pub struct AssetDetails<
-	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
-	AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
-	DepositBalance: Encode + Decode + Clone + Debug + Eq + PartialEq,
-> {
-	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
-	owner: AccountId,
-	/// The total supply across all accounts.
-	supply: Balance,
-	/// The balance deposited for this asset.
-	///
-	/// This pays for the data stored here together with any virtual accounts.
-	deposit: DepositBalance,
-	/// The number of balance-holding accounts that this asset may have, excluding those that were
-	/// created when they had a system-level ED.
-	max_zombies: u32,
-	/// The ED for virtual accounts.
-	min_balance: Balance,
-	/// The current number of zombie accounts.
-	zombies: u32,
-	/// The total number of accounts.
-	accounts: u32,
-	/// Whether the asset is frozen for permissionless transfers.
-	is_frozen: bool,
-	/// Whether the asset is a featured asset
-	is_featured: bool,
-}
+		for _ in 0..T::MaxBatchSize::get() {
+			snapshot.cursor = match sp_io::storage::next_key(&snapshot.cursor)
+				.filter(|key| key.starts_with(&prefix))
+			{
+				Some(key) => key,
+				None => {
+					snapshot.cursor = Vec::new();
+					snapshot.complete = true;
+					Self::deposit_event(Event::SnapshotCompleted(snapshot_id));
+					break
+				}
+			};
+			let mut key_material = Blake2_128Concat::reverse(&snapshot.cursor[prefix.len()..]);
+			if let Ok(who) = T::AccountId::decode(&mut key_material) {
+				let balance = Account::<T>::get(snapshot.id, &who).balance;
+				SnapshotBalances::<T>::insert(snapshot_id, who, balance);
+				snapshot.holders = snapshot.holders.saturating_add(1);
+			}
+		}
+		Snapshots::<T>::insert(snapshot_id, snapshot);
+		Ok(())
+	}
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
-pub struct AssetBalance<
-	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
-> {
-	/// The balance.
-	balance: Balance,
-	/// Whether the account is frozen.
-	is_frozen: bool,
-	/// Whether the account is a zombie. If not, then it has a reference.
-	is_zombie: bool,
-}
+	/// Credit up to `MaxBatchSize` more holders of the spin-off into `new_id`, finishing it once
+	/// every holder has been visited.
+	fn spin_off_step(new_id: T::AssetId) -> Result<(), DispatchError> {
+		let mut spin_off = SpinOffs::<T>::get(new_id).ok_or(Error::<T>::UnknownSpinOff)?;
+		let prefix = Self::accounts_prefix(spin_off.old_id);
+		if spin_off.cursor.is_empty() {
+			spin_off.cursor = prefix.clone();
+		}
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
-pub struct AssetMetadata<DepositBalance> {
-	/// The balance deposited for this metadata.
-	///
-	/// This pays for the data stored in this struct.
-	deposit: DepositBalance,
-	/// The user friendly name of this asset. Limited in length by `StringLimit`.
-	name: Vec<u8>,
-	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
-	symbol: Vec<u8>,
-	/// The number of decimals this asset uses to represent one unit.
-	decimals: u8,
-}
+		for _ in 0..T::MaxBatchSize::get() {
+			spin_off.cursor = match sp_io::storage::next_key(&spin_off.cursor)
+				.filter(|key| key.starts_with(&prefix))
+			{
+				Some(key) => key,
+				None => {
+					SpinOffs::<T>::remove(new_id);
+					Self::deposit_event(Event::SpunOff(spin_off.old_id, new_id));
+					return Ok(())
+				}
+			};
+			let mut key_material = Blake2_128Concat::reverse(&spin_off.cursor[prefix.len()..]);
+			let who = match T::AccountId::decode(&mut key_material) {
+				Ok(who) => who,
+				Err(_) => continue,
+			};
+			let amount = spin_off.ratio * Account::<T>::get(spin_off.old_id, &who).balance;
+			if !amount.is_zero() {
+				// A holder who cannot be credited must not hold up the others.
+				let _ = Self::do_mint(new_id, who, amount);
+			}
+		}
+		SpinOffs::<T>::insert(new_id, spin_off);
+		Ok(())
+	}
 
-// Featured Part for asset
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
-pub struct AssetFeature {
-	/// The level of this asset
-	destiny: FeatureDestinyRank,
-	/// The 'hue' identity of this asset
-	elements: FeatureElements,
-	/// The 'saturation' of this asset
-	saturation: FeatureRankedLevel,
-	/// The 'lightness' of this asset
-	lightness: FeatureLevel
-}
+	/// Emit `LargeTransfer` if `amount` reaches the whale threshold of the asset.
+	fn note_large_transfer(
+		id: T::AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: T::Balance,
+	) {
+		if matches!(details.whale_threshold, Some(threshold) if amount >= threshold) {
+			Self::deposit_event(Event::LargeTransfer(id, from, to, amount));
+		}
+	}
 
-// The main implementation block for the module.
-impl<T: Config> Pallet<T> {
-	// Public immutables
+	/// The outflow of `who` in the current rate-limiting window, together with the window start.
+	fn window_outflow(id: T::AssetId, who: &T::AccountId) -> (T::Balance, T::BlockNumber) {
+		let now = frame_system::Module::<T>::block_number();
+		let (spent, start) = TransferredToday::<T>::get(id, who);
+		if spent.is_zero() || now >= start.saturating_add(T::RateLimitWindow::get()) {
+			(Zero::zero(), now)
+		} else {
+			(spent, start)
+		}
+	}
 
-	/// Get the asset `id` balance of `who`.
-	pub fn balance(id: T::AssetId, who: T::AccountId) -> T::Balance {
-		Account::<T>::get(id, who).balance
+	/// The outflow of `who` in the current rate-limiting window after sending `amount` more,
+	/// together with the window start, or `DailyLimitExceeded` if that would exceed `limit`.
+	fn checked_window_outflow(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		limit: T::Balance,
+	) -> Result<(T::Balance, T::BlockNumber), DispatchError> {
+		let (spent, start) = Self::window_outflow(id, who);
+		let spent = spent.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+		ensure!(spent <= limit, Error::<T>::DailyLimitExceeded);
+		Ok((spent, start))
 	}
 
-	/// Get the total supply of an asset `id`.
-	pub fn total_supply(id: T::AssetId) -> T::Balance {
-		Asset::<T>::get(id).map(|x| x.supply).unwrap_or_else(Zero::zero)
+	/// Move the reserved deposit of `who` from `old` to `new`, touching the currency only for
+	/// the difference.
+	fn adjust_deposit(
+		id: T::AssetId,
+		who: &T::AccountId,
+		old: BalanceOf<T>,
+		new: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		if new > old {
+			Self::reserve_deposit(id, who, new - old)?;
+		} else if new < old {
+			Self::unreserve_deposit(id, who, old - new);
+		}
+		Ok(())
 	}
 
-	/// Check the number of zombies allow yet for an asset.
-	pub fn zombie_allowance(id: T::AssetId) -> u32 {
-		Asset::<T>::get(id).map(|x| x.max_zombies - x.zombies).unwrap_or_else(Zero::zero)
+	/// Set the feature of the asset `id`, keeping `FeatureHistogram` up to date.
+	fn insert_feature(id: T::AssetId, feature: AssetFeature) {
+		if let Some(old) = Feature::<T>::get(id) {
+			FeatureHistogram::<T>::mutate(old.destiny, |n| *n = n.saturating_sub(1));
+		}
+		FeatureHistogram::<T>::mutate(feature.destiny.clone(), |n| *n = n.saturating_add(1));
+		Feature::<T>::insert(id, feature);
 	}
 
-	/// Get the feature info of the asset
-	pub fn feature(id: T::AssetId) -> Option<AssetFeature> {
-		Feature::<T>::get(id)
+	/// Remove the feature of the asset `id`, keeping `FeatureHistogram` up to date.
+	fn remove_feature(id: T::AssetId) {
+		if let Some(old) = Feature::<T>::take(id) {
+			FeatureHistogram::<T>::mutate(old.destiny, |n| *n = n.saturating_sub(1));
+		}
 	}
 
 	/// create feature detail by code
@@ -1100,48 +6415,353 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Count `account` of `who` as a new account of the asset, marking it as a zombie or taking a
+	/// reference on `who` as the existence model requires, and recording when it was created.
 	fn new_account(
 		who: &T::AccountId,
-		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>>,
-	) -> Result<bool, DispatchError> {
+		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		account: &mut AssetBalanceOf<T>,
+	) -> Result<(), DispatchError> {
 		let accounts = d.accounts.checked_add(1).ok_or(Error::<T>::Overflow)?;
-		let r = Ok(if frame_system::Module::<T>::account_exists(who) {
-			frame_system::Module::<T>::inc_consumers(who).map_err(|_| Error::<T>::BadState)?;
-			false
-		} else {
-			ensure!(d.zombies < d.max_zombies, Error::<T>::TooManyZombies);
-			d.zombies += 1;
-			true
-		});
+		let exists = frame_system::Module::<T>::account_exists(who);
+		let is_zombie = match d.existence_model {
+			ExistenceModel::Sufficient => {
+				frame_system::Module::<T>::inc_providers(who);
+				false
+			}
+			ExistenceModel::Zombie | ExistenceModel::SystemRefOnly if exists => {
+				frame_system::Module::<T>::inc_consumers(who).map_err(|_| Error::<T>::BadState)?;
+				false
+			}
+			ExistenceModel::Zombie => {
+				ensure!(d.zombies < d.max_zombies, Error::<T>::TooManyZombies);
+				d.zombies += 1;
+				true
+			}
+			ExistenceModel::SystemRefOnly => return Err(Error::<T>::NoProvider.into()),
+		};
 		d.accounts = accounts;
-		r
+		account.set_zombie(is_zombie);
+		account.created_at = frame_system::Module::<T>::block_number();
+		Ok(())
 	}
 
 	/// If `who`` exists in system and it's a zombie, dezombify it.
 	fn dezombify(
 		who: &T::AccountId,
-		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>>,
-		is_zombie: &mut bool,
+		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		account: &mut AssetBalanceOf<T>,
 	) {
-		if *is_zombie && frame_system::Module::<T>::account_exists(who) {
+		if account.is_zombie()
+			&& d.existence_model == ExistenceModel::Zombie
+			&& frame_system::Module::<T>::account_exists(who)
+		{
 			// If the account exists, then it should have at least one provider
 			// so this cannot fail... but being defensive anyway.
 			let _ = frame_system::Module::<T>::inc_consumers(who);
-			*is_zombie = false;
+			account.set_zombie(false);
 			d.zombies = d.zombies.saturating_sub(1);
 		}
 	}
 
 	fn dead_account(
 		who: &T::AccountId,
-		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>>,
+		d: &mut AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
 		is_zombie: bool,
 	) {
 		if is_zombie {
 			d.zombies = d.zombies.saturating_sub(1);
+		} else if d.existence_model == ExistenceModel::Sufficient {
+			// Only fails if other pallets still consume the account, in which case it must be
+			// kept alive anyway.
+			let _ = frame_system::Module::<T>::dec_providers(who);
 		} else {
 			frame_system::Module::<T>::dec_consumers(who);
 		}
 		d.accounts = d.accounts.saturating_sub(1);
 	}
+
+	/// Take `amount` of asset `id` off the hold of `who` for `reason`.
+	fn do_release_hold(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reason: u8,
+	) -> Result<(), DispatchError> {
+		HoldReasons::<T>::try_mutate_exists(id, (who, reason), |maybe_held| -> Result<(), DispatchError> {
+			let held = maybe_held.unwrap_or_else(Zero::zero)
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::InsufficientHold)?;
+			*maybe_held = Some(held).filter(|held| !held.is_zero());
+			Ok(())
+		})?;
+		Account::<T>::mutate(id, who, |account| account.on_hold = account.on_hold.saturating_sub(amount));
+		Ok(())
+	}
+
+	/// Process the items of a pending batch until `BatchWeightLimit` is spent, returning the
+	/// weight used. At least one item is processed so that every call makes progress.
+	fn batch_step(batch_id: u64) -> Result<Weight, DispatchError> {
+		let mut batch = PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::UnknownBatch)?;
+		let limit = T::BatchWeightLimit::get();
+		let per_item = T::WeightInfo::mint();
+		let total = batch.items.len() as u32;
+
+		let mut used: Weight = 0;
+		while batch.next < total && (used == 0 || used.saturating_add(per_item) <= limit) {
+			let (who, amount) = batch.items[batch.next as usize].clone();
+			// A beneficiary who cannot be credited must not hold up the others.
+			if Self::do_mint(batch.id, who, amount).is_ok() {
+				batch.succeeded += 1;
+			} else {
+				batch.skipped += 1;
+			}
+			batch.next += 1;
+			used = used.saturating_add(per_item);
+		}
+
+		if batch.next < total {
+			Self::deposit_event(Event::BatchProgress(batch_id, batch.next, total));
+			PendingBatches::<T>::insert(batch_id, batch);
+		} else {
+			Self::deposit_event(Event::BatchCompleted(batch_id, batch.succeeded, batch.skipped));
+			PendingBatches::<T>::remove(batch_id);
+		}
+		Ok(used)
+	}
+
+	/// The weight of a transfer, depending on whether the sender's account was `reaped` and the
+	/// target's account `created`.
+	fn transfer_weight(reaped: bool, created: bool) -> Weight {
+		match (reaped, created) {
+			(false, false) => T::WeightInfo::transfer_existing(),
+			(false, true) => T::WeightInfo::transfer_create(),
+			(true, false) => T::WeightInfo::transfer_sweep(),
+			(true, true) => T::WeightInfo::transfer(),
+		}
+	}
+
+	/// The most weight paying the transfer fee of one transfer takes: a transfer or burn for each
+	/// share `FeeRouter` may make, and a transfer of the unrouted part to the Owner.
+	fn transfer_fee_weight() -> Weight {
+		let share = T::WeightInfo::transfer().max(T::WeightInfo::burn());
+		share.saturating_mul(Weight::from(T::MaxFeeShares::get()).saturating_add(1))
+	}
+
+	/// Whether the asset has reached its expiry block.
+	fn has_expired(details: &AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>) -> bool {
+		matches!(details.expires_at, Some(at) if frame_system::Module::<T>::block_number() >= at)
+	}
+
+	/// Whether transfers of asset `id` may credit `who`, as far as the destination whitelist of
+	/// the asset is concerned.
+	fn allowlisted(
+		id: T::AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		who: &T::AccountId,
+	) -> bool {
+		!details.destination_whitelist
+			|| DestinationAllowlist::<T>::contains_key(id, who)
+			|| (!details.strict_whitelist && Self::is_team(details, who))
+	}
+
+	/// Whether the asset is frozen, and its freeze has no end or has not reached it yet.
+	fn freeze_in_force(details: &AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>) -> bool {
+		let now = frame_system::Module::<T>::block_number();
+		details.is_frozen && !matches!(details.frozen_until, Some(until) if now > until)
+	}
+
+	/// Thaw the asset `id` if its freeze has passed its end.
+	fn lift_lapsed_freeze(id: T::AssetId) {
+		Asset::<T>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+			if details.is_frozen && !Self::freeze_in_force(details) {
+				details.is_frozen = false;
+				details.freeze_reason = 0;
+				details.frozen_until = None;
+				FrozenAssets::<T>::remove(id);
+				Self::deposit_event(Event::<T>::AssetThawed(id));
+			}
+		});
+	}
+
+	/// Put `amount` of asset `id` owned by `who` on hold for `reason`.
+	fn do_hold(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reason: u8,
+	) -> Result<(), DispatchError> {
+		Account::<T>::try_mutate(id, who, |account| -> Result<(), DispatchError> {
+			ensure!(!account.balance.is_zero(), Error::<T>::BalanceZero);
+			let on_hold = account.on_hold.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			ensure!(on_hold <= account.balance, Error::<T>::BalanceLow);
+			account.on_hold = on_hold;
+			Ok(())
+		})?;
+		HoldReasons::<T>::mutate(id, (who, reason), |held| *held = held.saturating_add(amount));
+		Ok(())
+	}
+
+	/// The asset in which the deposits of asset `id` are held, if not in `Currency`.
+	fn deposit_asset_for(id: T::AssetId) -> Option<T::AssetId> {
+		T::DepositAsset::get().filter(|deposit_id| *deposit_id != id)
+	}
+
+	/// Express a deposit in units of the deposit asset.
+	fn deposit_in_asset(amount: BalanceOf<T>) -> T::Balance {
+		let amount: u128 = amount.saturated_into();
+		amount.saturated_into()
+	}
+
+	/// Reserve a deposit of asset `id` from `who`.
+	fn reserve_deposit(id: T::AssetId, who: &T::AccountId, amount: BalanceOf<T>) -> Result<(), DispatchError> {
+		match Self::deposit_asset_for(id) {
+			None => T::Currency::reserve(who, amount),
+			Some(_) if amount.is_zero() => Ok(()),
+			Some(deposit_id) => Self::do_hold(deposit_id, who, Self::deposit_in_asset(amount), DEPOSIT_HOLD_REASON),
+		}
+	}
+
+	/// Return a deposit of asset `id` to `who`.
+	fn unreserve_deposit(id: T::AssetId, who: &T::AccountId, amount: BalanceOf<T>) {
+		match Self::deposit_asset_for(id) {
+			None => { T::Currency::unreserve(who, amount); }
+			Some(deposit_id) => {
+				// Only fails if the deposit was never held, in which case there is nothing to return.
+				let _ = Self::do_release_hold(deposit_id, who, Self::deposit_in_asset(amount), DEPOSIT_HOLD_REASON);
+			}
+		}
+	}
+
+	/// Every `SupplyReportPeriod` blocks, report the supply of up to `MaxSupplyReportAssets`
+	/// assets, resuming after the last asset reported by the previous heartbeat. Once every asset
+	/// has been reported the next heartbeat starts over. Returns the weight used.
+	fn supply_heartbeat(now: T::BlockNumber) -> Weight {
+		let period = T::SupplyReportPeriod::get();
+		if period.is_zero() || !(now % period).is_zero() {
+			return 0
+		}
+
+		let prefix = Asset::<T>::final_prefix();
+		let mut cursor = SupplyReportCursor::<T>::get();
+		if cursor.is_empty() {
+			cursor = prefix.to_vec();
+		}
+		let mut report = Vec::new();
+		let mut reads: Weight = 1;
+		for _ in 0..T::MaxSupplyReportAssets::get() {
+			reads += 1;
+			cursor = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix)) {
+				Some(key) => key,
+				None => {
+					cursor.clear();
+					break
+				}
+			};
+			let mut key_material = Blake2_128Concat::reverse(&cursor[prefix.len()..]);
+			if let Ok(id) = T::AssetId::decode(&mut key_material) {
+				reads += 1;
+				if let Some(details) = Asset::<T>::get(id) {
+					report.push((id, details.supply));
+				}
+			}
+		}
+		// Start over right away rather than sending an empty report next time.
+		if !cursor.is_empty() &&
+			sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix)).is_none()
+		{
+			cursor.clear();
+		}
+		SupplyReportCursor::<T>::put(cursor);
+
+		Self::deposit_event(Event::SupplyReport(now, report));
+		T::DbWeight::get().reads_writes(reads, 1)
+	}
+
+	/// The mints of asset `id` to `who` still within `window`, oldest first.
+	fn recent_mints(
+		id: T::AssetId,
+		who: &T::AccountId,
+		window: T::BlockNumber,
+	) -> Vec<(T::Balance, T::BlockNumber)> {
+		let now = frame_system::Module::<T>::block_number();
+		let mut mints = RecentMints::<T>::get(id, who);
+		mints.retain(|(_, at)| now.saturating_sub(*at) < window);
+		mints
+	}
+
+	/// The auction for asset id `id`, if there is one which no longer takes bids.
+	fn ended_auction(
+		id: T::AssetId,
+	) -> Option<IdAuction<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+		let now = frame_system::Module::<T>::block_number();
+		IdAuctions::<T>::get(id).filter(|auction| now >= auction.ends_at)
+	}
+
+	/// Whether the claim period of the ended `auction` has passed.
+	fn auction_lapsed(auction: &IdAuction<T::AccountId, BalanceOf<T>, T::BlockNumber>) -> bool {
+		let now = frame_system::Module::<T>::block_number();
+		now >= auction.ends_at.saturating_add(T::IdAuctionClaimPeriod::get())
+	}
+
+	/// The minimum balance `who` must keep of asset `id`, given the `min_balance` of the asset:
+	/// none at all if `who` is exempt from it.
+	fn min_balance_for(id: T::AssetId, who: &T::AccountId, min_balance: T::Balance) -> T::Balance {
+		if ExemptAccounts::<T>::contains_key(id, who) {
+			Zero::zero()
+		} else {
+			min_balance
+		}
+	}
+
+	/// Whether `who` is the owner of the asset with `details` or holds one of the global roles.
+	fn is_team(
+		details: &AssetDetails<T::Balance, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		who: &T::AccountId,
+	) -> bool {
+		who == &details.owner
+			|| T::AssetAdmin::is_issuer(who)
+			|| T::AssetAdmin::is_admin(who)
+			|| T::AssetAdmin::is_freezer(who)
+	}
+
+	/// Add an entry to the recent activity of asset `id`, dropping the oldest entries beyond
+	/// `MaxRecentActivity`, if activity is recorded at all.
+	fn note_activity(
+		id: T::AssetId,
+		kind: ActivityKind,
+		who: &T::AccountId,
+		counterparty: Option<&T::AccountId>,
+	) {
+		if !T::RecordActivity::get() {
+			return
+		}
+		let max = T::MaxRecentActivity::get() as usize;
+		RecentActivity::<T>::mutate(id, |entries| {
+			entries.push(ActivityEntry {
+				kind,
+				block: frame_system::Module::<T>::block_number(),
+				who: who.clone(),
+				counterparty: counterparty.cloned(),
+			});
+			let excess = entries.len().saturating_sub(max);
+			entries.drain(..excess);
+		});
+	}
+
+	/// The claim on asset id `id`, if there is one which has not expired.
+	fn live_claim(
+		id: T::AssetId,
+	) -> Option<AssetIdClaim<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+		let now = frame_system::Module::<T>::block_number();
+		ClaimedAssetIds::<T>::get(id).filter(|claim| now < claim.expires_at)
+	}
+}
+
+/// Mints rewards for other pallets. Only the issuer check is skipped: the minimum balance and the
+/// existence model of the asset still apply.
+impl<T: Config> AssetRewarder<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+	fn reward(id: T::AssetId, who: T::AccountId, amount: T::Balance) -> Result<(), DispatchError> {
+		Self::do_mint(id, who, amount)
+	}
 }
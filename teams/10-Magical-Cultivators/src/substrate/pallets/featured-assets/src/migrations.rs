@@ -0,0 +1,120 @@
+//! Storage migrations for the featured assets pallet.
+
+use super::*;
+use frame_support::{
+	traits::{GetPalletVersion, PalletVersion},
+	weights::Weight,
+};
+
+/// Migrate the storage of the pallet to the current layout, based on the version it was last
+/// stored with.
+pub fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = 0;
+	if let Some(version) = Pallet::<T>::storage_version() {
+		if version < PalletVersion::new(0, 2, 0) {
+			weight = weight.saturating_add(v0_2::migrate::<T>());
+		}
+	}
+	weight
+}
+
+/// Version 0.2.0 adds approvals, the per-window transfer limit, the existence model, the whale
+/// threshold, the transfer cooldown, community minting, the decimals lock, the burn switch, the
+/// destination whitelist switch, the expiry, the freeze reason, the clawback window, the
+/// indivisible flag, the single transfer cap, the freeze end, the cross-chain flag, the strict
+/// whitelist flag, the burn rate and the creator to `AssetDetails`, packs the flags of
+/// `AssetBalance` into a single byte and adds funds on hold, the freeze reason, the creation block
+/// and the extra data to it, indexes frozen assets in `FrozenAssets` and assets by owner in
+/// `OwnerAssets` and by creator in `CreatorAssets` and counts the assets of each destiny rank in
+/// `FeatureHistogram`.
+pub mod v0_2 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+		owner: AccountId,
+		supply: Balance,
+		deposit: DepositBalance,
+		max_zombies: u32,
+		min_balance: Balance,
+		zombies: u32,
+		accounts: u32,
+		is_frozen: bool,
+		is_featured: bool,
+	}
+
+	#[derive(Decode)]
+	struct OldAssetBalance<Balance> {
+		balance: Balance,
+		is_frozen: bool,
+		is_zombie: bool,
+	}
+
+	/// Translate every asset to the new `AssetDetails`, as a `Zombie` asset without approvals,
+	/// transfer limit, whale threshold, transfer cooldown or community minting, with unlocked
+	/// decimals, burnable balances, any destination allowed, no expiry, freeze reason `0`, no
+	/// clawback window, divisible, without a single transfer cap, not cross-chain, burning nothing
+	/// on transfer, with the team exempt from the destination allowlist and frozen for good if
+	/// frozen, created by its owner, and record the frozen ones and the owner and creator of each.
+	/// Every account starts with nothing on hold and freeze reason `0`, created at block zero with
+	/// default extra data. Every feature is counted in the histogram of its destiny rank.
+	pub fn migrate<T: Config>() -> Weight {
+		let (mut translated, mut assets, mut frozen): (Weight, Weight, Weight) = (0, 0, 0);
+		Asset::<T>::translate::<OldAssetDetails<T::Balance, T::AccountId, BalanceOf<T>>, _>(
+			|id, old| {
+				translated += 1;
+				assets += 1;
+				OwnerAssets::<T>::insert(&old.owner, id, ());
+				CreatorAssets::<T>::insert(&old.owner, id, ());
+				if old.is_frozen {
+					FrozenAssets::<T>::insert(id, ());
+					frozen += 1;
+				}
+				Some(AssetDetails {
+					owner: old.owner.clone(),
+					supply: old.supply,
+					deposit: old.deposit,
+					max_zombies: old.max_zombies,
+					min_balance: old.min_balance,
+					zombies: old.zombies,
+					accounts: old.accounts,
+					approvals: Zero::zero(),
+					is_frozen: old.is_frozen,
+					is_featured: old.is_featured,
+					daily_limit: None,
+					existence_model: ExistenceModel::Zombie,
+					whale_threshold: None,
+					transfer_cooldown: None,
+					community_mint: None,
+					decimals_locked: false,
+					burnable: true,
+					destination_whitelist: false,
+					expires_at: None,
+					freeze_reason: 0,
+					clawback_window: None,
+					indivisible: false,
+					max_transfer: None,
+					frozen_until: None,
+					cross_chain: false,
+					strict_whitelist: false,
+					burn_rate_bps: 0,
+					creator: old.owner,
+				})
+			}
+		);
+		Account::<T>::translate::<OldAssetBalance<T::Balance>, _>(|_, _, old| {
+			translated += 1;
+			let mut account = AssetBalance { balance: old.balance, ..Default::default() };
+			account.set_frozen(old.is_frozen);
+			account.set_zombie(old.is_zombie);
+			Some(account)
+		});
+		let mut features: Weight = 0;
+		for (_, feature) in Feature::<T>::iter() {
+			features += 1;
+			FeatureHistogram::<T>::mutate(feature.destiny, |n| *n = n.saturating_add(1));
+		}
+		let writes = translated + assets + frozen + features;
+		T::DbWeight::get().reads_writes(translated + features, writes)
+	}
+}
@@ -0,0 +1,31 @@
+//! Setters which write the storage of the featured assets pallet directly, bypassing every
+//! invariant, so tests can put the pallet into states no call would reach.
+//!
+//! Only compiled for tests or with the `test-helpers` feature, which must never be enabled in a
+//! production runtime.
+
+use super::*;
+
+/// Set the balance of `who` for the asset `id`, leaving the supply and the account counts alone.
+pub fn set_balance_unchecked<T: Config>(id: T::AssetId, who: &T::AccountId, balance: T::Balance) {
+	Account::<T>::mutate(id, who, |account| account.balance = balance);
+}
+
+/// Set the total supply of the asset `id`, if it exists, leaving the balances alone.
+pub fn set_supply_unchecked<T: Config>(id: T::AssetId, supply: T::Balance) {
+	Asset::<T>::mutate(id, |maybe_details| {
+		if let Some(details) = maybe_details {
+			details.supply = supply;
+		}
+	});
+}
+
+/// Set the account and zombie counts of the asset `id`, if it exists, leaving the accounts alone.
+pub fn set_counts_unchecked<T: Config>(id: T::AssetId, accounts: u32, zombies: u32) {
+	Asset::<T>::mutate(id, |maybe_details| {
+		if let Some(details) = maybe_details {
+			details.accounts = accounts;
+			details.zombies = zombies;
+		}
+	});
+}
@@ -2,11 +2,20 @@
 
 use super::*;
 use crate as mc_featured_assets;
+use crate::Event as AssetsEvent;
 
-use frame_support::{assert_ok, assert_noop, parameter_types};
+use frame_support::{
+	assert_ok, assert_noop, parameter_types, weights::Weight,
+	traits::{Currency, OnUnbalanced, OnInitialize, GenesisBuild},
+};
 use sp_core::H256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, testing::Header};
+use sp_runtime::{traits::{BlakeTwo256, IdentityLookup, BadOrigin}, testing::Header, Permill};
 use pallet_balances::Error as BalancesError;
+use mc_support::traits::{
+	ManagerAccessor, TransferPolicy, TransferError, FeatureRoller, AssetIdDisplay, OnAssetDestroyed,
+	FeeRouter, OnAccountTouched, CrossChainNotifier,
+};
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -68,9 +77,203 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const AssetDepositBase: u64 = 1;
 	pub const AssetDepositPerZombie: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
 	pub const StringLimit: u32 = 50;
+	pub const MaxTotalMetadataBytes: u32 = 60;
 	pub const MetadataDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const RateLimitWindow: u64 = 10;
+	pub const MaxBatchSize: u32 = 5;
+	pub const MaxMinBalance: u64 = 1_000;
+	pub const BatchWeightLimit: Weight = 1_000_000_000;
+	pub const MaxSupplyReportAssets: u32 = 2;
+	pub const MinAccountLifetime: u64 = 10;
+	pub const ClaimDeposit: u64 = 1;
+	pub const ClaimPeriod: u64 = 10;
+	pub const IdAuctionDuration: u64 = 10;
+	pub const IdAuctionClaimPeriod: u64 = 10;
+	pub const MaxRecentActivity: u32 = 3;
+	pub const MaxBurnRateBps: u16 = 1_000;
+}
+
+parameter_types! {
+	pub static ReusableAssetIds: bool = true;
+	pub static DestroyRefundRatio: Permill = Permill::one();
+	pub static DepositAsset: Option<u32> = None;
+	pub static SupplyReportPeriod: u64 = 0;
+	pub static EmitScaledAmounts: bool = false;
+	pub static SplitFees: bool = false;
+	pub static RecordActivity: bool = false;
+	pub static ReenterOnTransfer: bool = false;
+	pub static ReenterOnHooks: bool = false;
+	pub static BurnAddress: Option<u64> = None;
+	pub static MaxFeeShares: u32 = 4;
+}
+
+thread_local! {
+	/// The (issuer, admin, freezer) accounts of the test asset team.
+	static TEAM: RefCell<(u64, u64, u64)> = const { RefCell::new((1, 1, 1)) };
+}
+
+pub(crate) fn set_team(issuer: u64, admin: u64, freezer: u64) {
+	TEAM.with(|t| *t.borrow_mut() = (issuer, admin, freezer));
+}
+
+pub struct TestAssetAdmin;
+impl ManagerAccessor<u64> for TestAssetAdmin {
+	fn get_owner_id() -> u64 { 1 }
+	fn is_admin(who: &u64) -> bool { TEAM.with(|t| t.borrow().1 == *who) }
+	fn is_issuer(who: &u64) -> bool { TEAM.with(|t| t.borrow().0 == *who) }
+	fn is_freezer(who: &u64) -> bool { TEAM.with(|t| t.borrow().2 == *who) }
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_manager(who: &u64) { set_team(*who, *who, *who) }
+}
+
+/// An account that the test transfer policy refuses to credit.
+pub(crate) const BLOCKED: u64 = 666;
+
+thread_local! {
+	/// The outcome of the last attempt of the test transfer policy to re-enter `transfer`.
+	static REENTRY: RefCell<Option<Result<(), DispatchError>>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn last_reentry() -> Option<Result<(), DispatchError>> {
+	REENTRY.with(|r| *r.borrow())
+}
+
+/// Try to mint from within a hook while `ReenterOnHooks` is set, noting the outcome.
+fn reenter_mint(id: u32) {
+	if ReenterOnHooks::get() {
+		let result = Assets::mint(Origin::signed(1), id, 2, 10).map(|_| ()).map_err(|e| e.error);
+		REENTRY.with(|r| *r.borrow_mut() = Some(result));
+	}
+}
+
+pub struct TestTransferPolicy;
+impl TransferPolicy<u64, u32, u64> for TestTransferPolicy {
+	fn can_transfer(id: u32, from: &u64, to: &u64, amount: u64) -> Result<(), TransferError> {
+		if ReenterOnTransfer::get() {
+			// Try to send the amount straight back while it is still on its way.
+			let result = Assets::transfer(Origin::signed(*to), id, *from, amount)
+				.map(|_| ())
+				.map_err(|e| e.error);
+			REENTRY.with(|r| *r.borrow_mut() = Some(result));
+		}
+		if *from == BLOCKED {
+			Err(TransferError::Sender)
+		} else if *to == BLOCKED {
+			Err(TransferError::Recipient)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+thread_local! {
+	/// The assets destroyed so far, in order.
+	static DESTROYED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn destroyed_assets() -> Vec<u32> {
+	DESTROYED.with(|d| d.borrow().clone())
+}
+
+pub struct TestOnDestroy;
+impl OnAssetDestroyed<u32> for TestOnDestroy {
+	fn on_destroyed(id: u32) {
+		// The asset is still around for the hook to look at.
+		assert!(Asset::<Test>::contains_key(id));
+		DESTROYED.with(|d| d.borrow_mut().push(id));
+		reenter_mint(id);
+	}
+}
+
+/// A movement reported to `CrossChainNotifier`: asset, from, to (none for burns) and amount.
+type Movement = (u32, u64, Option<u64>, u64);
+
+thread_local! {
+	/// The movements reported to `CrossChainNotifier` so far.
+	static CROSS_CHAIN: RefCell<Vec<Movement>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn cross_chain_movements() -> Vec<Movement> {
+	CROSS_CHAIN.with(|c| c.borrow().clone())
+}
+
+pub struct TestCrossChainNotifier;
+impl CrossChainNotifier<u64, u32, u64> for TestCrossChainNotifier {
+	fn on_transfer(id: u32, from: &u64, to: &u64, amount: u64) {
+		CROSS_CHAIN.with(|c| c.borrow_mut().push((id, *from, Some(*to), amount)));
+	}
+	fn on_burn(id: u32, who: &u64, amount: u64) {
+		CROSS_CHAIN.with(|c| c.borrow_mut().push((id, *who, None, amount)));
+	}
+}
+
+/// Accounts sharing the transfer fees while `SplitFees` is set.
+pub(crate) const FEE_POOL: (u64, u64) = (8, 9);
+
+/// Pays fees to the owner, or splits them evenly across `FEE_POOL` while `SplitFees` is set.
+pub struct TestFeeRouter;
+impl FeeRouter<u64, u32, u64> for TestFeeRouter {
+	fn route(id: u32, owner: &u64, fee: u64) -> Vec<(Option<u64>, u64)> {
+		reenter_mint(id);
+		if SplitFees::get() {
+			vec![(Some(FEE_POOL.0), fee / 2), (Some(FEE_POOL.1), fee - fee / 2)]
+		} else {
+			vec![(Some(*owner), fee)]
+		}
+	}
+}
+
+/// Counts the balance changes of each account and records the highest balance it has held.
+pub struct TestTouchLog;
+impl OnAccountTouched<u32, u64, u64, (u32, u64)> for TestTouchLog {
+	fn on_touched(_: u32, _: &u64, balance: u64, extra: &mut (u32, u64)) {
+		extra.0 += 1;
+		extra.1 = extra.1.max(balance);
+	}
+}
+
+/// Auctions the asset ids from 100 to 199.
+pub struct PremiumIds;
+impl<AssetId: Copy + Into<u128>> Filter<AssetId> for PremiumIds {
+	fn filter(id: &AssetId) -> bool {
+		(100..200).contains(&(*id).into())
+	}
+}
+
+/// Presents asset ids as `FA-` followed by at least four digits.
+pub struct PrefixedAssetId;
+impl AssetIdDisplay<u32> for PrefixedAssetId {
+	fn to_string(id: &u32) -> Vec<u8> {
+		format!("FA-{:04}", id).into_bytes()
+	}
+	fn from_string(s: &[u8]) -> Option<u32> {
+		std::str::from_utf8(s.strip_prefix(b"FA-")?).ok()?.parse().ok()
+	}
+}
+
+/// Rolls a Tian destiny for 1%, Di for 9%, Xuan for 30% and Huang for 60% of the entropy space.
+pub struct TestFeatureRoller;
+impl FeatureRoller for TestFeatureRoller {
+	fn roll(entropy: u32) -> u32 {
+		let destiny = match entropy % 100 {
+			0 => 3,
+			1..=9 => 2,
+			10..=39 => 1,
+			_ => 0,
+		};
+		(destiny << 28) | (entropy & 0x0FFF_FFFF)
+	}
+}
+
+pub const TREASURY: u64 = 999;
+pub struct TestTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test, ()>> for TestTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test, ()>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
 }
 
 impl Config for Test {
@@ -81,22 +284,71 @@ impl Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type AssetDepositBase = AssetDepositBase;
 	type AssetDepositPerZombie = AssetDepositPerZombie;
+	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
+	type MaxTotalMetadataBytes = MaxTotalMetadataBytes;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type WeightInfo = ();
-	type AssetAdmin = ();
+	type AssetAdmin = TestAssetAdmin;
 	type RandomNumber = ();
+	type AssetIdDeriver = HashedAssetId<Test>;
+	type TransferPolicy = TestTransferPolicy;
+	type RateLimitWindow = RateLimitWindow;
+	type ReusableAssetIds = ReusableAssetIds;
+	type MaxBatchSize = MaxBatchSize;
+	type FeatureRoller = TestFeatureRoller;
+	type DestroyRefundRatio = DestroyRefundRatio;
+	type DestroyFeeDestination = TestTreasury;
+	type MaxMinBalance = MaxMinBalance;
+	type AssetIdConverter = ZeroPaddedAssetId;
+	type HoldOrigin = frame_system::EnsureRoot<u64>;
+	type BatchWeightLimit = BatchWeightLimit;
+	type DepositAsset = DepositAsset;
+	type SupplyReportPeriod = SupplyReportPeriod;
+	type MaxSupplyReportAssets = MaxSupplyReportAssets;
+	type AssetIdDisplay = PrefixedAssetId;
+	type MinAccountLifetime = MinAccountLifetime;
+	type OnDestroy = TestOnDestroy;
+	type EmitScaledAmounts = EmitScaledAmounts;
+	type ClaimDeposit = ClaimDeposit;
+	type ClaimPeriod = ClaimPeriod;
+	type FeeRouter = TestFeeRouter;
+	type MaxFeeShares = MaxFeeShares;
+	type AccountExtra = (u32, u64);
+	type OnAccountTouched = TestTouchLog;
+	type PremiumAssetIds = PremiumIds;
+	type IdAuctionDuration = IdAuctionDuration;
+	type IdAuctionClaimPeriod = IdAuctionClaimPeriod;
+	type IdAuctionProceeds = TestTreasury;
+	type CrossChainNotifier = TestCrossChainNotifier;
+	type RecordActivity = RecordActivity;
+	type MaxRecentActivity = MaxRecentActivity;
+	type BurnAddress = BurnAddress;
+	type MaxBurnRateBps = MaxBurnRateBps;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn witness(zombies: u32, approvals: u32) -> DestroyWitness {
+	DestroyWitness { zombies, approvals }
+}
+
+fn last_event() -> AssetsEvent<Test> {
+	System::events().into_iter().rev()
+		.find_map(|r| if let Event::mc_featured_assets(e) = r.event { Some(e) } else { None })
+		.expect("Event expected")
 }
 
 #[test]
 fn basic_minting_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
@@ -108,7 +360,7 @@ fn basic_minting_should_work() {
 fn lifecycle_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10));
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
 		assert_eq!(Balances::reserved_balance(&1), 11);
 		assert!(Asset::<Test>::contains_key(0));
 
@@ -120,14 +372,14 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, 100));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
 		assert!(!Metadata::<Test>::contains_key(0));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
 
-		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10));
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
 		assert_eq!(Balances::reserved_balance(&1), 11);
 		assert!(Asset::<Test>::contains_key(0));
 
@@ -139,7 +391,7 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		assert_ok!(Assets::force_destroy(Origin::root(), 0, 100));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(100, 0)));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
@@ -152,12 +404,12 @@ fn lifecycle_should_work() {
 fn destroy_with_non_zombies_should_not_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_noop!(Assets::destroy(Origin::signed(1), 0, 100), Error::<Test>::RefsLeft);
-		assert_noop!(Assets::force_destroy(Origin::root(), 0, 100), Error::<Test>::RefsLeft);
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)), Error::<Test>::RefsLeft);
+		assert_noop!(Assets::force_destroy(Origin::root(), 0, witness(100, 0)), Error::<Test>::RefsLeft);
 		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 100));
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, 100));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)));
 	});
 }
 
@@ -165,17 +417,17 @@ fn destroy_with_non_zombies_should_not_work() {
 fn destroy_with_bad_witness_should_not_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
-		assert_noop!(Assets::destroy(Origin::signed(1), 0, 0), Error::<Test>::BadWitness);
-		assert_noop!(Assets::force_destroy(Origin::root(), 0, 0), Error::<Test>::BadWitness);
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)), Error::<Test>::BadWitness);
+		assert_noop!(Assets::force_destroy(Origin::root(), 0, witness(0, 0)), Error::<Test>::BadWitness);
 	});
 }
 
 #[test]
 fn max_zombies_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 0, 100));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 
@@ -196,7 +448,7 @@ fn max_zombies_should_work() {
 #[test]
 fn resetting_max_zombies_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 1, ExistenceModel::Zombie));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
@@ -214,7 +466,7 @@ fn resetting_max_zombies_should_work() {
 #[test]
 fn dezombifying_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::zombie_allowance(0), 9);
 
@@ -237,7 +489,7 @@ fn dezombifying_should_work() {
 #[test]
 fn min_balance_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
 
@@ -267,7 +519,7 @@ fn min_balance_should_work() {
 #[test]
 fn querying_total_supply_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
@@ -285,7 +537,7 @@ fn querying_total_supply_should_work() {
 #[test]
 fn transferring_amount_below_available_balance_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
@@ -297,11 +549,11 @@ fn transferring_amount_below_available_balance_should_work() {
 #[test]
 fn transferring_frozen_user_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AccountFrozen);
 		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
 	});
@@ -310,11 +562,11 @@ fn transferring_frozen_user_should_not_work() {
 #[test]
 fn transferring_frozen_asset_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, None));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AssetFrozen);
 		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
 	});
@@ -323,17 +575,17 @@ fn transferring_frozen_asset_should_not_work() {
 #[test]
 fn origin_guards_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_noop!(Assets::transfer_ownership(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
 		// assert_noop!(Assets::set_team(Origin::signed(2), 0, 2, 2, 2), Error::<Test>::NoPermission);
-		assert_noop!(Assets::freeze(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::freeze(Origin::signed(2), 0, 1, None), Error::<Test>::NoPermission);
 		assert_noop!(Assets::thaw(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
 		assert_noop!(Assets::mint(Origin::signed(2), 0, 2, 100), Error::<Test>::NoPermission);
 		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 100), Error::<Test>::NoPermission);
 		assert_noop!(Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100), Error::<Test>::NoPermission);
 		assert_noop!(Assets::set_max_zombies(Origin::signed(2), 0, 11), Error::<Test>::NoPermission);
-		assert_noop!(Assets::destroy(Origin::signed(2), 0, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::destroy(Origin::signed(2), 0, witness(100, 0)), Error::<Test>::NoPermission);
 	});
 }
 
@@ -341,8 +593,8 @@ fn origin_guards_should_work() {
 fn transfer_owner_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		Balances::make_free_balance_be(&2, 1);
-		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10));
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
 
 		assert_eq!(Balances::reserved_balance(&1), 11);
 
@@ -361,11 +613,11 @@ fn transfer_owner_should_work() {
 #[test]
 fn set_team_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
-		// assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 3, 4));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		set_team(2, 3, 4);
 
 		assert_ok!(Assets::mint(Origin::signed(2), 0, 2, 100));
-		assert_ok!(Assets::freeze(Origin::signed(4), 0, 2));
+		assert_ok!(Assets::freeze(Origin::signed(4), 0, 2, None));
 		assert_ok!(Assets::thaw(Origin::signed(3), 0, 2));
 		assert_ok!(Assets::force_transfer(Origin::signed(3), 0, 2, 3, 100));
 		assert_ok!(Assets::burn(Origin::signed(3), 0, 3, 100));
@@ -375,12 +627,12 @@ fn set_team_should_work() {
 #[test]
 fn transferring_to_frozen_account_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_eq!(Assets::balance(0, 2), 100);
-		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
 		assert_eq!(Assets::balance(0, 2), 150);
 	});
@@ -389,7 +641,7 @@ fn transferring_to_frozen_account_should_work() {
 #[test]
 fn transferring_amount_more_than_available_balance_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
@@ -405,7 +657,7 @@ fn transferring_amount_more_than_available_balance_should_not_work() {
 #[test]
 fn transferring_less_than_one_unit_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 0), Error::<Test>::AmountZero);
@@ -415,7 +667,7 @@ fn transferring_less_than_one_unit_should_not_work() {
 #[test]
 fn transferring_more_units_than_total_supply_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
@@ -425,7 +677,7 @@ fn transferring_more_units_than_total_supply_should_not_work() {
 #[test]
 fn burning_asset_balance_with_positive_balance_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
@@ -436,7 +688,7 @@ fn burning_asset_balance_with_positive_balance_should_work() {
 #[test]
 fn burning_asset_balance_with_zero_balance_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 2), 0);
 		assert_noop!(Assets::burn(Origin::signed(1), 0, 2, u64::max_value()), Error::<Test>::BalanceZero);
@@ -451,7 +703,7 @@ fn set_metadata_should_work() {
 			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12),
 			Error::<Test>::Unknown,
 		);
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
 		// Cannot add metadata to unowned asset
 		assert_noop!(
 			Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12),
@@ -491,3 +743,3741 @@ fn set_metadata_should_work() {
 		assert!(!Metadata::<Test>::contains_key(0));
 	});
 }
+
+#[test]
+fn create_deterministic_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		let id = HashedAssetId::<Test>::derive_asset_id(&1, &[1u8; 32]);
+		assert_ok!(Assets::create_deterministic(Origin::signed(1), [1u8; 32], 10, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(last_event(), AssetsEvent::DeterministicCreated(id, 1, [1u8; 32]));
+		assert!(Asset::<Test>::contains_key(id));
+		assert!(Feature::<Test>::contains_key(id));
+		assert_eq!(Balances::reserved_balance(&1), 11);
+	});
+}
+
+#[test]
+fn create_deterministic_with_same_salt_should_not_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create_deterministic(Origin::signed(1), [1u8; 32], 10, 1, 10, ExistenceModel::Zombie));
+		assert_noop!(
+			Assets::create_deterministic(Origin::signed(1), [1u8; 32], 10, 1, 10, ExistenceModel::Zombie),
+			Error::<Test>::InUse,
+		);
+
+		// A different salt or a different creator derives a different id.
+		assert_ok!(Assets::create_deterministic(Origin::signed(1), [2u8; 32], 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::create_deterministic(Origin::signed(2), [1u8; 32], 10, 1, 10, ExistenceModel::Zombie));
+		assert_ne!(
+			HashedAssetId::<Test>::derive_asset_id(&1, &[1u8; 32]),
+			HashedAssetId::<Test>::derive_asset_id(&1, &[2u8; 32]),
+		);
+		assert_eq!(Asset::<Test>::iter().count(), 3);
+	});
+}
+
+#[test]
+fn transfer_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, BLOCKED, 50), Error::<Test>::PolicyViolation);
+		assert_noop!(
+			Assets::force_transfer(Origin::signed(1), 0, 1, BLOCKED, 50),
+			Error::<Test>::PolicyViolation,
+		);
+		assert_eq!(Assets::balance(0, 1), 100);
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 2, 3, 25));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 25);
+		assert_eq!(Assets::balance(0, 3), 25);
+	});
+}
+
+#[test]
+fn set_metadata_only_reserves_the_difference() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 30);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 5], vec![0u8; 5], 0));
+		assert_eq!(Balances::reserved_balance(1), 11);
+
+		// grow
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 5], 0));
+		assert_eq!(Balances::reserved_balance(1), 16);
+		assert_eq!(Metadata::<Test>::get(0).deposit, 16);
+
+		// shrink
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 2], vec![0u8; 2], 0));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(Metadata::<Test>::get(0).deposit, 5);
+
+		// same size
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 2], vec![1u8; 2], 0));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		// clear
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![], vec![], 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn set_metadata_failing_to_reserve_should_not_update() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 15);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 5], vec![0u8; 5], 12));
+		let before = Metadata::<Test>::get(0);
+
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 10], vec![1u8; 10], 12),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+		assert_eq!(Metadata::<Test>::get(0), before);
+		assert_eq!(Balances::reserved_balance(1), 11);
+	});
+}
+
+#[test]
+fn set_max_zombies_should_track_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(1), 11);
+
+		assert_ok!(Assets::set_max_zombies(Origin::signed(1), 0, 20));
+		assert_eq!(Balances::reserved_balance(1), 21);
+		assert_ok!(Assets::set_max_zombies(Origin::signed(1), 0, 5));
+		assert_eq!(Balances::reserved_balance(1), 6);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(1), 1);
+
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 40));
+		assert_eq!(last_event(), AssetsEvent::TransferredApproved(0, 1, 2, 3, 40));
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 20), Error::<Test>::Unapproved);
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 10));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 3), 50);
+
+		// Fully spent approvals are removed and refunded.
+		assert!(!Approvals::<Test>::contains_key(0, (1, 2)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn transfer_approved_should_charge_nothing_when_the_transfer_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 4, 100));
+		Balances::make_free_balance_be(&4, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(4), 0, 2, 50));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, true));
+
+		// The fee is due, but the destination is not allowed.
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 4, 3, 40),
+			Error::<Test>::NotAllowed,
+		);
+		assert_eq!((Assets::balance(0, 4), Assets::balance(0, 1)), (100, 0));
+		assert_eq!(Approvals::<Test>::get(0, (4, 2)).unwrap().amount, 50);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_noop!(Assets::cancel_approval(Origin::signed(1), 0, 3), Error::<Test>::Unknown);
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 10), Error::<Test>::Unapproved);
+	});
+}
+
+#[test]
+fn destroy_should_refund_approvals() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
+		Balances::make_free_balance_be(&10, 10);
+		Balances::make_free_balance_be(&20, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(10), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(20), 0, 2, 50));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 10, 100));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 20, 100));
+
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, witness(100, 1)), Error::<Test>::BadWitness);
+		assert_noop!(Assets::force_destroy(Origin::root(), 0, witness(100, 1)), Error::<Test>::BadWitness);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 2)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(10), 0);
+		assert_eq!(Balances::reserved_balance(20), 0);
+		assert_eq!(Approvals::<Test>::iter_prefix(0).count(), 0);
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::ApprovalCancelled(0, 10, 2))
+		));
+	});
+}
+
+#[test]
+fn force_cancel_approvals_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		for who in 10..13 {
+			Balances::make_free_balance_be(&who, 10);
+			assert_ok!(Assets::approve_transfer(Origin::signed(who), 0, 2, 50));
+		}
+		assert_noop!(Assets::force_cancel_approvals(Origin::signed(1), 0, 3), BadOrigin);
+
+		assert_ok!(Assets::force_cancel_approvals(Origin::root(), 0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		assert_eq!(Approvals::<Test>::iter_prefix(0).count(), 1);
+
+		assert_ok!(Assets::force_cancel_approvals(Origin::root(), 0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert!((10..13).all(|who| Balances::reserved_balance(who) == 0));
+	});
+}
+
+#[test]
+fn daily_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_daily_limit(Origin::signed(2), 0, Some(30)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_daily_limit(Origin::signed(1), 0, Some(30)));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 20));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 11), Error::<Test>::DailyLimitExceeded);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::DailyLimitExceeded);
+
+		// force_transfer is not limited.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 2, 10));
+
+		// The window resets after `RateLimitWindow` blocks.
+		System::set_block_number(10);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::DailyLimitExceeded);
+		System::set_block_number(11);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		assert_eq!(TransferredToday::<Test>::get(0, 1), (30, 11));
+
+		// Lifting the limit.
+		assert_ok!(Assets::set_daily_limit(Origin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 20));
+		assert_eq!(Assets::balance(0, 2), 90);
+	});
+}
+
+#[test]
+fn retired_asset_ids_should_not_be_reused() {
+	new_test_ext().execute_with(|| {
+		ReusableAssetIds::set(false);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+		assert_noop!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie), Error::<Test>::AssetRetired);
+		assert_noop!(Assets::create(Origin::signed(1), 0, 10, 1, 0, ExistenceModel::Zombie), Error::<Test>::AssetRetired);
+
+		assert_noop!(Assets::purge_retired(Origin::signed(1), 0), BadOrigin);
+		assert_noop!(Assets::purge_retired(Origin::root(), 1), Error::<Test>::Unknown);
+		assert_ok!(Assets::purge_retired(Origin::root(), 0));
+		assert_eq!(last_event(), AssetsEvent::RetiredPurged(0));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+	});
+}
+
+#[test]
+fn asset_ids_should_be_reusable_when_allowed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+	});
+}
+
+#[test]
+fn transferable_now_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::transferable_now(0, 1), 0);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::transferable_now(0, 1), 100);
+		assert_eq!(Assets::transferable_now(0, 2), 0);
+
+		// Capped by what is left of the window.
+		assert_ok!(Assets::set_daily_limit(Origin::signed(1), 0, Some(40)));
+		assert_eq!(Assets::transferable_now(0, 1), 40);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 25));
+		assert_eq!(Assets::transferable_now(0, 1), 15);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 16), Error::<Test>::DailyLimitExceeded);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 15));
+		assert_eq!(Assets::transferable_now(0, 1), 0);
+
+		// The window resets.
+		System::set_block_number(11);
+		assert_eq!(Assets::transferable_now(0, 1), 40);
+
+		// Freezes always win.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_eq!(Assets::transferable_now(0, 1), 0);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, None));
+		assert_eq!(Assets::transferable_now(0, 1), 0);
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+
+		// Spending the remainder of the window must not leave dust behind.
+		assert_ok!(Assets::set_daily_limit(Origin::signed(1), 0, Some(58)));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::transferable_now(0, 1), 55);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 56), Error::<Test>::DailyLimitExceeded);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 55));
+		assert_eq!(Assets::balance(0, 1), 5);
+	});
+}
+
+#[test]
+fn owner_clear_approvals_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 0, 4, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(3), 0, 4, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(3), 0, 5, 50));
+		assert_eq!(Balances::reserved_balance(2), 1);
+		assert_eq!(Balances::reserved_balance(3), 2);
+
+		let pairs = vec![(2, 4), (3, 5), (2, 5)];
+		assert_noop!(Assets::owner_clear_approvals(Origin::signed(2), 0, pairs.clone()), Error::<Test>::NoPermission);
+		assert_noop!(Assets::owner_clear_approvals(Origin::signed(1), 0, vec![(2, 4); 6]), Error::<Test>::BatchTooLarge);
+		assert_ok!(Assets::owner_clear_approvals(Origin::signed(1), 0, pairs));
+
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 1);
+		assert!(!Approvals::<Test>::contains_key(0, (2, 4)));
+		assert!(!Approvals::<Test>::contains_key(0, (3, 5)));
+		assert!(Approvals::<Test>::contains_key(0, (3, 4)));
+		assert_eq!(last_event(), AssetsEvent::ApprovalCancelled(0, 3, 5));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+	});
+}
+
+#[test]
+fn mint_and_burn_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_noop!(Assets::mint_batch(Origin::signed(2), 0, vec![(2, 10)]), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint_batch(Origin::signed(1), 0, vec![(2, 10); 6]), Error::<Test>::BatchTooLarge);
+		// All or nothing.
+		assert_noop!(Assets::mint_batch(Origin::signed(1), 0, vec![(2, 10), (3, 1)]), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::mint_batch(Origin::signed(1), 0, vec![(2, 10), (3, 20), (2, 5)]));
+		assert_eq!(Assets::balance(0, 2), 15);
+		assert_eq!(Assets::balance(0, 3), 20);
+		assert_eq!(Assets::total_supply(0), 35);
+
+		assert_noop!(Assets::burn_batch(Origin::signed(1), 0, vec![(2, 5), (4, 5)]), Error::<Test>::BalanceZero);
+		assert_ok!(Assets::burn_batch(Origin::signed(1), 0, vec![(2, 5), (3, 16)]));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::balance(0, 3), 0);
+		assert_eq!(Assets::total_supply(0), 10);
+		assert_eq!(last_event(), AssetsEvent::Burned(0, 3, 20));
+	});
+}
+
+#[test]
+fn transfer_multi_and_freeze_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::transfer_multi(Origin::signed(1), 0, vec![(2, 10), (3, 0)]), Error::<Test>::AmountZero);
+		assert_noop!(Assets::transfer_multi(Origin::signed(1), 0, vec![(2, 60), (3, 60)]), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::transfer_multi(Origin::signed(1), 0, vec![(2, 10), (3, 20)]));
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::balance(0, 3), 20);
+
+		assert_noop!(Assets::freeze_batch(Origin::signed(1), 0, vec![2, 4]), Error::<Test>::BalanceZero);
+		assert_ok!(Assets::freeze_batch(Origin::signed(1), 0, vec![2, 3]));
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 1, 5), Error::<Test>::AccountFrozen);
+		assert_noop!(Assets::transfer(Origin::signed(3), 0, 1, 5), Error::<Test>::AccountFrozen);
+		set_team(1, 1, 2);
+		assert_noop!(Assets::freeze_batch(Origin::signed(1), 0, vec![1]), Error::<Test>::NoPermission);
+	});
+}
+
+#[test]
+fn airdrop_should_skip_recipients_that_cannot_receive() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 20));
+		assert_noop!(Assets::airdrop(Origin::signed(2), 0, vec![3], 10), Error::<Test>::NoPermission);
+		assert_noop!(Assets::airdrop(Origin::signed(1), 0, vec![3; 6], 10), Error::<Test>::BatchTooLarge);
+
+		// Below `min_balance` for new accounts, but fine for existing holders.
+		assert_ok!(Assets::airdrop(Origin::signed(1), 0, vec![3, 4], 5));
+		assert_eq!(last_event(), AssetsEvent::AirdropCompleted(0, 1, 1));
+		assert_eq!(Assets::balance(0, 3), 25);
+		assert_eq!(Assets::balance(0, 4), 0);
+
+		// Account 3 already used one of the two zombie slots; 2 is not a zombie.
+		assert_ok!(Assets::airdrop(Origin::signed(1), 0, vec![4, 5, 2, 6], 10));
+		assert_eq!(last_event(), AssetsEvent::AirdropCompleted(0, 2, 2));
+		assert_eq!(Assets::balance(0, 4), 10);
+		assert_eq!(Assets::balance(0, 5), 0);
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::balance(0, 6), 0);
+		assert_eq!(Assets::total_supply(0), 45);
+	});
+}
+
+#[test]
+fn sufficient_assets_should_provide_for_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Sufficient),
+			Error::<Test>::ZombiesNotAllowed,
+		);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 0, 1, ExistenceModel::Sufficient));
+		assert_noop!(Assets::set_max_zombies(Origin::signed(1), 0, 1), Error::<Test>::ZombiesNotAllowed);
+
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 5, 100));
+		assert!(System::account_exists(&5));
+		assert_eq!(System::providers(&5), 1);
+		assert_eq!(Assets::zombie_allowance(0), 0);
+
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 6, 100));
+		assert!(!System::account_exists(&5));
+		assert!(System::account_exists(&6));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 6, 100));
+		assert!(!System::account_exists(&6));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+	});
+}
+
+#[test]
+fn system_ref_only_assets_should_require_existing_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Assets::create(Origin::signed(1), 0, 1, 1, 10, ExistenceModel::SystemRefOnly),
+			Error::<Test>::ZombiesNotAllowed,
+		);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 0, 1, ExistenceModel::SystemRefOnly));
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 5, 100), Error::<Test>::NoProvider);
+
+		Balances::make_free_balance_be(&5, 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 5, 100));
+		assert_eq!(System::consumers(&5), 1);
+		assert_noop!(Assets::transfer(Origin::signed(5), 0, 6, 50), Error::<Test>::NoProvider);
+
+		Balances::make_free_balance_be(&6, 100);
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 6, 100));
+		assert_eq!(System::consumers(&5), 0);
+		assert_eq!(System::consumers(&6), 1);
+	});
+}
+
+#[test]
+fn zombie_assets_should_keep_zombie_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 2, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 5, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 6, 100));
+		assert!(!System::account_exists(&5));
+		assert_eq!(Assets::zombie_allowance(0), 0);
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 7, 100), Error::<Test>::TooManyZombies);
+
+		Balances::make_free_balance_be(&5, 100);
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 6, 50));
+		assert_eq!(System::consumers(&5), 1);
+		assert_eq!(Assets::zombie_allowance(0), 1);
+	});
+}
+
+#[test]
+fn migration_to_v0_2_should_work() {
+	use frame_support::{storage::unhashed, traits::PalletVersion};
+
+	new_test_ext().execute_with(|| {
+		// owner, supply, deposit, max_zombies, min_balance, zombies, accounts, is_frozen, is_featured
+		let old = (1u64, 100u64, 10u64, 5u32, 2u64, 1u32, 3u32, true, true);
+		unhashed::put(&Asset::<Test>::hashed_key_for(0), &old);
+		// balance, is_frozen, is_zombie
+		unhashed::put(&Account::<Test>::hashed_key_for(0, 2), &(40u64, true, false));
+		unhashed::put(&Account::<Test>::hashed_key_for(0, 3), &(50u64, false, true));
+		PalletVersion::new(0, 1, 0).put_into_storage::<PalletInfo, Assets>();
+
+		migrations::migrate::<Test>();
+
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!(details.owner, 1);
+		assert_eq!(details.supply, 100);
+		assert_eq!(details.deposit, 10);
+		assert_eq!(details.max_zombies, 5);
+		assert_eq!(details.min_balance, 2);
+		assert_eq!(details.zombies, 1);
+		assert_eq!(details.accounts, 3);
+		assert_eq!(details.approvals, 0);
+		assert!(details.is_frozen);
+		assert_eq!(details.daily_limit, None);
+		assert_eq!(details.existence_model, ExistenceModel::Zombie);
+		assert!(details.burnable);
+		assert_eq!(details.creator, 1);
+		assert_eq!(Assets::frozen_assets(None, 10), vec![0]);
+		assert_eq!(Assets::assets_created_by(1), vec![0]);
+
+		let account = Account::<Test>::get(0, 2);
+		assert_eq!(account.balance, 40);
+		assert!(account.is_frozen());
+		assert!(!account.is_zombie());
+		assert_eq!(account.on_hold, 0);
+		let account = Account::<Test>::get(0, 3);
+		assert_eq!(account.balance, 50);
+		assert!(!account.is_frozen());
+		assert!(account.is_zombie());
+	});
+}
+
+#[test]
+fn verify_asset_should_report_drift() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::verify_asset(Origin::signed(3), 1, 2), Error::<Test>::Unknown);
+		assert_noop!(Assets::verify_asset(Origin::signed(3), 0, 1), Error::<Test>::BadWitness);
+
+		assert_ok!(Assets::verify_asset(Origin::signed(3), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::InvariantCheck(0, true, true, true));
+
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().supply = 150);
+		assert_ok!(Assets::verify_asset(Origin::signed(3), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::InvariantCheck(0, false, true, true));
+
+		Asset::<Test>::mutate(0, |d| {
+			let d = d.as_mut().unwrap();
+			d.supply = 200;
+			d.accounts = 3;
+			d.zombies = 1;
+		});
+		assert_ok!(Assets::verify_asset(Origin::signed(3), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::InvariantCheck(0, true, false, false));
+	});
+}
+
+#[test]
+fn feature_roller_should_bias_destiny() {
+	use mc_support::primitives::FeatureDestinyRank;
+
+	let mut counts = [0u32; 4];
+	for entropy in 0..1000 {
+		let rank: u8 = Assets::new_feature_detail(TestFeatureRoller::roll(entropy)).destiny.into();
+		counts[rank as usize] += 1;
+	}
+	assert_eq!(counts, [600, 300, 90, 10]);
+
+	new_test_ext().execute_with(|| {
+		// The test randomness always draws zero, which the roller maps to Tian.
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::feature(0).unwrap().destiny, FeatureDestinyRank::Tian);
+	});
+}
+
+#[test]
+fn transfer_ownership_to_pallet_should_work() {
+	use sp_runtime::{ModuleId, traits::AccountIdConversion};
+
+	new_test_ext().execute_with(|| {
+		let module_id = *b"mc/trsry";
+		let custodian: u64 = ModuleId(module_id).into_account();
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(1), 11);
+
+		assert_noop!(Assets::transfer_ownership_to_pallet(Origin::signed(2), 0, module_id), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::transfer_ownership_to_pallet(Origin::signed(1), 0, module_id),
+			BalancesError::<Test, _>::DeadAccount,
+		);
+		Balances::make_free_balance_be(&custodian, 1);
+		assert_ok!(Assets::transfer_ownership_to_pallet(Origin::signed(1), 0, module_id));
+		assert_eq!(last_event(), AssetsEvent::OwnerChanged(0, custodian));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(custodian), 11);
+		assert_noop!(Assets::transfer_ownership(Origin::signed(1), 0, 2), Error::<Test>::NoPermission);
+		Balances::make_free_balance_be(&2, 1);
+		assert_ok!(Assets::transfer_ownership(Origin::signed(custodian), 0, 2));
+		assert_eq!(Balances::reserved_balance(2), 11);
+	});
+}
+
+#[test]
+fn freeze_errors_should_name_what_is_frozen() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+
+		// An asset-level freeze is reported even when the account is frozen too.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, None));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AssetFrozen);
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 50), Error::<Test>::AssetFrozen);
+
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AssetFrozen);
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 50), Error::<Test>::AccountFrozen);
+		// Admin transfers ignore freezes.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 3, 50));
+	});
+}
+
+#[test]
+fn destroy_should_refund_full_deposit_by_default() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(1), 32);
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+	});
+}
+
+#[test]
+fn destroy_should_keep_part_of_deposit_as_fee() {
+	new_test_ext().execute_with(|| {
+		DestroyRefundRatio::set(Permill::from_percent(75));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(1), 32);
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+		assert!(System::events().iter().any(|r| r.event == Event::mc_featured_assets(AssetsEvent::DestroyFeePaid(0, 8))));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 92);
+		assert_eq!(Balances::free_balance(TREASURY), 8);
+
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_destroy(Origin::root(), 1, witness(0, 0)));
+		assert_eq!(last_event(), AssetsEvent::Destroyed(1));
+		assert_eq!(Balances::free_balance(1), 92);
+	});
+}
+
+#[test]
+fn can_receive_should_work() {
+	new_test_ext().execute_with(|| {
+		let cannot = |reason| CanReceive { possible: false, reason: Some(reason) };
+		let can = CanReceive { possible: true, reason: None };
+
+		assert_eq!(Assets::can_receive(0, 2), cannot(ReceiveError::UnknownAsset));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::can_receive(0, 2), can);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::can_receive(0, 2), can);
+		assert_eq!(Assets::can_receive(0, 3), cannot(ReceiveError::TooManyZombies));
+		Balances::make_free_balance_be(&3, 100);
+		assert_eq!(Assets::can_receive(0, 3), can);
+
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 0, 1, ExistenceModel::SystemRefOnly));
+		assert_eq!(Assets::can_receive(1, 2), cannot(ReceiveError::NoProvider));
+		assert_eq!(Assets::can_receive(1, 3), can);
+
+		assert_ok!(Assets::force_create(Origin::root(), 2, 1, 0, 1, ExistenceModel::Sufficient));
+		assert_eq!(Assets::can_receive(2, 4), can);
+	});
+}
+
+#[test]
+fn can_receive_should_respect_the_destination_whitelist() {
+	new_test_ext().execute_with(|| {
+		let cannot = |reason| CanReceive { possible: false, reason: Some(reason) };
+		let can = CanReceive { possible: true, reason: None };
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, true));
+
+		// Holding the asset already makes no difference.
+		assert_eq!(Assets::can_receive(0, 2), cannot(ReceiveError::NotAllowlisted));
+		assert_eq!(Assets::can_receive(0, 3), cannot(ReceiveError::NotAllowlisted));
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 3, 10), Error::<Test>::NotAllowed);
+		assert_ok!(Assets::add_destination(Origin::signed(1), 0, 3));
+		assert_eq!(Assets::can_receive(0, 3), can);
+
+		// The team is exempt unless the whitelist is strict.
+		assert_eq!(Assets::can_receive(0, 1), can);
+		assert_ok!(Assets::set_strict_whitelist(Origin::signed(1), 0, true));
+		assert_eq!(Assets::can_receive(0, 1), cannot(ReceiveError::NotAllowlisted));
+	});
+}
+
+#[test]
+fn can_receive_should_fail_once_the_asset_expired() {
+	new_test_ext().execute_with(|| {
+		let cannot = |reason| CanReceive { possible: false, reason: Some(reason) };
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, Some(5)));
+		assert_eq!(Assets::can_receive(0, 2).reason, None);
+
+		System::set_block_number(5);
+		assert_eq!(Assets::can_receive(0, 2), cannot(ReceiveError::Expired));
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 3, 10), Error::<Test>::AssetExpired);
+	});
+}
+
+#[test]
+fn feature_code_of_should_round_trip() {
+	use mc_support::primitives::{FeatureElements, FeatureHue, FeatureLevel, FeatureRankedLevel};
+
+	let hues: Vec<FeatureHue> = (0..9).map(FeatureHue::from).collect();
+	let mut elements = Vec::new();
+	for a in &hues {
+		elements.push(FeatureElements::One(a.clone()));
+		for b in &hues {
+			elements.push(FeatureElements::Two(a.clone(), b.clone()));
+			for c in &hues {
+				elements.push(FeatureElements::Three(a.clone(), b.clone(), c.clone()));
+				for d in &hues {
+					elements.push(FeatureElements::Four(a.clone(), b.clone(), c.clone(), d.clone()));
+				}
+			}
+		}
+	}
+	let levels: Vec<FeatureLevel> = (0..6).map(FeatureLevel::from).collect();
+	let mut saturations = Vec::new();
+	for level in &levels {
+		saturations.push(FeatureRankedLevel::Low(level.clone()));
+		saturations.push(FeatureRankedLevel::Middle(level.clone()));
+		saturations.push(FeatureRankedLevel::High(level.clone()));
+	}
+
+	for destiny in 0..4u8 {
+		for lightness in &levels {
+			for saturation in &saturations {
+				for elements in &elements {
+					let feature = AssetFeature {
+						destiny: destiny.into(),
+						elements: elements.clone(),
+						saturation: saturation.clone(),
+						lightness: lightness.clone(),
+					};
+					assert_eq!(Assets::new_feature_detail(Assets::feature_code_of(&feature)), feature);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn min_balance_above_maximum_should_not_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(
+			Assets::force_create(Origin::root(), 0, 1, 10, 1_001, ExistenceModel::Zombie),
+			Error::<Test>::MinBalanceTooHigh,
+		);
+		assert_noop!(
+			Assets::create(Origin::signed(1), 0, 10, 1_001, 10, ExistenceModel::Zombie),
+			Error::<Test>::MinBalanceTooHigh,
+		);
+		assert_noop!(
+			Assets::create_deterministic(Origin::signed(1), [0u8; 32], 10, 1_001, 10, ExistenceModel::Zombie),
+			Error::<Test>::MinBalanceTooHigh,
+		);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1_000, ExistenceModel::Zombie));
+		assert_ok!(Assets::create(Origin::signed(1), 1, 10, 1_000, 10, ExistenceModel::Zombie));
+	});
+}
+
+#[test]
+fn stream_should_pay_out_over_time() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 11));
+		assert_eq!(Assets::balance(0, 1), 900);
+		assert_eq!(Escrowed::<Test>::get(0), 100);
+		assert_eq!(Assets::total_supply(0), 1000);
+
+		System::set_block_number(4);
+		assert_noop!(Assets::withdraw_stream(Origin::signed(1), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::withdraw_stream(Origin::signed(2), 0));
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_noop!(Assets::withdraw_stream(Origin::signed(2), 0), Error::<Test>::AmountZero);
+
+		System::set_block_number(20);
+		assert_ok!(Assets::withdraw_stream(Origin::signed(2), 0));
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_eq!(Escrowed::<Test>::get(0), 0);
+		assert!(!Streams::<Test>::contains_key(0));
+		assert_noop!(Assets::withdraw_stream(Origin::signed(2), 0), Error::<Test>::UnknownStream);
+	});
+}
+
+#[test]
+fn cancel_stream_should_split_escrow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 11));
+		assert_ok!(Assets::create_stream(Origin::signed(1), 0, 3, 10, 11));
+		assert_eq!(NextStreamId::<Test>::get(), 2);
+
+		System::set_block_number(3);
+		assert_ok!(Assets::withdraw_stream(Origin::signed(2), 0));
+		System::set_block_number(6);
+		assert_noop!(Assets::cancel_stream(Origin::signed(3), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::cancel_stream(Origin::signed(1), 0));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::balance(0, 1), 850);
+		assert_eq!(last_event(), AssetsEvent::StreamCancelled(0, 30, 50));
+
+		// The recipient may cancel too, even before withdrawing anything.
+		assert_ok!(Assets::cancel_stream(Origin::signed(3), 1));
+		assert_eq!(Assets::balance(0, 3), 50);
+		assert_eq!(Assets::balance(0, 1), 900);
+		assert_eq!(Escrowed::<Test>::get(0), 0);
+		assert_noop!(Assets::cancel_stream(Origin::signed(1), 1), Error::<Test>::UnknownStream);
+	});
+}
+
+#[test]
+fn create_stream_should_check_arguments_and_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 1), Error::<Test>::InvalidStreamEnd);
+		assert_noop!(Assets::create_stream(Origin::signed(1), 0, 2, 0, 11), Error::<Test>::AmountZero);
+		assert_noop!(Assets::create_stream(Origin::signed(1), 0, 2, 11, 11), Error::<Test>::BalanceLow);
+		// The sender may not be left with dust.
+		assert_noop!(Assets::create_stream(Origin::signed(1), 0, 2, 1, 98), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_noop!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 11), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+
+		// Spending the whole balance removes the account; the escrow blocks destruction.
+		assert_ok!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 11));
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert_noop!(Assets::force_destroy(Origin::root(), 0, witness(100, 0)), Error::<Test>::RefsLeft);
+	});
+}
+
+#[test]
+fn transfer_should_roll_back_when_destination_cannot_be_referenced() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		// Make the zombie sender exist in the system, so the transfer would dezombify it.
+		Balances::make_free_balance_be(&1, 100);
+		// An account known to the system but without providers cannot take a consumer reference.
+		frame_system::Account::<Test>::mutate(2, |a| a.nonce = 1);
+		assert!(System::account_exists(&2));
+
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::BadState);
+		assert_eq!(System::consumers(&1), 0);
+		assert_eq!(System::consumers(&2), 0);
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::zombie_allowance(0), 9);
+	});
+}
+
+#[test]
+fn frozen_assets_should_track_freezes() {
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, 10, 1, ExistenceModel::Zombie));
+		}
+		assert!(Assets::frozen_assets(None, 10).is_empty());
+
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, None));
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 2, None));
+		let mut frozen = Assets::frozen_assets(None, 10);
+		frozen.sort();
+		assert_eq!(frozen, vec![0, 2]);
+
+		// Paging through the set visits every frozen asset once.
+		let first = Assets::frozen_assets(None, 1);
+		assert_eq!(first.len(), 1);
+		let rest = Assets::frozen_assets(Some(first[0]), 10);
+		assert_eq!(rest.len(), 1);
+		assert_ne!(first[0], rest[0]);
+		assert!(Assets::frozen_assets(Some(rest[0]), 10).is_empty());
+		assert!(Assets::frozen_assets(Some(1), 10).is_empty());
+
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+		assert_eq!(Assets::frozen_assets(None, 10), vec![2]);
+
+		assert_ok!(Assets::force_destroy(Origin::root(), 2, witness(100, 0)));
+		assert!(Assets::frozen_assets(None, 10).is_empty());
+	});
+}
+
+#[test]
+fn mint_to_owner_should_follow_ownership() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Assets::mint_to_owner(Origin::signed(1), 0, 100), Error::<Test>::Unknown);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_noop!(Assets::mint_to_owner(Origin::signed(2), 0, 100), Error::<Test>::NoPermission);
+
+		assert_ok!(Assets::mint_to_owner(Origin::signed(1), 0, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(last_event(), AssetsEvent::Issued(0, 1, 100));
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::mint_to_owner(Origin::signed(1), 0, 50));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::total_supply(0), 150);
+	});
+}
+
+#[test]
+fn large_transfers_should_be_reported() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_noop!(Assets::set_whale_threshold(Origin::signed(2), 0, Some(100)), Error::<Test>::NoPermission);
+
+		// No threshold, no alerts.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 500));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 500));
+
+		assert_ok!(Assets::set_whale_threshold(Origin::signed(1), 0, Some(100)));
+		assert_eq!(last_event(), AssetsEvent::WhaleThresholdSet(0, Some(100)));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 99));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 99));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		assert_eq!(last_event(), AssetsEvent::LargeTransfer(0, 1, 2, 100));
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 2, 3, 200));
+		assert_eq!(last_event(), AssetsEvent::LargeTransfer(0, 2, 3, 200));
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::ForceTransferred(0, 2, 3, 200))
+		));
+
+		assert_ok!(Assets::set_whale_threshold(Origin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 200));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 200));
+	});
+}
+
+#[test]
+fn purge_all_dust_should_resume_from_cursor() {
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, 10, 1, ExistenceModel::Zombie));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 1, 100));
+		}
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 20));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 4, 5));
+		// As if a migration had raised the minimum balance of assets 0 and 1.
+		for id in 0..2 {
+			Asset::<Test>::mutate(id, |d| d.as_mut().unwrap().min_balance = 50);
+		}
+		System::set_block_number(11);
+
+		assert_noop!(Assets::purge_all_dust(Origin::signed(1), 10), BadOrigin);
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 4));
+		assert!(!DustCursor::<Test>::get().is_empty());
+		let first = match last_event() {
+			AssetsEvent::GlobalDustPurged(purged, _) => purged,
+			e => panic!("unexpected event {:?}", e),
+		};
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 4));
+		assert!(DustCursor::<Test>::get().is_empty());
+		let second = match last_event() {
+			AssetsEvent::GlobalDustPurged(purged, _) => purged,
+			e => panic!("unexpected event {:?}", e),
+		};
+		assert_eq!(first + second, 3);
+
+		for (id, who) in &[(0, 2), (0, 3), (1, 4)] {
+			assert!(!Account::<Test>::contains_key(id, who));
+		}
+		assert_eq!(Assets::total_supply(0), 100);
+		assert_eq!(Assets::total_supply(1), 100);
+		assert_eq!(Assets::total_supply(2), 100);
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
+
+		// A new sweep starts over and finds nothing left.
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::GlobalDustPurged(0, 0));
+	});
+}
+
+#[test]
+fn do_burn_should_return_actual_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::do_burn(0, 1, 50), Ok(50));
+		assert_eq!(Assets::balance(0, 1), 50);
+
+		// Burning 45 would leave 5, below the minimum balance, so everything goes.
+		assert_eq!(Assets::do_burn(0, 1, 45), Ok(50));
+		assert_eq!(last_event(), AssetsEvent::Burned(0, 1, 50));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_noop!(Assets::do_burn(0, 1, 1), Error::<Test>::BalanceZero);
+	});
+}
+
+#[test]
+fn destroy_should_remove_feature() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 0x1234_5678, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert!(Assets::feature(0).is_some());
+		assert!(Assets::feature(1).is_some());
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)));
+		assert_ok!(Assets::force_destroy(Origin::root(), 1, witness(100, 0)));
+		assert_eq!(Assets::feature(0), None);
+		assert_eq!(Assets::feature(1), None);
+	});
+}
+
+#[test]
+fn spin_off_should_credit_holders_in_pages() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		// One page worth of holders, so the first call cannot tell it has seen them all.
+		for who in 1..=5 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, who * 100));
+		}
+		assert_noop!(
+			Assets::spin_off(Origin::signed(2), 0, 1, Permill::from_percent(10), 1),
+			Error::<Test>::NoPermission,
+		);
+
+		assert_ok!(Assets::spin_off(Origin::signed(1), 0, 1, Permill::from_percent(10), 1));
+		assert_eq!(Assets::feature(1), Some(Assets::new_feature_detail(1)));
+		for who in 1..=5 {
+			assert_eq!(Assets::balance(0, who), who * 100);
+			assert_eq!(Assets::balance(1, who), who * 10);
+		}
+		assert!(SpinOffs::<Test>::contains_key(1));
+
+		assert_ok!(Assets::spin_off_continue(Origin::signed(9), 1));
+		assert_eq!(last_event(), AssetsEvent::SpunOff(0, 1));
+		assert_eq!(Assets::total_supply(1), 150);
+		assert_noop!(Assets::spin_off_continue(Origin::signed(9), 1), Error::<Test>::UnknownSpinOff);
+	});
+}
+
+#[test]
+fn spin_off_should_continue_across_calls() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		for who in 1..=7 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, 100));
+		}
+		// Account 8 ends up with a share of zero and is skipped.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 8, 1));
+
+		assert_ok!(Assets::spin_off(Origin::signed(1), 0, 1, Permill::from_percent(50), 1));
+		assert!(Asset::<Test>::get(1).unwrap().accounts < 7);
+		assert!(SpinOffs::<Test>::contains_key(1));
+		assert_ok!(Assets::spin_off_continue(Origin::signed(1), 1));
+		assert_eq!(last_event(), AssetsEvent::SpunOff(0, 1));
+		for who in 1..=7 {
+			assert_eq!(Assets::balance(1, who), 50);
+		}
+		assert!(!Account::<Test>::contains_key(1, 8));
+		assert_eq!(Assets::total_supply(0), 701);
+		assert_eq!(Assets::total_supply(1), 350);
+	});
+}
+
+#[test]
+fn transfer_cooldown_should_space_out_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_transfer_cooldown(Origin::signed(2), 0, Some(5)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+
+		assert_ok!(Assets::set_transfer_cooldown(Origin::signed(1), 0, Some(5)));
+		assert_eq!(last_event(), AssetsEvent::TransferCooldownSet(0, Some(5)));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_eq!(LastTransfer::<Test>::get(0, 1), Some(1));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::TransferCooldown);
+		// Other accounts and forced transfers are not held back.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 10));
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 3, 10));
+
+		System::set_block_number(5);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::TransferCooldown);
+		System::set_block_number(6);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_eq!(LastTransfer::<Test>::get(0, 1), Some(6));
+
+		assert_ok!(Assets::set_transfer_cooldown(Origin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+	});
+}
+
+#[test]
+fn asset_overview_should_bundle_details_feature_and_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::asset_overview(0), None);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 0x1234_5678, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![1u8; 5], 12));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		let overview = Assets::asset_overview(0).unwrap();
+		let details = Asset::<Test>::get(0).unwrap();
+		let metadata = Metadata::<Test>::get(0);
+		assert_eq!(overview.owner, details.owner);
+		assert_eq!(overview.supply, Assets::total_supply(0));
+		assert_eq!(overview.min_balance, details.min_balance);
+		assert_eq!(overview.accounts, details.accounts);
+		assert_eq!(overview.zombies, details.zombies);
+		assert_eq!(overview.max_zombies, details.max_zombies);
+		assert_eq!(overview.is_frozen, details.is_frozen);
+		assert_eq!(overview.existence_model, details.existence_model);
+		assert_eq!(overview.feature, Assets::feature(0));
+		assert_eq!(overview.name, metadata.name);
+		assert_eq!(overview.symbol, metadata.symbol);
+		assert_eq!(overview.decimals, metadata.decimals);
+	});
+}
+
+#[test]
+fn eth_addresses_should_round_trip() {
+	new_test_ext().execute_with(|| {
+		let id = 0x0102_0304;
+		let address = Assets::eth_address_of(id);
+		assert_eq!(address, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
+		assert_eq!(Assets::asset_of_eth_address(address), None);
+
+		assert_ok!(Assets::force_create(Origin::root(), id, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::asset_of_eth_address(address), Some(id));
+		assert_ok!(Assets::force_create(Origin::root(), u32::MAX, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::asset_of_eth_address(Assets::eth_address_of(u32::MAX)), Some(u32::MAX));
+
+		let mut address = address;
+		address[0] = 1;
+		assert_eq!(Assets::asset_of_eth_address(address), None);
+	});
+}
+
+#[test]
+fn community_mint_should_be_rate_limited() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_noop!(Assets::community_mint(Origin::signed(2), 0), Error::<Test>::NoCommunityMint);
+		let community_mint = CommunityMint { per_period: 10, period_blocks: 5 };
+		assert_noop!(
+			Assets::set_community_mint(Origin::signed(2), 0, Some(community_mint.clone())),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::set_community_mint(Origin::signed(1), 0, Some(community_mint)));
+
+		assert_ok!(Assets::community_mint(Origin::signed(2), 0));
+		assert_eq!(last_event(), AssetsEvent::Issued(0, 2, 10));
+		assert_noop!(Assets::community_mint(Origin::signed(2), 0), Error::<Test>::CommunityMintTooSoon);
+		assert_ok!(Assets::community_mint(Origin::signed(3), 0));
+
+		System::set_block_number(5);
+		assert_noop!(Assets::community_mint(Origin::signed(2), 0), Error::<Test>::CommunityMintTooSoon);
+		System::set_block_number(6);
+		assert_ok!(Assets::community_mint(Origin::signed(2), 0));
+		assert_eq!(Assets::balance(0, 2), 20);
+		assert_eq!(Assets::total_supply(0), 30);
+
+		assert_ok!(Assets::set_community_mint(Origin::signed(1), 0, None));
+		System::set_block_number(20);
+		assert_noop!(Assets::community_mint(Origin::signed(2), 0), Error::<Test>::NoCommunityMint);
+
+		// The supply still may not overflow.
+		let community_mint = CommunityMint { per_period: u64::MAX - 30, period_blocks: 5 };
+		assert_ok!(Assets::set_community_mint(Origin::signed(1), 0, Some(community_mint)));
+		assert_ok!(Assets::community_mint(Origin::signed(4), 0));
+		assert_noop!(Assets::community_mint(Origin::signed(5), 0), Error::<Test>::Overflow);
+	});
+}
+
+#[test]
+fn snapshot_should_copy_balances_in_pages() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		for who in 1..=7 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, who * 10));
+		}
+		assert_noop!(Assets::take_snapshot(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Assets::take_snapshot(Origin::root(), 1), Error::<Test>::Unknown);
+
+		System::set_block_number(3);
+		assert_ok!(Assets::take_snapshot(Origin::signed(1), 0));
+		assert_eq!(NextSnapshotId::<Test>::get(), 1);
+		let snapshot = Snapshots::<Test>::get(0).unwrap();
+		assert_eq!((snapshot.id, snapshot.block, snapshot.holders, snapshot.complete), (0, 3, 5, false));
+
+		assert_ok!(Assets::take_snapshot_continue(Origin::signed(9), 0));
+		assert_eq!(last_event(), AssetsEvent::SnapshotCompleted(0));
+		assert_noop!(Assets::take_snapshot_continue(Origin::signed(9), 0), Error::<Test>::SnapshotComplete);
+		assert_noop!(Assets::take_snapshot_continue(Origin::signed(9), 1), Error::<Test>::UnknownSnapshot);
+
+		// Later transfers do not change the snapshot.
+		assert_ok!(Assets::transfer(Origin::signed(7), 0, 8, 70));
+		for who in 1..=7 {
+			assert_eq!(Assets::snapshot_balance(0, who), who * 10);
+		}
+		assert_eq!(Assets::snapshot_balance(0, 8), 0);
+	});
+}
+
+#[test]
+fn discard_snapshot_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::take_snapshot(Origin::root(), 0));
+		assert_eq!(last_event(), AssetsEvent::SnapshotCompleted(0));
+
+		assert_noop!(Assets::discard_snapshot(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::discard_snapshot(Origin::signed(1), 0, 1), Error::<Test>::BadWitness);
+		assert_ok!(Assets::discard_snapshot(Origin::signed(1), 0, 2));
+		assert!(!Snapshots::<Test>::contains_key(0));
+		assert_eq!(Assets::snapshot_balance(0, 1), 0);
+		assert_noop!(Assets::discard_snapshot(Origin::root(), 0, 2), Error::<Test>::UnknownSnapshot);
+	});
+}
+
+#[test]
+fn burn_should_report_supply_underflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		// Corrupt the accounting so that the account holds more than the supply.
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().supply = 30);
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 50));
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::SupplyUnderflow(0, 30, 50))
+		));
+		assert_eq!(last_event(), AssetsEvent::Burned(0, 1, 50));
+		assert_eq!(Assets::total_supply(0), 0);
+
+		assert_ok!(Assets::verify_asset(Origin::signed(1), 0, 10));
+		assert_eq!(last_event(), AssetsEvent::InvariantCheck(0, false, true, true));
+	});
+}
+
+#[test]
+fn decimals_should_lock_once_set() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		// Zero decimals do not lock them yet.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 0));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 12));
+		assert!(Asset::<Test>::get(0).unwrap().decimals_locked);
+
+		// The name and symbol may still change.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 6], vec![1u8; 3], 12));
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 6], vec![1u8; 3], 18),
+			Error::<Test>::DecimalsLocked,
+		);
+		assert_eq!(Metadata::<Test>::get(0).decimals, 12);
+
+		// The metadata may still be removed, which leaves the decimals locked at zero.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![], vec![], 0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 12),
+			Error::<Test>::DecimalsLocked,
+		);
+	});
+}
+
+#[test]
+fn lock_decimals_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_noop!(Assets::lock_decimals(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::lock_decimals(Origin::signed(1), 0));
+		assert_eq!(last_event(), AssetsEvent::DecimalsLocked(0));
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 6),
+			Error::<Test>::DecimalsLocked,
+		);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 0));
+	});
+}
+
+#[test]
+fn temp_issuer_should_mint_within_window_and_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 3, 10), Error::<Test>::NoPermission);
+		assert_noop!(Assets::grant_temp_issuer(Origin::signed(2), 0, 2, 10, 100), Error::<Test>::NoPermission);
+		assert_ok!(Assets::grant_temp_issuer(Origin::signed(1), 0, 2, 10, 100));
+		assert_eq!(last_event(), AssetsEvent::TempIssuerGranted(0, 2, 10, 100));
+
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 3, 60));
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 3, 41), Error::<Test>::TempIssuerCapExceeded);
+		System::set_block_number(10);
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 4, 40));
+		assert_eq!(TempIssuers::<Test>::get(0, 2).unwrap().minted, 100);
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 4, 1), Error::<Test>::TempIssuerCapExceeded);
+
+		assert_ok!(Assets::grant_temp_issuer(Origin::signed(1), 0, 2, 20, 100));
+		System::set_block_number(21);
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 3, 10), Error::<Test>::NoPermission);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// The regular issuer is unaffected.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 10));
+	});
+}
+
+#[test]
+fn revoke_temp_issuer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::grant_temp_issuer(Origin::signed(1), 0, 2, 10, 100));
+		assert_noop!(Assets::revoke_temp_issuer(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_ok!(Assets::revoke_temp_issuer(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::TempIssuerRevoked(0, 2));
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 3, 10), Error::<Test>::NoPermission);
+		assert_noop!(Assets::revoke_temp_issuer(Origin::signed(1), 0, 2), Error::<Test>::NotTempIssuer);
+	});
+}
+
+#[test]
+fn hold_and_release_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::hold(Origin::signed(1), 0, 1, 10, 1), BadOrigin);
+		assert_noop!(Assets::hold(Origin::root(), 0, 2, 10, 1), Error::<Test>::BalanceZero);
+		assert_noop!(Assets::hold(Origin::root(), 0, 1, 101, 1), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::hold(Origin::root(), 0, 1, 30, 1));
+		assert_eq!(last_event(), AssetsEvent::Held(0, 1, 30, 1));
+		assert_ok!(Assets::hold(Origin::root(), 0, 1, 20, 2));
+		assert_eq!(Assets::transferable_now(0, 1), 50);
+		assert_noop!(Assets::hold(Origin::root(), 0, 1, 51, 3), Error::<Test>::BalanceLow);
+
+		assert_noop!(Assets::release_hold(Origin::root(), 0, 1, 31, 1), Error::<Test>::InsufficientHold);
+		assert_noop!(Assets::release_hold(Origin::root(), 0, 1, 1, 3), Error::<Test>::InsufficientHold);
+		assert_ok!(Assets::release_hold(Origin::root(), 0, 1, 30, 1));
+		assert_eq!(last_event(), AssetsEvent::HoldReleased(0, 1, 30, 1));
+		assert!(!HoldReasons::<Test>::contains_key(0, (1, 1)));
+		assert_eq!(HoldReasons::<Test>::get(0, (1, 2)), 20);
+		assert_eq!(Assets::transferable_now(0, 1), 80);
+	});
+}
+
+#[test]
+fn held_funds_should_not_move_or_burn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::hold(Origin::root(), 0, 1, 2, 1));
+
+		// The account must survive, so at least the minimum balance stays behind.
+		assert_eq!(Assets::transferable_now(0, 1), 95);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 96), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 95));
+		assert_eq!(Assets::balance(0, 1), 5);
+
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 45));
+		assert_ok!(Assets::hold(Origin::root(), 0, 1, 20, 2));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::MAX));
+		assert_eq!(Assets::balance(0, 1), 22);
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 1, 2, 10), Error::<Test>::AmountZero);
+	});
+}
+
+#[test]
+fn transfer_on_hold_should_settle() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::hold(Origin::root(), 0, 1, 40, 7));
+		assert_noop!(Assets::transfer_on_hold(Origin::signed(1), 0, 1, 2, 40, 7), BadOrigin);
+		assert_noop!(Assets::transfer_on_hold(Origin::root(), 0, 1, 2, 40, 6), Error::<Test>::InsufficientHold);
+
+		assert_ok!(Assets::transfer_on_hold(Origin::root(), 0, 1, 2, 30, 7));
+		assert_eq!(last_event(), AssetsEvent::HoldTransferred(0, 1, 2, 30, 7));
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_eq!(HoldReasons::<Test>::get(0, (1, 7)), 10);
+		assert_eq!(Assets::transferable_now(0, 1), 60);
+	});
+}
+
+#[test]
+fn resumable_batch_should_complete_across_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		// Each call has room for three mints.
+		let beneficiaries = vec![(10, 10), (11, 10), (12, 1), (13, 10), (14, 10), (15, 10), (16, 10)];
+		assert_noop!(Assets::start_mint_batch(Origin::signed(2), 0, beneficiaries.clone()), Error::<Test>::NoPermission);
+		assert_ok!(Assets::start_mint_batch(Origin::signed(1), 0, beneficiaries));
+		assert_eq!(last_event(), AssetsEvent::BatchProgress(0, 3, 7));
+		assert_eq!(Assets::total_supply(0), 20);
+
+		assert_ok!(Assets::continue_batch(Origin::signed(3), 0));
+		assert_eq!(last_event(), AssetsEvent::BatchProgress(0, 6, 7));
+		assert_ok!(Assets::continue_batch(Origin::signed(3), 0));
+		assert_eq!(last_event(), AssetsEvent::BatchCompleted(0, 6, 1));
+		assert!(!PendingBatches::<Test>::contains_key(0));
+		assert_eq!(Assets::total_supply(0), 60);
+		assert_eq!(Assets::balance(0, 16), 10);
+		assert_noop!(Assets::continue_batch(Origin::signed(3), 0), Error::<Test>::UnknownBatch);
+	});
+}
+
+#[test]
+fn resumable_batch_should_report_actual_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		let info = Assets::start_mint_batch(Origin::signed(1), 0, vec![(10, 10)]).unwrap();
+		let start = <() as EstimatedWeightInfo>::start_mint_batch(1);
+		assert_eq!(info.actual_weight, Some(start + <() as WeightInfo>::mint()));
+		assert_eq!(last_event(), AssetsEvent::BatchCompleted(0, 1, 0));
+	});
+}
+
+#[test]
+fn transfer_should_refund_weight_by_mode() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+
+		let weight = |info: frame_support::weights::PostDispatchInfo| info.actual_weight.unwrap();
+		let create = weight(Assets::transfer(Origin::signed(1), 0, 2, 50).unwrap());
+		assert_eq!(create, <() as EstimatedWeightInfo>::transfer_create());
+		let keep = weight(Assets::transfer(Origin::signed(1), 0, 2, 20).unwrap());
+		assert_eq!(keep, <() as EstimatedWeightInfo>::transfer_existing());
+		// Leaving 5 behind sweeps the sender.
+		let sweep = weight(Assets::transfer(Origin::signed(1), 0, 2, 25).unwrap());
+		assert_eq!(sweep, <() as EstimatedWeightInfo>::transfer_sweep());
+		assert!(!Account::<Test>::contains_key(0, 1));
+		let both = weight(Assets::transfer(Origin::signed(3), 0, 4, 95).unwrap());
+		assert_eq!(both, <() as WeightInfo>::transfer());
+
+		assert!(keep < sweep);
+		assert!(sweep <= <() as WeightInfo>::transfer());
+	});
+}
+
+#[test]
+fn asset_rewarder_should_mint_without_issuer() {
+	new_test_ext().execute_with(|| {
+		type Rewarder = Assets;
+		assert_noop!(<Rewarder as AssetRewarder<_, _, _>>::reward(0, 2, 50), Error::<Test>::Unknown);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1, 10, ExistenceModel::Zombie));
+
+		assert_ok!(<Rewarder as AssetRewarder<_, _, _>>::reward(0, 2, 50));
+		assert_eq!(last_event(), AssetsEvent::Issued(0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::total_supply(0), 50);
+
+		assert_noop!(<Rewarder as AssetRewarder<_, _, _>>::reward(0, 3, 5), Error::<Test>::BalanceLow);
+		// The only zombie slot is taken by account 2.
+		assert_noop!(<Rewarder as AssetRewarder<_, _, _>>::reward(0, 3, 50), Error::<Test>::TooManyZombies);
+		assert_eq!(Assets::total_supply(0), 50);
+	});
+}
+
+#[test]
+fn account_flags_should_behave_like_bools() {
+	let mut account = AssetBalance::<u64, u64, ()>::default();
+	assert!(!account.is_frozen());
+	assert!(!account.is_zombie());
+
+	account.set_zombie(true);
+	account.set_frozen(true);
+	assert!(account.is_frozen());
+	assert!(account.is_zombie());
+	account.set_frozen(true);
+	assert!(account.is_frozen());
+
+	account.set_frozen(false);
+	assert!(!account.is_frozen());
+	assert!(account.is_zombie());
+	account.set_zombie(false);
+	assert!(!account.is_zombie());
+	assert_eq!(account, AssetBalance::<u64, u64, ()>::default());
+
+	// balance, flags, on_hold
+	assert_eq!(AssetBalance { balance: 7u64, flags: 3, on_hold: 0, freeze_reason: 0, created_at: 0u64, extra: () }.encode(), (7u64, 3u8, 0u64, 0u8, 0u64).encode());
+}
+
+#[test]
+fn account_flags_should_follow_freezes_and_zombies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert!(Account::<Test>::get(0, 2).is_zombie());
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
+		assert!(Account::<Test>::get(0, 2).is_frozen());
+		assert!(Account::<Test>::get(0, 2).is_zombie());
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 2));
+		assert!(!Account::<Test>::get(0, 2).is_frozen());
+	});
+}
+
+#[test]
+fn transfer_of_unknown_asset_should_fail_first() {
+	new_test_ext().execute_with(|| {
+		assert!(!Assets::asset_exists(0));
+		// A stray account of an asset which does not exist must be left alone.
+		Account::<Test>::insert(0, 1, AssetBalance { balance: 100, ..Default::default() });
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Unknown);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 500), Error::<Test>::Unknown);
+		assert_eq!(Assets::balance(0, 1), 100);
+
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert!(Assets::asset_exists(0));
+	});
+}
+
+#[test]
+fn burn_should_respect_burnable() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::set_burnable(Origin::signed(2), 0, false), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		assert_eq!(last_event(), AssetsEvent::BurnableSet(0, false));
+
+		assert_noop!(Assets::burn(Origin::signed(1), 0, 2, 10), Error::<Test>::BurnDisabled);
+		assert_noop!(Assets::burn_batch(Origin::signed(1), 0, vec![(2, 10)]), Error::<Test>::BurnDisabled);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, true));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::total_supply(0), 90);
+	});
+}
+
+#[test]
+fn unburnable_asset_can_still_be_destroyed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(10, 0)));
+		assert!(!Assets::asset_exists(0));
+	});
+}
+
+#[test]
+fn transfer_min_received_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::transfer_min_received(Origin::signed(1), 0, 2, 50, 51),
+			Error::<Test>::SlippageExceeded
+		);
+		assert_ok!(Assets::transfer_min_received(Origin::signed(1), 0, 2, 50, 50));
+		assert_eq!(Assets::balance(0, 2), 50);
+
+		// Leaving 5 behind sweeps it to the target as well.
+		assert_noop!(
+			Assets::transfer_min_received(Origin::signed(1), 0, 2, 45, 51),
+			Error::<Test>::SlippageExceeded
+		);
+		assert_ok!(Assets::transfer_min_received(Origin::signed(1), 0, 2, 45, 50));
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
+#[test]
+fn convert_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::convert(Origin::signed(2), 0, 10), Error::<Test>::NoConversion);
+		assert_noop!(Assets::set_conversion(Origin::signed(1), 0, 1, Permill::from_percent(50)), BadOrigin);
+		assert_noop!(Assets::set_conversion(Origin::root(), 0, 2, Permill::from_percent(50)), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::set_conversion(Origin::root(), 0, 1, Permill::from_percent(50)));
+		assert_eq!(last_event(), AssetsEvent::ConversionSet(0, 1, Permill::from_percent(50)));
+		assert_ok!(Assets::convert(Origin::signed(2), 0, 30));
+		assert_eq!(last_event(), AssetsEvent::Converted(2, 0, 1, 30, 15));
+		assert_eq!(Assets::balance(0, 2), 70);
+		assert_eq!(Assets::balance(1, 2), 15);
+
+		// The dust left behind is converted as well.
+		assert_ok!(Assets::convert(Origin::signed(2), 0, 65));
+		assert_eq!(last_event(), AssetsEvent::Converted(2, 0, 1, 70, 35));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(Assets::total_supply(1), 50);
+	});
+}
+
+#[test]
+fn convert_should_respect_freezes_and_supply() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::set_conversion(Origin::root(), 0, 1, Permill::one()));
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
+		assert_noop!(Assets::convert(Origin::signed(2), 0, 10), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 2));
+
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		assert_noop!(Assets::convert(Origin::signed(2), 0, 10), Error::<Test>::BurnDisabled);
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, true));
+
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 3, u64::MAX - 5));
+		assert_noop!(Assets::convert(Origin::signed(2), 0, 10), Error::<Test>::Overflow);
+		assert_eq!(Assets::balance(0, 2), 100);
+	});
+}
+
+#[test]
+fn force_transfer_from_account_without_balance_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 2, 3, 10), Error::<Test>::BalanceZero);
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 1, 1, 3, 10), Error::<Test>::BalanceZero);
+	});
+}
+
+#[test]
+fn destination_whitelist_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_destination_whitelist(Origin::signed(2), 0, true), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, true));
+		assert_eq!(last_event(), AssetsEvent::DestinationWhitelistSet(0, true));
+
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::NotAllowed);
+		assert_ok!(Assets::add_destination(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::DestinationAdded(0, 2));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20));
+		assert_noop!(Assets::transfer_approved(Origin::signed(3), 0, 1, 3, 10), Error::<Test>::NotAllowed);
+		assert_ok!(Assets::transfer_approved(Origin::signed(3), 0, 1, 2, 10));
+
+		// Forced transfers are not restricted.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 3, 10));
+
+		assert_ok!(Assets::remove_destination(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::DestinationRemoved(0, 2));
+		assert_noop!(Assets::remove_destination(Origin::signed(1), 0, 2), Error::<Test>::NotAllowed);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::NotAllowed);
+
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, false));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 30);
+	});
+}
+
+#[test]
+fn deposit_queries_should_match_reserves() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_eq!(Assets::create_deposit(10), 11);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(1), Assets::create_deposit(10));
+
+		assert_eq!(Assets::metadata_deposit(3, 2), 6);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0; 3], vec![0; 2], 0));
+		assert_eq!(Balances::reserved_balance(1), Assets::create_deposit(10) + Assets::metadata_deposit(3, 2));
+	});
+}
+
+#[test]
+fn expiry_should_stop_mints_and_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_expiry(Origin::signed(2), 0, Some(10)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, Some(10)));
+		assert_eq!(last_event(), AssetsEvent::ExpirySet(0, Some(10)));
+
+		System::set_block_number(9);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+
+		System::set_block_number(10);
+		Assets::on_initialize(10);
+		assert_eq!(last_event(), AssetsEvent::AssetExpired(0));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::AssetExpired);
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 2, 10), Error::<Test>::AssetExpired);
+
+		// The asset can still be wound down.
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 20));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(1, 0)));
+	});
+}
+
+#[test]
+fn moving_the_expiry_should_reschedule_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, Some(10)));
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, Some(20)));
+		assert!(Expiries::<Test>::get(10).is_empty());
+		assert_eq!(Expiries::<Test>::get(20), vec![0]);
+
+		System::set_block_number(20);
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, None));
+		Assets::on_initialize(20);
+		assert_eq!(last_event(), AssetsEvent::ExpirySet(0, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+
+		assert_ok!(Assets::set_expiry(Origin::signed(1), 0, Some(15)));
+		assert_eq!(last_event(), AssetsEvent::AssetExpired(0));
+	});
+}
+
+#[test]
+fn deposits_should_be_held_in_deposit_asset() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		// The deposit asset itself takes its deposit in the native currency.
+		assert_ok!(Assets::create(Origin::signed(1), 0, 0, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		DepositAsset::set(Some(0));
+
+		assert_ok!(Assets::create(Origin::signed(1), 1, 10, 1, 10, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(HoldReasons::<Test>::get(0, (1, DEPOSIT_HOLD_REASON)), 11);
+		assert_eq!(Assets::transferable_now(0, 1), 89);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 1, vec![0; 3], vec![0; 2], 0));
+		assert_eq!(HoldReasons::<Test>::get(0, (1, DEPOSIT_HOLD_REASON)), 17);
+
+		// Nobody else may release a deposit.
+		assert_noop!(
+			Assets::release_hold(Origin::root(), 0, 1, 17, DEPOSIT_HOLD_REASON),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::create(Origin::signed(2), 2, 10, 1, 10, ExistenceModel::Zombie),
+			Error::<Test>::BalanceZero
+		);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 1, witness(0, 0)));
+		assert!(!HoldReasons::<Test>::contains_key(0, (1, DEPOSIT_HOLD_REASON)));
+		assert_eq!(Account::<Test>::get(0, 1).on_hold, 0);
+		assert_eq!(Assets::balance(0, 1), 100);
+	});
+}
+
+#[test]
+fn deposit_asset_should_follow_ownership_and_pay_fees() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		DepositAsset::set(Some(0));
+		DestroyRefundRatio::set(Permill::from_percent(50));
+
+		assert_ok!(Assets::create(Origin::signed(1), 1, 9, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 1, 2));
+		assert_eq!(Assets::balance(0, 1), 90);
+		assert_eq!(Assets::balance(0, 2), 110);
+		assert_eq!(HoldReasons::<Test>::get(0, (2, DEPOSIT_HOLD_REASON)), 10);
+		assert!(!HoldReasons::<Test>::contains_key(0, (1, DEPOSIT_HOLD_REASON)));
+
+		assert_ok!(Assets::destroy(Origin::signed(2), 1, witness(0, 0)));
+		assert_eq!(last_event(), AssetsEvent::Destroyed(1));
+		assert_eq!(Assets::balance(0, 2), 105);
+		assert_eq!(Account::<Test>::get(0, 2).on_hold, 0);
+		assert_eq!(Assets::total_supply(0), 195);
+	});
+}
+
+#[test]
+fn approvals_for_delegate_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 2, 100));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 5, 20));
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 1, 5, 30));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 6, 40));
+
+		let mut approvals = Assets::approvals_for_delegate(5, None, 10);
+		approvals.sort();
+		assert_eq!(approvals, vec![(0, 1, 20), (1, 2, 30)]);
+		assert_eq!(Assets::approvals_for_delegate(6, None, 10), vec![(0, 1, 40)]);
+
+		let first = Assets::approvals_for_delegate(5, None, 1);
+		let rest = Assets::approvals_for_delegate(5, Some((first[0].0, first[0].1)), 10);
+		assert_eq!(first.len() + rest.len(), 2);
+		assert_ne!(first, rest);
+
+		assert_ok!(Assets::transfer_approved(Origin::signed(5), 0, 1, 3, 20));
+		assert_eq!(Assets::approvals_for_delegate(5, None, 10), vec![(1, 2, 30)]);
+		assert_ok!(Assets::cancel_approval(Origin::signed(2), 1, 5));
+		assert!(Assets::approvals_for_delegate(5, None, 10).is_empty());
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::MAX));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(1, 1)));
+		assert!(Assets::approvals_for_delegate(6, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn transfer_percent_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 101));
+		assert_noop!(Assets::transfer_percent(Origin::signed(2), 0, 3, Permill::one(), false), Error::<Test>::AmountZero);
+
+		// Half of 101 is rounded down.
+		assert_ok!(Assets::transfer_percent(Origin::signed(1), 0, 2, Permill::from_percent(50), true));
+		assert_eq!(Assets::balance(0, 1), 51);
+		assert_eq!(Assets::balance(0, 2), 50);
+
+		assert_ok!(Assets::transfer_percent(Origin::signed(1), 0, 2, Permill::one(), true));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_noop!(Assets::transfer_percent(Origin::signed(1), 0, 2, Permill::one(), true), Error::<Test>::AmountZero);
+
+		assert_ok!(Assets::transfer_percent(Origin::signed(1), 0, 2, Permill::one(), false));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert_eq!(Assets::balance(0, 2), 101);
+	});
+}
+
+#[test]
+fn force_set_metadata_should_take_no_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0; 10], vec![0; 10], 0));
+		assert_eq!(Balances::reserved_balance(1), 11 + 21);
+
+		assert_noop!(Assets::force_set_metadata(Origin::signed(1), 0, vec![0; 10], vec![0; 10], 0), BadOrigin);
+		assert_noop!(Assets::force_set_metadata(Origin::root(), 1, vec![0; 10], vec![0; 10], 0), Error::<Test>::Unknown);
+		assert_noop!(Assets::force_set_metadata(Origin::root(), 0, vec![0; 51], vec![0; 10], 0), Error::<Test>::BadMetadata);
+
+		// The deposit paid for the earlier metadata is returned.
+		assert_ok!(Assets::force_set_metadata(Origin::root(), 0, vec![0; 5], vec![0; 5], 12));
+		assert_eq!(last_event(), AssetsEvent::MetadataSet(0, vec![0; 5], vec![0; 5], 12));
+		assert_eq!(Balances::reserved_balance(1), 11);
+		assert_eq!(Metadata::<Test>::get(0).deposit, 0);
+		assert!(Asset::<Test>::get(0).unwrap().decimals_locked);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(0, 0)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn freeze_reason_should_be_stored_emitted_and_cleared() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::freeze_reason(0, 1), None);
+		assert_eq!(Assets::asset_freeze_reason(0), None);
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, Some(7)));
+		assert_eq!(last_event(), AssetsEvent::Frozen(0, 1, 7));
+		assert_eq!(Assets::freeze_reason(0, 1), Some(7));
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
+		assert_eq!(last_event(), AssetsEvent::Frozen(0, 2, 0));
+		assert_eq!(Assets::freeze_reason(0, 2), Some(0));
+
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, Some(3)));
+		assert_eq!(last_event(), AssetsEvent::AssetFrozen(0, 3));
+		assert_eq!(Assets::asset_freeze_reason(0), Some(3));
+
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_eq!(Assets::freeze_reason(0, 1), None);
+		assert_eq!(Account::<Test>::get(0, 1).freeze_reason, 0);
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+		assert_eq!(Assets::asset_freeze_reason(0), None);
+		assert_eq!(Asset::<Test>::get(0).unwrap().freeze_reason, 0);
+	});
+}
+
+#[test]
+fn test_helpers_should_corrupt_detectably() {
+	use crate::test_helpers::*;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		let consistent = || {
+			let details = Asset::<Test>::get(0).unwrap();
+			let accounts: Vec<_> = Account::<Test>::iter_prefix(0).collect();
+			let total: u64 = accounts.iter().map(|(_, a)| a.balance).sum();
+			let zombies = accounts.iter().filter(|(_, a)| a.is_zombie()).count() as u32;
+			total == details.supply && accounts.len() as u32 == details.accounts && zombies == details.zombies
+		};
+		assert!(consistent());
+
+		set_balance_unchecked::<Test>(0, &1, 101);
+		assert_eq!(Assets::balance(0, 1), 101);
+		assert!(!consistent());
+		set_supply_unchecked::<Test>(0, 151);
+		assert_eq!(Assets::total_supply(0), 151);
+		assert!(consistent());
+
+		set_counts_unchecked::<Test>(0, 3, 0);
+		assert!(!consistent());
+		set_counts_unchecked::<Test>(0, 2, 2);
+		assert!(consistent());
+	});
+}
+
+#[test]
+fn feature_histogram_should_track_created_and_destroyed_assets() {
+	use mc_support::primitives::FeatureDestinyRank::*;
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_eq!(Assets::feature_histogram(), vec![(Huang, 0), (Xuan, 0), (Di, 0), (Tian, 0)]);
+
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 0x1000_0001, ExistenceModel::Zombie));
+		assert_ok!(Assets::create(Origin::signed(1), 1, 10, 1, 0x3000_0001, ExistenceModel::Zombie));
+		// The test randomness always draws zero, which the roller maps to Tian.
+		assert_ok!(Assets::force_create(Origin::root(), 2, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::feature_histogram(), vec![(Huang, 0), (Xuan, 1), (Di, 0), (Tian, 2)]);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 1, witness(0, 0)));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(0, 0)));
+		assert_eq!(Assets::feature_histogram(), vec![(Huang, 0), (Xuan, 0), (Di, 0), (Tian, 1)]);
+	});
+}
+
+#[test]
+fn redeem_should_pay_out_of_the_reserve() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::redeem(Origin::signed(2), 0, 10), Error::<Test>::NotRedeemable);
+
+		assert_noop!(Assets::set_redemption_rate(Origin::signed(2), 0, Some(2)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_redemption_rate(Origin::signed(1), 0, Some(2)));
+		assert_eq!(last_event(), AssetsEvent::RedemptionRateSet(0, Some(2)));
+		assert_noop!(Assets::fund_redemption(Origin::signed(2), 0, 50), Error::<Test>::NoPermission);
+		assert_ok!(Assets::fund_redemption(Origin::signed(1), 0, 50));
+		assert_eq!(last_event(), AssetsEvent::RedemptionFunded(0, 50));
+		assert_eq!(Balances::reserved_balance(1), 50);
+
+		assert_ok!(Assets::redeem(Origin::signed(2), 0, 10));
+		assert_eq!(last_event(), AssetsEvent::Redeemed(0, 2, 10, 20));
+		assert_eq!(Assets::balance(0, 2), 90);
+		assert_eq!(Assets::total_supply(0), 90);
+		assert_eq!(Balances::free_balance(2), 20);
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_eq!(RedemptionReserve::<Test>::get(0), 30);
+
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		assert_noop!(Assets::redeem(Origin::signed(2), 0, 10), Error::<Test>::BurnDisabled);
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, true));
+
+		// The reserve cannot cover 40, so nothing is burned.
+		assert_noop!(Assets::redeem(Origin::signed(2), 0, 20), Error::<Test>::InsufficientRedemptionReserve);
+		assert_ok!(Assets::redeem(Origin::signed(2), 0, 15));
+		assert_eq!(RedemptionReserve::<Test>::get(0), 0);
+		assert_noop!(Assets::redeem(Origin::signed(2), 0, 1), Error::<Test>::InsufficientRedemptionReserve);
+
+		assert_ok!(Assets::set_redemption_rate(Origin::signed(1), 0, None));
+		assert_noop!(Assets::redeem(Origin::signed(2), 0, 1), Error::<Test>::NotRedeemable);
+	});
+}
+
+#[test]
+fn redemption_reserve_should_follow_the_owner_and_be_returned_on_destroy() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::fund_redemption(Origin::signed(1), 0, 30));
+
+		Balances::make_free_balance_be(&2, 1);
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 30);
+
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(0, 0)));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 31);
+		assert!(!RedemptionReserve::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn supply_heartbeat_should_report_every_asset_over_several_periods() {
+	new_test_ext().execute_with(|| {
+		SupplyReportPeriod::set(5);
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, 10, 1, ExistenceModel::Zombie));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 1, 100 + id as u64));
+		}
+		let report = |n| match last_event() {
+			AssetsEvent::SupplyReport(block, report) => {
+				assert_eq!(block, n);
+				report
+			}
+			e => panic!("unexpected event {:?}", e),
+		};
+
+		// Nothing is reported off schedule.
+		System::set_block_number(4);
+		Assets::on_initialize(4);
+		assert_eq!(last_event(), AssetsEvent::Issued(2, 1, 102));
+
+		let mut seen = Vec::new();
+		for n in [5, 10] {
+			System::set_block_number(n);
+			Assets::on_initialize(n);
+			seen.extend(report(n));
+		}
+		// Two assets in the first report, the last one in the second.
+		assert_eq!(seen.len(), 3);
+		seen.sort();
+		assert_eq!(seen, vec![(0, 100), (1, 101), (2, 102)]);
+
+		// Having reported every asset, the cursor starts over.
+		System::set_block_number(15);
+		Assets::on_initialize(15);
+		assert_eq!(report(15).len(), 2);
+
+		SupplyReportPeriod::set(0);
+		System::set_block_number(20);
+		Assets::on_initialize(20);
+		assert_eq!(report(15).len(), 2);
+	});
+}
+
+#[test]
+fn transfer_fee_should_floor_small_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 2000));
+		let fee = TransferFee { rate: Permill::from_percent(1), min_fee: 2, min_fee_threshold: 10 };
+		assert_noop!(Assets::set_transfer_fee(Origin::signed(2), 0, Some(fee.clone())), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::set_transfer_fee(Origin::signed(1), 0, Some(TransferFee { min_fee: 11, ..fee.clone() })),
+			Error::<Test>::BadTransferFee,
+		);
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee.clone())));
+		assert_eq!(last_event(), AssetsEvent::TransferFeeSet(0, Some(fee)));
+
+		// 1% of 50 rounds down to zero, so the minimum fee is paid instead.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 50));
+		assert_eq!(Assets::balance(0, 2), 1950);
+		assert_eq!(Assets::balance(0, 3), 48);
+		assert_eq!(Assets::balance(0, 1), 1002);
+		assert!(System::events().iter().any(|r| r.event == Event::mc_featured_assets(AssetsEvent::TransferFeeCharged(0, 2, 2))));
+
+		// Below the threshold the fee may still round down to zero.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 5));
+		assert_eq!(Assets::balance(0, 3), 53);
+
+		// Above the minimum, the rate applies.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 500));
+		assert_eq!(Assets::balance(0, 3), 548);
+		assert_eq!(Assets::balance(0, 1), 1007);
+
+		// Splitting 250 into five transfers costs more in fees, not less.
+		assert_eq!(Assets::transfer_fee(0, 250), 2);
+		assert_ok!(Assets::transfer_multi(Origin::signed(2), 0, vec![(4, 50); 5]));
+		assert_eq!(Assets::balance(0, 4), 240);
+		assert_eq!(Assets::balance(0, 1), 1017);
+
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 50));
+		assert_eq!(Assets::balance(0, 3), 598);
+	});
+}
+
+#[test]
+fn transfer_min_received_should_account_for_the_transfer_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 1000));
+		let fee = TransferFee { rate: Permill::from_percent(1), min_fee: 2, min_fee_threshold: 10 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+
+		assert_noop!(Assets::transfer_min_received(Origin::signed(2), 0, 3, 100, 99), Error::<Test>::SlippageExceeded);
+		assert_ok!(Assets::transfer_min_received(Origin::signed(2), 0, 3, 100, 98));
+		assert_eq!(Assets::balance(0, 3), 98);
+	});
+}
+
+#[test]
+fn force_migrate_account_should_move_a_zombie_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, Some(4)));
+		assert_eq!(Assets::zombie_allowance(0), 9);
+
+		assert_noop!(Assets::force_migrate_account(Origin::signed(1), 0, 2, 3), BadOrigin);
+		assert_noop!(Assets::force_migrate_account(Origin::root(), 0, 3, 4), Error::<Test>::BalanceZero);
+		assert_noop!(Assets::force_migrate_account(Origin::root(), 0, 2, 2), Error::<Test>::AccountExists);
+
+		assert_ok!(Assets::force_migrate_account(Origin::root(), 0, 2, 3));
+		assert_eq!(last_event(), AssetsEvent::AccountMigrated(0, 2, 3));
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert!(Account::<Test>::get(0, 3).is_zombie());
+		assert_eq!(Assets::freeze_reason(0, 3), Some(4));
+		assert_eq!(Assets::zombie_allowance(0), 9);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		assert_ok!(Assets::hold(Origin::root(), 0, 3, 10, 0));
+		assert_noop!(Assets::force_migrate_account(Origin::root(), 0, 3, 4), Error::<Test>::FundsOnHold);
+	});
+}
+
+#[test]
+fn force_migrate_account_should_move_system_references() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(System::consumers(&2), 1);
+
+		assert_ok!(Assets::force_migrate_account(Origin::root(), 0, 2, 3));
+		assert_eq!(System::consumers(&2), 0);
+		assert_eq!(System::consumers(&3), 1);
+		assert!(!Account::<Test>::get(0, 3).is_zombie());
+
+		// An account without a system balance becomes a zombie.
+		assert_ok!(Assets::force_migrate_account(Origin::root(), 0, 3, 4));
+		assert_eq!(System::consumers(&3), 0);
+		assert!(Account::<Test>::get(0, 4).is_zombie());
+		assert_eq!(Assets::zombie_allowance(0), 9);
+		assert_eq!(Assets::balance(0, 4), 100);
+	});
+}
+
+#[test]
+fn recount_should_correct_corrupted_counters() {
+	use crate::test_helpers::*;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		Balances::make_free_balance_be(&1, 100);
+		for who in 1..=3 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, 100));
+		}
+		set_counts_unchecked::<Test>(0, 7, 0);
+		set_supply_unchecked::<Test>(0, 1);
+
+		assert_noop!(Assets::recount(Origin::signed(1), 0, 2), BadOrigin);
+		assert_noop!(Assets::recount(Origin::root(), 1, 2), Error::<Test>::Unknown);
+
+		// Nothing is corrected until every account has been counted.
+		assert_ok!(Assets::recount(Origin::root(), 0, 2));
+		assert_eq!(Assets::total_supply(0), 1);
+		assert!(Recounts::<Test>::contains_key(0));
+
+		assert_ok!(Assets::recount(Origin::root(), 0, 2));
+		assert_eq!(last_event(), AssetsEvent::AssetRecounted(0, 3, 2, 300));
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.accounts, details.zombies, details.supply), (3, 2, 300));
+		assert!(!Recounts::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn recount_should_count_escrowed_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::create_stream(Origin::signed(1), 0, 2, 10, 11));
+		assert_eq!(Escrowed::<Test>::get(0), 100);
+
+		// A correct supply is left as it is.
+		assert_ok!(Assets::recount(Origin::root(), 0, 10));
+		assert_eq!(last_event(), AssetsEvent::AssetRecounted(0, 1, 1, 1000));
+		assert_eq!(Assets::total_supply(0), 1000);
+	});
+}
+
+#[test]
+fn default_asset_id_display_should_round_trip() {
+	type Decimal = ();
+	for id in [0u32, 7, 1234, u32::MAX] {
+		let s = <Decimal as AssetIdDisplay<u32>>::to_string(&id);
+		assert_eq!(s, id.to_string().into_bytes());
+		assert_eq!(<Decimal as AssetIdDisplay<u32>>::from_string(&s), Some(id));
+	}
+	assert_eq!(<Decimal as AssetIdDisplay<u32>>::from_string(b""), None);
+	assert_eq!(<Decimal as AssetIdDisplay<u32>>::from_string(b"12a"), None);
+	assert_eq!(<Decimal as AssetIdDisplay<u32>>::from_string(b"4294967296"), None);
+}
+
+#[test]
+fn custom_asset_id_display_should_round_trip() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::format_asset_id(1), b"FA-0001".to_vec());
+		assert_eq!(Assets::format_asset_id(12345), b"FA-12345".to_vec());
+		assert_eq!(Assets::parse_asset_id(b"FA-0001".to_vec()), Some(1));
+		assert_eq!(Assets::parse_asset_id(Assets::format_asset_id(12345)), Some(12345));
+		assert_eq!(Assets::parse_asset_id(b"0001".to_vec()), None);
+	});
+}
+
+#[test]
+fn purge_all_dust_should_spare_young_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		System::set_block_number(5);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+		assert_eq!(Account::<Test>::get(0, 2).created_at, 5);
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().min_balance = 50);
+
+		// Account 2 was created 9 blocks ago, short of the minimum lifetime of 10.
+		System::set_block_number(14);
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::GlobalDustPurged(0, 0));
+		assert_eq!(Assets::balance(0, 2), 10);
+
+		System::set_block_number(15);
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::GlobalDustPurged(1, 1));
+		assert!(!Account::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn purge_all_dust_should_spare_funds_on_hold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::hold(Origin::root(), 0, 2, 4, 1));
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().min_balance = 50);
+
+		System::set_block_number(11);
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::GlobalDustPurged(0, 0));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Account::<Test>::get(0, 2).on_hold, 4);
+		assert_eq!(HoldReasons::<Test>::get(0, (2, 1)), 4);
+	});
+}
+
+#[test]
+fn import_from_legacy_should_install_asset_and_holders() {
+	new_test_ext().execute_with(|| {
+		let legacy = LegacyAssetDetails { owner: 1, supply: 350, deposit: 0, max_zombies: 10, min_balance: 5, is_frozen: false };
+		assert_noop!(Assets::import_from_legacy(Origin::signed(1), 0, legacy.clone(), 0x1000_0001), BadOrigin);
+		assert_noop!(Assets::import_from_legacy(Origin::root(), 0, legacy.clone(), 0), Error::<Test>::BadFeaturePoint);
+		assert_ok!(Assets::import_from_legacy(Origin::root(), 0, legacy.clone(), 0x1000_0001));
+		assert_eq!(last_event(), AssetsEvent::LegacyImported(0, 1));
+		assert_noop!(Assets::import_from_legacy(Origin::root(), 0, legacy, 0x1000_0001), Error::<Test>::InUse);
+		assert_eq!(Assets::feature(0).unwrap().destiny, mc_support::primitives::FeatureDestinyRank::Xuan);
+
+		assert_noop!(Assets::import_accounts(Origin::root(), 0, vec![(2, 100), (3, 300)]), Error::<Test>::ImportExceedsSupply);
+		assert_noop!(Assets::import_accounts(Origin::root(), 0, vec![(2, 4)]), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::import_accounts(Origin::root(), 0, vec![(2, 100), (3, 200)]));
+		assert_eq!(last_event(), AssetsEvent::LegacyAccountsImported(0, 2));
+		assert_noop!(Assets::import_accounts(Origin::root(), 0, vec![(2, 50)]), Error::<Test>::AccountExists);
+
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::import_accounts(Origin::root(), 0, vec![(4, 50)]));
+		assert_eq!(last_event(), AssetsEvent::LegacyImportCompleted(0));
+		assert_noop!(Assets::import_accounts(Origin::root(), 0, vec![(5, 50)]), Error::<Test>::NotImporting);
+
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.supply, details.accounts, details.zombies), (350, 3, 2));
+		let total: u64 = Account::<Test>::iter_prefix(0).map(|(_, a)| a.balance).sum();
+		assert_eq!(total, details.supply);
+		assert_eq!(Account::<Test>::iter_prefix(0).filter(|(_, a)| a.is_zombie()).count(), 2);
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 5, 40));
+	});
+}
+
+#[test]
+fn import_from_legacy_should_respect_deposits_claims_and_premium_ids() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		let legacy = LegacyAssetDetails {
+			owner: 1,
+			supply: 0,
+			deposit: 10,
+			max_zombies: 10,
+			min_balance: 5,
+			is_frozen: false,
+		};
+
+		// The deposit must still be held by the owner.
+		assert_noop!(
+			Assets::import_from_legacy(Origin::root(), 0, legacy.clone(), 0x1000_0001),
+			Error::<Test>::DepositNotReserved,
+		);
+		assert_ok!(Balances::reserve(&1, 10));
+
+		// Neither an id claimed by someone else nor a premium id may be taken over.
+		assert_ok!(Assets::claim_asset_id(Origin::signed(2), 0));
+		assert_noop!(
+			Assets::import_from_legacy(Origin::root(), 0, legacy.clone(), 0x1000_0001),
+			Error::<Test>::IdClaimed,
+		);
+		assert_noop!(
+			Assets::import_from_legacy(Origin::root(), 100, legacy.clone(), 0x1000_0001),
+			Error::<Test>::PremiumId,
+		);
+
+		// Once the claim expired the import goes ahead and releases it.
+		System::set_block_number(11);
+		assert_ok!(Assets::import_from_legacy(Origin::root(), 0, legacy, 0x1000_0001));
+		assert!(!ClaimedAssetIds::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 10);
+	});
+}
+
+#[test]
+fn clawback_should_work_within_the_window_only() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::set_clawback_window(Origin::signed(2), 0, Some(10)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_clawback_window(Origin::signed(1), 0, Some(10)));
+		assert_eq!(last_event(), AssetsEvent::ClawbackWindowSet(0, Some(10)));
+
+		// Mints from before the window was set cannot be clawed back.
+		assert_noop!(Assets::clawback(Origin::signed(1), 0, 2, 50), Error::<Test>::NothingToClawback);
+
+		System::set_block_number(5);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 30));
+		System::set_block_number(8);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 20));
+		assert_noop!(Assets::clawback(Origin::signed(2), 0, 2, 10), Error::<Test>::NoPermission);
+
+		// The latest mint goes first, then part of the one before.
+		assert_ok!(Assets::clawback(Origin::signed(1), 0, 2, 25));
+		assert_eq!(last_event(), AssetsEvent::ClawedBack(0, 2, 25));
+		assert_eq!(Assets::balance(0, 2), 125);
+		assert_eq!(RecentMints::<Test>::get(0, 2), vec![(25, 5)]);
+
+		System::set_block_number(14);
+		assert_ok!(Assets::clawback(Origin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 115);
+
+		// At block 15 the mint of block 5 has left the window.
+		System::set_block_number(15);
+		assert_noop!(Assets::clawback(Origin::signed(1), 0, 2, 10), Error::<Test>::NothingToClawback);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 5));
+		assert_eq!(RecentMints::<Test>::get(0, 2), vec![(5, 15)]);
+	});
+}
+
+#[test]
+fn indivisible_assets_should_have_zero_decimals() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert!(!Assets::is_indivisible(0));
+		assert_noop!(Assets::set_indivisible(Origin::signed(2), 0, true), Error::<Test>::NoPermission);
+
+		assert_ok!(Assets::set_indivisible(Origin::signed(1), 0, true));
+		assert_eq!(last_event(), AssetsEvent::IndivisibleSet(0, true));
+		assert!(Assets::is_indivisible(0));
+		assert!(!Metadata::<Test>::contains_key(0));
+
+		// The decimals given are ignored in favour of zero, and so are not locked.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 12));
+		assert_eq!(last_event(), AssetsEvent::MetadataSet(0, vec![0u8; 4], vec![0u8; 2], 0));
+		assert_eq!(Metadata::<Test>::get(0).decimals, 0);
+		assert!(!Asset::<Test>::get(0).unwrap().decimals_locked);
+		assert_ok!(Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 4], vec![0u8; 2], 6));
+		assert_eq!(Metadata::<Test>::get(0).decimals, 0);
+		assert!(Assets::asset_overview(0).unwrap().indivisible);
+
+		assert_ok!(Assets::set_indivisible(Origin::signed(1), 0, false));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 12));
+		assert_eq!(Metadata::<Test>::get(0).decimals, 12);
+	});
+}
+
+#[test]
+fn set_indivisible_should_reset_unlocked_decimals() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 12));
+		assert_noop!(Assets::set_indivisible(Origin::signed(1), 0, true), Error::<Test>::DecimalsLocked);
+
+		// Metadata written before decimals could be locked is reset to zero decimals.
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().decimals_locked = false);
+		assert_ok!(Assets::set_indivisible(Origin::signed(1), 0, true));
+		assert_eq!(Metadata::<Test>::get(0).decimals, 0);
+		assert_eq!(Metadata::<Test>::get(0).name, vec![0u8; 4]);
+	});
+}
+
+#[test]
+fn set_metadata_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 1, vec![0u8; 4], vec![0u8; 4], 0));
+		assert_eq!(Balances::reserved_balance(1), 9);
+
+		assert_noop!(
+			Assets::set_metadata_batch(Origin::signed(1), vec![(0, vec![0u8; 1], vec![0u8; 1], 0); 6]),
+			Error::<Test>::BatchTooLarge,
+		);
+		assert_ok!(Assets::set_metadata_batch(Origin::signed(1), vec![
+			(0, vec![0u8; 10], vec![0u8; 10], 12),
+			(1, vec![0u8; 2], vec![0u8; 2], 0),
+		]));
+		assert_eq!(Metadata::<Test>::get(0).decimals, 12);
+		assert_eq!(Metadata::<Test>::get(1).name, vec![0u8; 2]);
+		assert_eq!(Balances::reserved_balance(1), 26);
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::MetadataSet(0, vec![0u8; 10], vec![0u8; 10], 12))
+		));
+		assert_eq!(last_event(), AssetsEvent::MetadataSet(1, vec![0u8; 2], vec![0u8; 2], 0));
+	});
+}
+
+#[test]
+fn set_metadata_batch_should_roll_back_on_failure() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 2, 10, 1, ExistenceModel::Zombie));
+
+		assert_noop!(
+			Assets::set_metadata_batch(Origin::signed(1), vec![
+				(0, vec![0u8; 10], vec![0u8; 10], 12),
+				(1, vec![0u8; 10], vec![0u8; 10], 12),
+			]),
+			Error::<Test>::NoPermission,
+		);
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert!(!Asset::<Test>::get(0).unwrap().decimals_locked);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn feature_status_should_reveal_a_missing_feature() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::feature_status(0), None);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::feature_status(0), Some((true, Feature::<Test>::get(0))));
+		assert!(Feature::<Test>::contains_key(0));
+
+		// A featured asset whose feature went missing is told apart from an unfeatured one.
+		Feature::<Test>::remove(0);
+		assert_eq!(Assets::feature_status(0), Some((true, None)));
+	});
+}
+
+#[test]
+fn destroy_should_call_the_hook() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)), Error::<Test>::RefsLeft);
+		assert!(destroyed_assets().is_empty());
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 1, witness(100, 0)));
+		assert_eq!(destroyed_assets(), vec![1]);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::force_destroy(Origin::root(), 0, witness(100, 0)));
+		assert_eq!(destroyed_assets(), vec![1, 0]);
+	});
+}
+
+#[test]
+fn transfers_should_conserve_supply() {
+	// A small xorshift generator, so every run checks the same cases.
+	let mut seed = 0x2545_f491_u64;
+	let mut next = |bound: u64| {
+		seed ^= seed << 13;
+		seed ^= seed >> 7;
+		seed ^= seed << 17;
+		seed % bound
+	};
+	for _ in 0..50 {
+		new_test_ext().execute_with(|| {
+			let min_balance = 1 + next(20);
+			assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, min_balance, ExistenceModel::Zombie));
+			for who in 1..=5 {
+				assert_ok!(Assets::mint(Origin::signed(1), 0, who, min_balance + next(100)));
+			}
+			let supply = Assets::total_supply(0);
+			for _ in 0..20 {
+				let (from, to, amount) = (1 + next(5), 1 + next(6), next(60));
+				// Failed transfers are fine, only a change in supply is not.
+				let _ = Assets::transfer(Origin::signed(from), 0, to, amount);
+				let balances: u64 = Account::<Test>::iter_prefix_values(0).map(|a| a.balance).sum();
+				assert_eq!(Assets::total_supply(0), supply);
+				assert_eq!(balances, supply);
+			}
+		});
+	}
+}
+
+#[test]
+fn transfer_should_not_saturate_the_recipient() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 15));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+		crate::test_helpers::set_balance_unchecked::<Test>(0, &2, u64::MAX - 10);
+
+		// Account 1 would be left with 5, below the minimum, so all 15 would move.
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::Overflow);
+		assert_eq!(Assets::balance(0, 1), 15);
+		assert_eq!(Assets::total_supply(0), 25);
+	});
+}
+
+#[test]
+fn max_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_max_transfer(Origin::signed(2), 0, Some(30)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_max_transfer(Origin::signed(1), 0, Some(30)));
+		assert_eq!(last_event(), AssetsEvent::MaxTransferSet(0, Some(30)));
+
+		// Every transfer may move up to the cap, however many are made.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 31), Error::<Test>::AboveMaxTransfer);
+
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 0, 3, 50));
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(3), 0, 2, 4, 31),
+			Error::<Test>::AboveMaxTransfer,
+		);
+		assert_ok!(Assets::transfer_approved(Origin::signed(3), 0, 2, 4, 30));
+
+		// force_transfer is not capped.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 2, 40));
+		assert_eq!(Assets::balance(0, 2), 70);
+
+		assert_ok!(Assets::set_max_transfer(Origin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 70));
+	});
+}
+
+#[test]
+fn drain_to_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		for who in 1..=4 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, 10 * who));
+		}
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 3, None));
+		assert_noop!(Assets::drain_to_owner(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::drain_to_owner(Origin::signed(1), 1, 2), Error::<Test>::Unknown);
+
+		// Stopping at the boundary of a page leaves the rest for the next call.
+		assert_ok!(Assets::drain_to_owner(Origin::signed(1), 0, 2));
+		assert!(Drains::<Test>::contains_key(0));
+		let left: u64 = (2..=4).map(|who| Assets::balance(0, who)).sum();
+		assert!(left > 0 && left < 90);
+
+		assert_ok!(Assets::drain_to_owner(Origin::signed(1), 0, 3));
+		assert_eq!(last_event(), AssetsEvent::Drained(0, 3, 90, 0));
+		assert!(!Drains::<Test>::contains_key(0));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::total_supply(0), 100);
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.accounts, details.zombies), (1, 0));
+	});
+}
+
+#[test]
+fn drain_to_owner_should_skip_accounts_which_cannot_be_drained() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 20));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, BLOCKED, 30));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 40));
+
+		// The transfer policy vetoes the drain of one holder, which leaves the others unaffected.
+		assert_ok!(Assets::drain_to_owner(Origin::signed(1), 0, 10));
+		assert_eq!(last_event(), AssetsEvent::Drained(0, 2, 60, 1));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::balance(0, BLOCKED), 30);
+		assert!(!Drains::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn scaled_amounts_should_follow_decimals() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::scaled_amount(0, 1_234), (1_234, 0, 0));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 2));
+		assert_eq!(Assets::scaled_amount(0, 1_234), (12, 34, 2));
+		assert_eq!(Assets::scaled_amount(0, 5), (0, 5, 2));
+		// Metadata stored before decimals were bounded may still have a unit too large to fit.
+		Metadata::<Test>::mutate(0, |metadata| metadata.decimals = 30);
+		assert_eq!(Assets::scaled_amount(0, u64::MAX), (0, u64::MAX, 30));
+
+		assert_ok!(Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 4], vec![0u8; 2], 3));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 10_000));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 1_500));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 1_500));
+
+		EmitScaledAmounts::set(true);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 2_050));
+		assert_eq!(last_event(), AssetsEvent::TransferredHuman(0, 1, 2, 2, 50, 3));
+		EmitScaledAmounts::set(false);
+	});
+}
+
+#[test]
+fn claimed_asset_ids_should_be_reserved_to_the_claimant() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::claim_asset_id(Origin::signed(1), 0));
+		assert_eq!(last_event(), AssetsEvent::AssetIdClaimed(0, 1, 11));
+		assert_eq!(Balances::reserved_balance(1), 1);
+
+		assert_noop!(Assets::claim_asset_id(Origin::signed(2), 0), Error::<Test>::IdClaimed);
+		assert_noop!(
+			Assets::create(Origin::signed(2), 0, 10, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::IdClaimed,
+		);
+		assert_noop!(
+			Assets::complete_claimed_create(Origin::signed(2), 0, 10, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::NotClaimed,
+		);
+
+		assert_ok!(Assets::complete_claimed_create(Origin::signed(1), 0, 10, 1, 1, ExistenceModel::Zombie));
+		assert_eq!(last_event(), AssetsEvent::Created(0, 1));
+		assert!(!ClaimedAssetIds::<Test>::contains_key(0));
+		// Only the asset deposit is left reserved.
+		assert_eq!(Balances::reserved_balance(1), 11);
+		assert_noop!(Assets::claim_asset_id(Origin::signed(2), 0), Error::<Test>::InUse);
+	});
+}
+
+#[test]
+fn expired_claims_should_not_hold() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::claim_asset_id(Origin::signed(1), 0));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Assets::complete_claimed_create(Origin::signed(1), 0, 10, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::NotClaimed,
+		);
+		// Anyone may take over the expired claim, returning the old deposit.
+		assert_ok!(Assets::claim_asset_id(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 1);
+
+		System::set_block_number(21);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 1, ExistenceModel::Zombie));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!ClaimedAssetIds::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn total_deposit_of_should_sum_owned_assets() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_eq!(Assets::total_deposit_of(1), 0);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::create(Origin::signed(1), 1, 5, 1, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::create(Origin::signed(2), 2, 5, 1, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::total_deposit_of(1), 11 + 6);
+
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 0));
+		assert_eq!(Assets::total_deposit_of(1), 11 + 7 + 6);
+		assert_eq!(Assets::total_deposit_of(1), Balances::reserved_balance(1));
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 1, 2));
+		assert_eq!(Assets::total_deposit_of(1), 11 + 7);
+		assert_eq!(Assets::total_deposit_of(2), 6 + 6);
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, witness(100, 0)));
+		assert_eq!(Assets::total_deposit_of(1), 0);
+	});
+}
+
+#[test]
+fn transfer_fees_should_follow_the_router() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 2000));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+
+		SplitFees::set(true);
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 110));
+		assert_eq!(Assets::balance(0, 3), 99);
+		assert_eq!((Assets::balance(0, FEE_POOL.0), Assets::balance(0, FEE_POOL.1)), (5, 6));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::TransferFeeCharged(0, 2, 11))
+		));
+		SplitFees::set(false);
+
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 100));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_eq!(Assets::total_supply(0), 2000);
+	});
+}
+
+#[test]
+fn transfer_fees_should_pay_shares_past_the_limit_to_the_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 2000));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+
+		SplitFees::set(true);
+		MaxFeeShares::set(1);
+		let info = Assets::transfer(Origin::signed(2), 0, 3, 110).unwrap();
+		assert_eq!((Assets::balance(0, FEE_POOL.0), Assets::balance(0, FEE_POOL.1)), (5, 0));
+		assert_eq!(Assets::balance(0, 1), 6);
+		let share = <() as WeightInfo>::transfer().max(<() as WeightInfo>::burn());
+		let paid = <() as EstimatedWeightInfo>::transfer_create() + 2 * share;
+		assert_eq!(info.actual_weight, Some(paid));
+		MaxFeeShares::set(4);
+		SplitFees::set(false);
+	});
+}
+
+#[test]
+fn transfer_fees_should_pass_the_checks_of_the_transfer_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 2000));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+		assert_ok!(Assets::set_transfer_cooldown(Origin::signed(1), 0, Some(5)));
+
+		// Paying the fee does not start the cooldown ahead of the transfer it is paid for.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 100));
+		assert_eq!((Assets::balance(0, 3), Assets::balance(0, 1)), (90, 10));
+		assert_noop!(
+			Assets::transfer(Origin::signed(2), 0, 3, 100),
+			Error::<Test>::TransferCooldown,
+		);
+
+		// Nor does the Owner receiving the fee need to be allowed as a destination.
+		System::set_block_number(6);
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, true));
+		assert_ok!(Assets::set_strict_whitelist(Origin::signed(1), 0, true));
+		assert_ok!(Assets::add_destination(Origin::signed(1), 0, 3));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 100));
+		assert_eq!((Assets::balance(0, 3), Assets::balance(0, 1)), (180, 20));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 2, 3, 90));
+	});
+}
+
+#[test]
+fn try_total_supply_should_tell_unknown_assets_apart() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::try_total_supply(0), None);
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::try_total_supply(0), Some(0));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::try_total_supply(0), Some(100));
+	});
+}
+
+#[test]
+fn freeze_asset_until_should_thaw_by_itself() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::freeze_asset_until(Origin::signed(2), 0, 5, None),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::freeze_asset_until(Origin::signed(1), 0, 5, Some(3)));
+		assert_eq!(last_event(), AssetsEvent::AssetFrozenUntil(0, 3, 5));
+		assert_eq!(Assets::asset_freeze_reason(0), Some(3));
+
+		System::set_block_number(5);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::AssetFrozen);
+
+		// Past the end the asset counts as thawed, and the first transfer lifts the freeze.
+		System::set_block_number(6);
+		assert_eq!(Assets::asset_freeze_reason(0), None);
+		assert!(!Assets::asset_overview(0).unwrap().is_frozen);
+		assert!(FrozenAssets::<Test>::contains_key(0));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::AssetThawed(0))
+		));
+		assert!(!Asset::<Test>::get(0).unwrap().is_frozen);
+		assert!(!FrozenAssets::<Test>::contains_key(0));
+
+		// A plain freeze has no end.
+		assert_ok!(Assets::freeze_asset_until(Origin::signed(1), 0, 7, None));
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0, None));
+		System::set_block_number(100);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 10), Error::<Test>::AssetFrozen);
+	});
+}
+
+/// The pallet in a runtime with 128-bit asset identifiers, as used for large derived id spaces.
+mod wide_asset_ids {
+	use super::*;
+
+	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+	type Block = frame_system::mocking::MockBlock<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test where
+			Block = Block,
+			NodeBlock = Block,
+			UncheckedExtrinsic = UncheckedExtrinsic,
+		{
+			System: frame_system::{Module, Call, Config, Storage, Event<T>},
+			Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+			Assets: mc_featured_assets::{Module, Call, Storage, Event<T>},
+		}
+	);
+
+	impl frame_system::Config for Test {
+		type BaseCallFilter = ();
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type Origin = Origin;
+		type Index = u64;
+		type Call = Call;
+		type BlockNumber = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Header = Header;
+		type Event = Event;
+		type BlockHashCount = BlockHashCount;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u64>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+	}
+
+	impl pallet_balances::Config for Test {
+		type MaxLocks = ();
+		type Balance = u64;
+		type DustRemoval = ();
+		type Event = Event;
+		type ExistentialDeposit = ExistentialDeposit;
+		type AccountStore = System;
+		type WeightInfo = ();
+	}
+
+	impl Config for Test {
+		type Currency = Balances;
+		type Event = Event;
+		type Balance = u64;
+		type AssetId = u128;
+		type ForceOrigin = frame_system::EnsureRoot<u64>;
+		type AssetDepositBase = AssetDepositBase;
+		type AssetDepositPerZombie = AssetDepositPerZombie;
+		type ApprovalDeposit = ApprovalDeposit;
+		type StringLimit = StringLimit;
+		type MaxTotalMetadataBytes = MaxTotalMetadataBytes;
+		type MetadataDepositBase = MetadataDepositBase;
+		type MetadataDepositPerByte = MetadataDepositPerByte;
+		type WeightInfo = ();
+		type AssetAdmin = TestAssetAdmin;
+		type RandomNumber = ();
+		type AssetIdDeriver = HashedAssetId<Test>;
+		type TransferPolicy = ();
+		type RateLimitWindow = RateLimitWindow;
+		type ReusableAssetIds = ReusableAssetIds;
+		type MaxBatchSize = MaxBatchSize;
+		type FeatureRoller = TestFeatureRoller;
+		type DestroyRefundRatio = DestroyRefundRatio;
+		type DestroyFeeDestination = ();
+		type MaxMinBalance = MaxMinBalance;
+		type AssetIdConverter = ZeroPaddedAssetId;
+		type HoldOrigin = frame_system::EnsureRoot<u64>;
+		type BatchWeightLimit = BatchWeightLimit;
+		type DepositAsset = ();
+		type SupplyReportPeriod = SupplyReportPeriod;
+		type MaxSupplyReportAssets = MaxSupplyReportAssets;
+		type AssetIdDisplay = ();
+		type MinAccountLifetime = MinAccountLifetime;
+		type OnDestroy = ();
+		type EmitScaledAmounts = EmitScaledAmounts;
+		type ClaimDeposit = ClaimDeposit;
+		type ClaimPeriod = ClaimPeriod;
+		type FeeRouter = ();
+		type MaxFeeShares = MaxFeeShares;
+		type AccountExtra = ();
+		type OnAccountTouched = ();
+		type PremiumAssetIds = PremiumIds;
+		type IdAuctionDuration = IdAuctionDuration;
+		type IdAuctionClaimPeriod = IdAuctionClaimPeriod;
+		type IdAuctionProceeds = ();
+		type CrossChainNotifier = ();
+		type RecordActivity = RecordActivity;
+		type MaxRecentActivity = MaxRecentActivity;
+		type BurnAddress = ();
+		type MaxBurnRateBps = MaxBurnRateBps;
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Test>().unwrap().into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	#[test]
+	fn core_calls_should_work_with_wide_asset_ids() {
+		new_test_ext().execute_with(|| {
+			let id = u128::MAX - 1;
+			Balances::make_free_balance_be(&1, 100);
+			assert_ok!(Assets::claim_asset_id(Origin::signed(1), id));
+			assert_ok!(
+				Assets::complete_claimed_create(Origin::signed(1), id, 10, 1, 1, ExistenceModel::Zombie)
+			);
+			assert_ok!(Assets::set_metadata(Origin::signed(1), id, vec![0u8; 4], vec![0u8; 2], 0));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 2, 100));
+			assert_ok!(Assets::transfer(Origin::signed(2), id, 3, 40));
+			assert_ok!(Assets::approve_transfer(Origin::signed(1), id, 2, 10));
+			assert_ok!(Assets::freeze_asset(Origin::signed(1), id, None));
+			assert_eq!(Assets::frozen_assets(None, 10), vec![id]);
+			assert_ok!(Assets::thaw_asset(Origin::signed(1), id));
+			assert_eq!((Assets::balance(id, 2), Assets::balance(id, 3)), (60, 40));
+			assert_eq!(Assets::total_deposit_of(1), Balances::reserved_balance(1) - 1);
+			assert_eq!(Assets::feature_histogram().iter().map(|(_, n)| n).sum::<u32>(), 1);
+
+			// Identifiers survive every representation whole.
+			let address = Assets::eth_address_of(id);
+			assert_eq!(Assets::asset_of_eth_address(address), Some(id));
+			assert_eq!(Assets::parse_asset_id(Assets::format_asset_id(id)), Some(id));
+			assert_eq!(Assets::format_asset_id(u128::MAX), u128::MAX.to_string().into_bytes());
+
+			assert_ok!(Assets::burn(Origin::signed(1), id, 2, 60));
+			assert_ok!(Assets::burn(Origin::signed(1), id, 3, 40));
+			assert_ok!(Assets::force_cancel_approvals(Origin::root(), id, 1));
+			assert_ok!(Assets::destroy(Origin::signed(1), id, witness(100, 0)));
+			assert_eq!(Assets::try_total_supply(id), None);
+			assert_eq!(Balances::reserved_balance(1), 0);
+
+			// Derived identifiers make use of the whole width.
+			assert_ok!(
+				Assets::create_deterministic(Origin::signed(1), [7u8; 32], 10, 1, 1, ExistenceModel::Zombie)
+			);
+			let derived = HashedAssetId::<Test>::derive_asset_id(&1, &[7u8; 32]);
+			assert!(derived > u64::MAX as u128);
+			assert_eq!(Assets::try_total_supply(derived), Some(0));
+		});
+	}
+}
+
+#[test]
+fn transfer_exact_should_not_sweep_dust() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		// Leaving 5 behind sweeps it along with a plain transfer...
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 95));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 3, 100));
+		assert_eq!(Assets::balance(0, 1), 0);
+
+		// ...but fails an exact one.
+		assert_noop!(Assets::transfer_exact(Origin::signed(2), 0, 3, 95), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::transfer_exact(Origin::signed(2), 0, 4, 5), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::transfer_exact(Origin::signed(2), 0, 3, 90));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 2, 3, 90));
+		assert_eq!((Assets::balance(0, 2), Assets::balance(0, 3)), (10, 190));
+		assert_ok!(Assets::transfer_exact(Origin::signed(2), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 2), 0);
+	});
+}
+
+#[test]
+fn account_extra_should_follow_balance_changes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Account::<Test>::get(0, 1).extra, (1, 100));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 40));
+		assert_eq!(Account::<Test>::get(0, 1).extra, (2, 100));
+		assert_eq!(Account::<Test>::get(0, 2).extra, (1, 40));
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 10));
+		assert_eq!(Account::<Test>::get(0, 1).extra, (3, 100));
+
+		// Changes other than to the balance keep the extra data as it is.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 2));
+		assert_eq!(Account::<Test>::get(0, 2).extra, (1, 40));
+
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 40));
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(Account::<Test>::get(0, 1).extra, (4, 100));
+	});
+}
+
+#[test]
+fn premium_ids_should_only_be_created_by_auction_winners() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=3 {
+			Balances::make_free_balance_be(&who, 100);
+		}
+		assert_noop!(
+			Assets::create(Origin::signed(1), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::PremiumId,
+		);
+		assert_noop!(Assets::claim_asset_id(Origin::signed(1), 100), Error::<Test>::PremiumId);
+		assert_noop!(Assets::bid_for_id(Origin::signed(1), 99, 10), Error::<Test>::NotPremiumId);
+		assert_noop!(Assets::bid_for_id(Origin::signed(1), 100, 0), Error::<Test>::BidTooLow);
+
+		// Each bid must beat the last, whose bidder gets it back.
+		assert_ok!(Assets::bid_for_id(Origin::signed(1), 100, 10));
+		assert_eq!(last_event(), AssetsEvent::IdBid(100, 1, 10));
+		assert_noop!(Assets::bid_for_id(Origin::signed(2), 100, 10), Error::<Test>::BidTooLow);
+		assert_ok!(Assets::bid_for_id(Origin::signed(2), 100, 20));
+		assert_eq!((Balances::reserved_balance(1), Balances::reserved_balance(2)), (0, 20));
+		assert_ok!(Assets::bid_for_id(Origin::signed(3), 100, 30));
+		assert_eq!((Balances::reserved_balance(2), Balances::reserved_balance(3)), (0, 30));
+
+		// Nobody may claim the id while the auction takes bids.
+		assert_noop!(
+			Assets::claim_won_id(Origin::signed(3), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::AuctionOngoing,
+		);
+		System::set_block_number(11);
+		assert_noop!(Assets::bid_for_id(Origin::signed(1), 100, 40), Error::<Test>::AuctionEnded);
+		assert_noop!(
+			Assets::claim_won_id(Origin::signed(2), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::NotAuctionWinner,
+		);
+		assert_noop!(
+			Assets::create(Origin::signed(2), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::PremiumId,
+		);
+		// Not even the winner may skip paying the bid by creating the asset directly.
+		assert_noop!(
+			Assets::create(Origin::signed(3), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::PremiumId,
+		);
+		assert_noop!(
+			Assets::close_lapsed_auction(Origin::signed(1), 100),
+			Error::<Test>::AuctionNotLapsed,
+		);
+
+		// The winner creates the asset and pays the bid as a fee.
+		assert_ok!(Assets::claim_won_id(Origin::signed(3), 100, 1, 1, 1, ExistenceModel::Zombie));
+		assert_eq!(last_event(), AssetsEvent::IdAuctionWon(100, 3, 30));
+		assert_eq!(Asset::<Test>::get(100).unwrap().owner, 3);
+		assert!(IdAuctions::<Test>::get(100).is_none());
+		assert_eq!(Balances::reserved_balance(3), 2);
+		assert_eq!(Balances::free_balance(3), 68);
+		assert_eq!(Balances::free_balance(TREASURY), 30);
+		assert_eq!((Balances::free_balance(1), Balances::free_balance(2)), (100, 100));
+	});
+}
+
+#[test]
+fn unclaimed_id_auctions_should_lapse_and_refund_the_bid() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(
+			Assets::close_lapsed_auction(Origin::signed(2), 100),
+			Error::<Test>::AuctionNotLapsed,
+		);
+		assert_ok!(Assets::bid_for_id(Origin::signed(1), 100, 10));
+
+		// The winner may claim the id until the claim period after the auction has passed.
+		System::set_block_number(20);
+		assert_noop!(
+			Assets::close_lapsed_auction(Origin::signed(2), 100),
+			Error::<Test>::AuctionNotLapsed,
+		);
+		System::set_block_number(21);
+		assert_noop!(
+			Assets::claim_won_id(Origin::signed(1), 100, 1, 1, 1, ExistenceModel::Zombie),
+			Error::<Test>::AuctionLapsed,
+		);
+
+		// Anyone may then close the auction, returning the bid.
+		assert_ok!(Assets::close_lapsed_auction(Origin::signed(2), 100));
+		assert_eq!(last_event(), AssetsEvent::IdAuctionLapsed(100, 1, 10));
+		assert!(IdAuctions::<Test>::get(100).is_none());
+		assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (100, 0));
+
+		// The id is up for auction again.
+		assert_ok!(Assets::bid_for_id(Origin::signed(1), 100, 5));
+		assert_eq!(IdAuctions::<Test>::get(100).unwrap().ends_at, 31);
+	});
+}
+
+#[test]
+fn force_clear_orphans_should_remove_entries_of_missing_assets() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		for id in 0..2 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, 0, 1, ExistenceModel::Zombie));
+			assert_ok!(Assets::set_metadata(Origin::signed(1), id, vec![0u8; 4], vec![0u8; 2], 0));
+		}
+		// Asset 0 vanishes as it did before destroy removed its feature and metadata.
+		Asset::<Test>::remove(0);
+		assert_noop!(Assets::force_clear_orphans(Origin::signed(1), 10), BadOrigin);
+
+		// Two feature and two metadata entries, then one more call to notice there are no more.
+		for _ in 0..4 {
+			assert_ok!(Assets::force_clear_orphans(Origin::root(), 1));
+			assert!(OrphanSweeps::<Test>::get().is_some());
+		}
+		assert_ok!(Assets::force_clear_orphans(Origin::root(), 1));
+		assert_eq!(last_event(), AssetsEvent::OrphansCleared(2));
+		assert!(OrphanSweeps::<Test>::get().is_none());
+
+		assert!(!Feature::<Test>::contains_key(0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert!(Feature::<Test>::contains_key(1));
+		assert!(Metadata::<Test>::contains_key(1));
+		assert_eq!(Assets::feature_histogram().iter().map(|(_, n)| n).sum::<u32>(), 1);
+
+		// A sweep over clean storage clears nothing.
+		assert_ok!(Assets::force_clear_orphans(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::OrphansCleared(0));
+	});
+}
+
+#[test]
+fn only_cross_chain_assets_should_notify() {
+	new_test_ext().execute_with(|| {
+		for id in 0..2 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, 10, 1, ExistenceModel::Zombie));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 1, 100));
+		}
+		assert_noop!(Assets::set_cross_chain(Origin::signed(2), 0, true), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_cross_chain(Origin::signed(1), 0, true));
+		assert_eq!(last_event(), AssetsEvent::CrossChainSet(0, true));
+
+		for id in 0..2 {
+			assert_ok!(Assets::transfer(Origin::signed(1), id, 2, 30));
+			assert_ok!(Assets::burn(Origin::signed(1), id, 2, 10));
+		}
+		assert_eq!(cross_chain_movements(), vec![(0, 1, Some(2), 30), (0, 2, None, 10)]);
+
+		assert_ok!(Assets::set_cross_chain(Origin::signed(1), 0, false));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		assert_eq!(cross_chain_movements().len(), 2);
+	});
+}
+
+#[test]
+fn force_create_weight_should_include_the_randomness_draw() {
+	use frame_support::weights::GetDispatchInfo;
+
+	let call = mc_featured_assets::Call::<Test>::force_create(0, 1, 10, 1, ExistenceModel::Zombie);
+	let base = <() as EstimatedWeightInfo>::force_create_base();
+	let draw = <() as EstimatedWeightInfo>::randomness_draw();
+	assert!(draw > 0);
+	assert_eq!(call.get_dispatch_info().weight, base + draw);
+}
+
+#[test]
+fn recent_activity_should_keep_the_latest_entries() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert!(Assets::recent_activity(0).is_empty());
+
+		RecordActivity::set(true);
+		let entry = |kind, block, who, counterparty| ActivityEntry { kind, block, who, counterparty };
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::recent_activity(0), vec![entry(ActivityKind::Issued, 1, 1, None)]);
+
+		System::set_block_number(2);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		System::set_block_number(3);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 10));
+		System::set_block_number(4);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2, None));
+
+		// Only the last `MaxRecentActivity` entries are kept.
+		assert_eq!(Assets::recent_activity(0), vec![
+			entry(ActivityKind::Transferred, 2, 1, Some(2)),
+			entry(ActivityKind::Burned, 3, 2, None),
+			entry(ActivityKind::Frozen, 4, 2, None),
+		]);
+		RecordActivity::set(false);
+	});
+}
+
+#[test]
+fn hooks_should_not_reenter_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		ReenterOnTransfer::set(true);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		ReenterOnTransfer::set(false);
+		assert_eq!(last_reentry(), Some(Err(Error::<Test>::Reentrancy.into())));
+
+		// The re-entrant transfer left nothing behind, and the guard was released.
+		assert_eq!((Assets::balance(0, 1), Assets::balance(0, 2)), (70, 130));
+		assert_eq!(Asset::<Test>::get(0).unwrap().supply, 200);
+		assert!(!MutationInProgress::<Test>::get());
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 30));
+	});
+}
+
+#[test]
+fn fee_router_and_destroy_hooks_should_not_reenter() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+
+		ReenterOnHooks::set(true);
+		assert_ok!(Assets::transfer(Origin::signed(3), 0, 4, 50));
+		assert_eq!(last_reentry(), Some(Err(Error::<Test>::Reentrancy.into())));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert!(!MutationInProgress::<Test>::get());
+
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 10, 1, ExistenceModel::Zombie));
+		REENTRY.with(|r| *r.borrow_mut() = None);
+		assert_ok!(Assets::destroy(Origin::signed(1), 1, witness(0, 0)));
+		ReenterOnHooks::set(false);
+		assert_eq!(last_reentry(), Some(Err(Error::<Test>::Reentrancy.into())));
+		assert!(!Asset::<Test>::contains_key(1));
+		assert!(!MutationInProgress::<Test>::get());
+	});
+}
+
+#[test]
+fn exempt_accounts_should_keep_balances_below_the_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		assert_noop!(Assets::set_exempt(Origin::signed(2), 0, 3, true), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_exempt(Origin::signed(1), 0, 3, true));
+		assert_eq!(last_event(), AssetsEvent::ExemptionSet(0, 3, true));
+
+		// The same transfer sweeps a normal account but not an exempt one.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 4, 95));
+		assert_ok!(Assets::transfer(Origin::signed(3), 0, 5, 95));
+		assert_eq!((Assets::balance(0, 2), Assets::balance(0, 4)), (0, 100));
+		assert_eq!((Assets::balance(0, 3), Assets::balance(0, 5)), (5, 95));
+
+		// Nor is an exempt account reaped by a burn, and it may be credited any amount.
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 3, 2));
+		assert_eq!(Assets::balance(0, 3), 3);
+		assert_ok!(Assets::transfer(Origin::signed(4), 0, 3, 1));
+		assert_noop!(Assets::transfer(Origin::signed(4), 0, 6, 1), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 1));
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 6, 1), Error::<Test>::BalanceLow);
+
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.accounts, details.zombies, details.supply), (3, 3, 199));
+
+		// An exempt account emptied is still reaped.
+		assert_ok!(Assets::transfer(Origin::signed(3), 0, 4, 5));
+		assert!(!Account::<Test>::contains_key(0, 3));
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.accounts, details.zombies), (2, 2));
+	});
+}
+
+#[test]
+fn exempt_accounts_should_be_spared_by_purge_all_dust_until_burned_empty() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		assert_ok!(Assets::set_exempt(Origin::signed(1), 0, 3, true));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 3, 95));
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().min_balance = 50);
+
+		System::set_block_number(11);
+		assert_ok!(Assets::purge_all_dust(Origin::root(), 10));
+		assert_eq!(last_event(), AssetsEvent::GlobalDustPurged(0, 0));
+		assert_eq!(Assets::balance(0, 3), 5);
+
+		// Burning all of it reaps the account rather than leaving an empty one behind.
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 3, 5));
+		assert!(!Account::<Test>::contains_key(0, 3));
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.accounts, details.supply), (1, 100));
+	});
+}
+
+#[test]
+fn simulate_transfer_should_predict_transfer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		let fee = TransferFee { rate: Permill::from_percent(10), min_fee: 0, min_fee_threshold: 0 };
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Some(fee)));
+
+		let check = |from: u64, to: u64, amount: u64| {
+			let events = System::events().len();
+			let simulation = Assets::simulate_transfer(0, from, to, amount);
+			assert_eq!(System::events().len(), events);
+
+			let (existed, before) = (Account::<Test>::contains_key(0, to), Assets::balance(0, to));
+			let result = Assets::transfer(Origin::signed(from), 0, to, amount);
+			assert_eq!(simulation.error, result.err().map(|e| e.error));
+			assert_eq!(simulation.delivered, Assets::balance(0, to) - before);
+			let collapsed = result.is_ok() && !Account::<Test>::contains_key(0, from);
+			assert_eq!(simulation.sender_collapses, collapsed);
+			assert_eq!(simulation.recipient_created, !existed && Account::<Test>::contains_key(0, to));
+			simulation
+		};
+
+		// A tenth goes to the Owner as a fee and the recipient is created.
+		let simulation = check(2, 3, 50);
+		assert_eq!((simulation.delivered, simulation.recipient_created), (45, true));
+		// The remaining 2 are swept along and the sender collapses.
+		let simulation = check(2, 3, 48);
+		assert_eq!((simulation.delivered, simulation.sender_collapses), (46, true));
+		// Failures are predicted with their error.
+		assert_eq!(check(2, 3, 10).error, Some(Error::<Test>::BalanceLow.into()));
+		assert_eq!(check(3, 4, 0).error, Some(Error::<Test>::AmountZero.into()));
+		assert_eq!(check(3, 4, 1).error, Some(Error::<Test>::BalanceLow.into()));
+	});
+}
+
+#[test]
+fn distribute_should_split_by_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_noop!(
+			Assets::distribute(Origin::signed(2), 0, 100, vec![(2, 1)]),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Assets::distribute(Origin::signed(1), 0, 100, vec![(2, 0)]),
+			Error::<Test>::NoShares,
+		);
+		assert_noop!(
+			Assets::distribute(Origin::signed(1), 0, 100, vec![]),
+			Error::<Test>::NoShares,
+		);
+		assert_noop!(
+			Assets::distribute(Origin::signed(1), 0, 100, vec![(2, 1); 6]),
+			Error::<Test>::BatchTooLarge,
+		);
+
+		// 100 split 1:1:1 is 33 each, and the 1 left over goes to the first recipient.
+		assert_ok!(Assets::distribute(Origin::signed(1), 0, 100, vec![(2, 1), (3, 1), (4, 1)]));
+		assert_eq!(last_event(), AssetsEvent::Issued(0, 4, 33));
+		let balances = || (Assets::balance(0, 2), Assets::balance(0, 3), Assets::balance(0, 4));
+		assert_eq!(balances(), (34, 33, 33));
+
+		// Shares weigh the split, and a recipient without shares gets nothing.
+		let recipients = vec![(4, 7), (2, 2), (5, 0), (3, 1)];
+		assert_ok!(Assets::distribute(Origin::signed(1), 0, 1000, recipients));
+		assert_eq!(balances(), (234, 133, 733));
+		assert!(!Account::<Test>::contains_key(0, 5));
+		assert_eq!(Asset::<Test>::get(0).unwrap().supply, 1100);
+
+		// A recipient who cannot be credited fails the whole distribution.
+		assert_noop!(
+			Assets::distribute(Origin::signed(1), 0, u64::MAX, vec![(6, 1), (2, 1)]),
+			Error::<Test>::Overflow,
+		);
+	});
+}
+
+#[test]
+fn decimals_should_be_bounded_by_the_balance_type() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::checked_pow10(0), Some(1));
+		assert_eq!(Assets::checked_pow10(19), Some(10_000_000_000_000_000_000));
+		assert_eq!(Assets::checked_pow10(20), None);
+
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 20),
+			Error::<Test>::DecimalsOverflow,
+		);
+		assert_noop!(
+			Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 4], vec![0u8; 2], 20),
+			Error::<Test>::DecimalsOverflow,
+		);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 4], vec![0u8; 2], 19));
+		let unit = 10_000_000_000_000_000_000;
+		assert_eq!(Assets::scaled_amount(0, u64::MAX), (1, u64::MAX - unit, 19));
+	});
+}
+
+fn genesis_ext(
+	assets: Vec<GenesisAsset<u32, u64, u64>>,
+	accounts: Vec<(u32, u64, u64)>,
+) -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	mc_featured_assets::GenesisConfig::<Test> { assets, accounts }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	storage.into()
+}
+
+fn genesis_asset(supply: u64, accounts: u32, zombies: u32) -> GenesisAsset<u32, u64, u64> {
+	GenesisAsset {
+		id: 0,
+		owner: 1,
+		max_zombies: 10,
+		min_balance: 1,
+		existence_model: ExistenceModel::Zombie,
+		feature_code: 1,
+		supply,
+		accounts,
+		zombies,
+	}
+}
+
+#[test]
+fn consistent_genesis_should_build() {
+	genesis_ext(vec![genesis_asset(150, 2, 1)], vec![(0, 1, 100), (0, 2, 50)]).execute_with(|| {
+		assert_eq!(Assets::total_supply(0), 150);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 50);
+		// Account 1 exists in the system, so only account 2 is a zombie.
+		assert_eq!(Asset::<Test>::get(0).unwrap().zombies, 1);
+		assert!(Feature::<Test>::get(0).is_some());
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+	});
+}
+
+#[test]
+#[should_panic(expected = "supply of asset 0 is 200, but its accounts hold 150")]
+fn genesis_with_wrong_supply_should_panic() {
+	genesis_ext(vec![genesis_asset(200, 2, 1)], vec![(0, 1, 100), (0, 2, 50)]);
+}
+
+#[test]
+#[should_panic(expected = "asset 0 declares 2 zombies, but 1 are seeded")]
+fn genesis_with_wrong_counts_should_panic() {
+	genesis_ext(vec![genesis_asset(150, 2, 2)], vec![(0, 1, 100), (0, 2, 50)]);
+}
+
+#[test]
+fn transfers_to_the_burn_address_should_burn() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 5, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		BurnAddress::set(Some(77));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 77, 30));
+		assert_eq!(last_event(), AssetsEvent::Burned(0, 1, 30));
+		assert_eq!(Assets::total_supply(0), 70);
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert!(!Account::<Test>::contains_key(0, 77));
+
+		// Dust left below the minimum balance is burned too.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 77, 67));
+		assert_eq!(last_event(), AssetsEvent::Burned(0, 1, 70));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert!(!Account::<Test>::contains_key(0, 77));
+
+		// A transfer to the burn address is a burn, so it needs the asset to be burnable.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 77, 10), Error::<Test>::BurnDisabled);
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, true));
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1, None));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 77, 10), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+
+		BurnAddress::set(None);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 77, 10));
+		assert_eq!(Assets::balance(0, 77), 10);
+		assert_eq!(Assets::total_supply(0), 100);
+	});
+}
+
+#[test]
+fn metadata_should_be_capped_in_total() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		// Each field is within `StringLimit`, but together they are above the total cap.
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 40], vec![0u8; 21], 0),
+			Error::<Test>::BadMetadata,
+		);
+		assert_noop!(
+			Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 40], vec![0u8; 21], 0),
+			Error::<Test>::BadMetadata,
+		);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 40], vec![0u8; 20], 0));
+		assert_eq!(Metadata::<Test>::get(0).name.len(), 40);
+	});
+}
+
+#[test]
+fn would_be_zombie_should_follow_system_existence() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_eq!(Assets::would_be_zombie(0, 2), None);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::would_be_zombie(0, 2), Some(false));
+		assert_eq!(Assets::would_be_zombie(0, 5), Some(true));
+
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 5, 100));
+		assert!(Account::<Test>::get(0, 5).is_zombie());
+		assert_eq!(Assets::would_be_zombie(0, 5), None);
+
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 0, 1, ExistenceModel::Sufficient));
+		assert_eq!(Assets::would_be_zombie(1, 6), Some(false));
+	});
+}
+
+#[test]
+fn team_should_bypass_the_destination_whitelist_unless_strict() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 5, 100));
+		set_team(2, 1, 3);
+		assert_ok!(Assets::set_destination_whitelist(Origin::signed(1), 0, true));
+
+		// The owner, issuer, admin and freezer need no allowlist entry.
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 1, 10));
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 2, 10));
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 3, 10));
+		assert_noop!(Assets::transfer(Origin::signed(5), 0, 4, 10), Error::<Test>::NotAllowed);
+
+		assert_noop!(
+			Assets::set_strict_whitelist(Origin::signed(2), 0, true),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::set_strict_whitelist(Origin::signed(1), 0, true));
+		assert_eq!(last_event(), AssetsEvent::StrictWhitelistSet(0, true));
+		assert_noop!(Assets::transfer(Origin::signed(5), 0, 1, 10), Error::<Test>::NotAllowed);
+		assert_noop!(Assets::transfer(Origin::signed(5), 0, 2, 10), Error::<Test>::NotAllowed);
+		assert_ok!(Assets::add_destination(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer(Origin::signed(5), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 2), 20);
+	});
+}
+
+#[test]
+fn try_state_should_catch_broken_accounting() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::do_try_state());
+
+		test_helpers::set_supply_unchecked::<Test>(0, 149);
+		let error = "the supply of an asset is not held by its accounts";
+		assert_eq!(Assets::do_try_state(), Err(error));
+		test_helpers::set_supply_unchecked::<Test>(0, 150);
+
+		test_helpers::set_counts_unchecked::<Test>(0, 3, 1);
+		assert_eq!(Assets::do_try_state(), Err("the account count of an asset is wrong"));
+		test_helpers::set_counts_unchecked::<Test>(0, 1, 2);
+		assert_eq!(Assets::do_try_state(), Err("an asset has more zombies than accounts"));
+		test_helpers::set_counts_unchecked::<Test>(0, 2, 1);
+		assert_ok!(Assets::do_try_state());
+
+		Feature::<Test>::remove(0);
+		assert_eq!(Assets::do_try_state(), Err("a featured asset has no feature"));
+	});
+}
+
+#[test]
+fn transfers_should_burn_the_burn_rate() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 10_000));
+		assert_noop!(Assets::set_burn_rate(Origin::signed(2), 0, 250), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::set_burn_rate(Origin::signed(1), 0, 1_001),
+			Error::<Test>::BurnRateTooHigh,
+		);
+		assert_ok!(Assets::set_burn_rate(Origin::signed(1), 0, 250));
+		assert_eq!(last_event(), AssetsEvent::BurnRateSet(0, 250));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 1_000));
+		assert_eq!(Assets::total_supply(0), 9_975);
+		assert_eq!(Assets::balance(0, 1), 9_000);
+		assert_eq!(Assets::balance(0, 2), 975);
+		assert!(System::events().iter().any(|r|
+			r.event == Event::mc_featured_assets(AssetsEvent::Burned(0, 1, 25))
+		));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 975));
+
+		// A burn that rounds down to zero burns nothing.
+		System::reset_events();
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 39));
+		assert_eq!(Assets::total_supply(0), 9_975);
+		assert_eq!(Assets::balance(0, 2), 1_014);
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 1, 2, 39));
+		assert!(!System::events().iter().any(|r|
+			matches!(r.event, Event::mc_featured_assets(AssetsEvent::Burned(..)))
+		));
+	});
+}
+
+#[test]
+fn transfer_burn_should_respect_the_cap_and_burnable() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 1, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 10_000));
+		assert_ok!(Assets::set_burn_rate(Origin::signed(1), 0, 1_000));
+		assert_ok!(Assets::set_max_transfer(Origin::signed(1), 0, Some(100)));
+
+		// The cap applies to the amount sent, burned share included.
+		assert_noop!(
+			Assets::transfer(Origin::signed(1), 0, 2, 105),
+			Error::<Test>::AboveMaxTransfer,
+		);
+		let info = Assets::transfer(Origin::signed(1), 0, 2, 100).unwrap();
+		let burn = <() as WeightInfo>::burn();
+		assert_eq!(info.actual_weight, Some(<() as EstimatedWeightInfo>::transfer_create() + burn));
+		assert_eq!((Assets::balance(0, 2), Assets::total_supply(0)), (90, 9_990));
+
+		// Nothing is burned while burning is forbidden.
+		assert_ok!(Assets::set_burnable(Origin::signed(1), 0, false));
+		let info = Assets::transfer(Origin::signed(1), 0, 2, 100).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as EstimatedWeightInfo>::transfer_existing()));
+		assert_eq!((Assets::balance(0, 2), Assets::total_supply(0)), (190, 9_990));
+	});
+}
+
+#[test]
+fn transfer_burn_should_not_reap_the_sender() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10, 95, ExistenceModel::Zombie));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		assert_ok!(Assets::set_burn_rate(Origin::signed(1), 0, 1_000));
+
+		// Burning 9 leaves 91, below the minimum, which the rest of the transfer sweeps along.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 90));
+		assert_eq!(last_event(), AssetsEvent::Transferred(0, 2, 3, 91));
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!((Assets::balance(0, 3), Assets::total_supply(0)), (191, 191));
+	});
+}
+
+#[test]
+fn creator_should_be_kept_when_ownership_moves() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 10, 1, 10, ExistenceModel::Zombie));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 2, 10, 1, ExistenceModel::Zombie));
+		assert_eq!(Assets::assets_created_by(1), vec![0]);
+		assert_eq!(Assets::assets_created_by(2), vec![1]);
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 2);
+		assert_eq!(Asset::<Test>::get(0).unwrap().creator, 1);
+		assert_eq!(Assets::assets_created_by(1), vec![0]);
+		assert_eq!(Assets::assets_created_by(2), vec![1]);
+
+		assert_ok!(Assets::destroy(Origin::signed(2), 0, witness(0, 0)));
+		assert!(Assets::assets_created_by(1).is_empty());
+	});
+}
@@ -41,13 +41,11 @@
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
+use crate::estimated_weights::EstimatedWeightInfo;
 
 /// Weight functions needed for pallet_assets.
-pub trait WeightInfo {
+pub trait WeightInfo: EstimatedWeightInfo {
 	fn create() -> Weight;
-	fn force_create() -> Weight;
-	fn destroy(z: u32, ) -> Weight;
-	fn force_destroy(z: u32, ) -> Weight;
 	fn mint() -> Weight;
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
@@ -70,27 +68,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn force_create() -> Weight {
-		(21_480_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-	}
-	fn destroy(z: u32, ) -> Weight {
-		(0 as Weight)
-			// Standard Error: 2_000
-			.saturating_add((1_149_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
-	}
-	fn force_destroy(z: u32, ) -> Weight {
-		(0 as Weight)
-			// Standard Error: 2_000
-			.saturating_add((1_146_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
-	}
 	fn mint() -> Weight {
 		(32_995_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -164,27 +141,6 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	fn force_create() -> Weight {
-		(21_480_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-	}
-	fn destroy(z: u32, ) -> Weight {
-		(0 as Weight)
-			// Standard Error: 2_000
-			.saturating_add((1_149_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
-	}
-	fn force_destroy(z: u32, ) -> Weight {
-		(0 as Weight)
-			// Standard Error: 2_000
-			.saturating_add((1_146_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(z as Weight)))
-	}
 	fn mint() -> Weight {
 		(32_995_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
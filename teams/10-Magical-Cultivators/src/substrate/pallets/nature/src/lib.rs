@@ -218,6 +218,15 @@ impl<T: Config> ManagerAccessor<T::AccountId> for Pallet<T> {
 	fn is_freezer(who: &T::AccountId) -> bool {
 		Managers::<T>::get(who).map(|x| x.is_freezer).unwrap_or(false)
 	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_manager(who: &T::AccountId) {
+		Managers::<T>::insert(who, ManagerInfo {
+			deposit: Zero::zero(),
+			is_admin: true,
+			is_issuer: true,
+			is_freezer: true,
+		});
+	}
 }
 
 impl<T: Config> RandomNumber<u32> for Pallet<T> {
@@ -6,9 +6,12 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 // Asset 的组合特性
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FeatureHue {
 	Green,
 	Yellow,
@@ -52,6 +55,7 @@ impl From<u8> for FeatureHue {
 	}
 }
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FeatureElements {
 	One(FeatureHue),
 	Two(FeatureHue, FeatureHue),
@@ -90,10 +94,32 @@ impl From<&[u8;4]> for FeatureElements {
 	}
 }
 
+impl From<FeatureElements> for u16 {
+	/// Packs the hues into nibbles, the inverse of `From<u16>`. No nibble is left at zero, as a
+	/// zero nibble marks a missing hue.
+	fn from(elements: FeatureElements) -> u16 {
+		let hues = match elements {
+			FeatureElements::One(a) => [Some(a), None, None, None],
+			FeatureElements::Two(a, b) => [Some(a), Some(b), None, None],
+			FeatureElements::Three(a, b, c) => [Some(a), Some(b), Some(c), None],
+			FeatureElements::Four(a, b, c, d) => [Some(a), Some(b), Some(c), Some(d)],
+		};
+		hues.iter().flatten().enumerate().fold(0u16, |code, (i, hue)| {
+			// `FeatureHue::from` maps `n` to the hue numbered `n % 9 + 1`.
+			let nibble = match Into::<u8>::into(hue.clone()) {
+				0x01 => 0x09,
+				num => num - 1,
+			};
+			code | ((nibble as u16) << (4 * i))
+		})
+	}
+}
+
 impl Default for FeatureElements {
 	fn default() -> Self { Self::One(FeatureHue::Green) }
 }
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FeatureLevel {
 	Lv0,
 	Lv1,
@@ -131,6 +157,7 @@ impl Default for FeatureLevel {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FeatureRankedLevel {
 	Low(FeatureLevel),
 	Middle(FeatureLevel),
@@ -148,11 +175,22 @@ impl From<u8> for FeatureRankedLevel {
 		}
 	}
 }
+impl From<FeatureRankedLevel> for u8 {
+	fn from(level: FeatureRankedLevel) -> u8 {
+		let (rank, level) = match level {
+			FeatureRankedLevel::Low(level) => (0u8, level),
+			FeatureRankedLevel::Middle(level) => (1u8, level),
+			FeatureRankedLevel::High(level) => (2u8, level),
+		};
+		(rank << 4) | Into::<u8>::into(level)
+	}
+}
 impl Default for FeatureRankedLevel {
 	fn default() -> Self { Self::Low(FeatureLevel::Lv0) }
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum FeatureDestinyRank {
 	Tian,
 	Di,
@@ -1,4 +1,5 @@
-// use sp_std::prelude::*;
+use sp_std::{convert::TryFrom, vec::Vec};
+use sp_runtime::{RuntimeDebug, DispatchError, DispatchResult};
 
 pub trait ManagerAccessor<AccountId>: Sized {
 	fn get_owner_id() -> AccountId;
@@ -9,6 +10,9 @@ pub trait ManagerAccessor<AccountId>: Sized {
 	fn is_issuer(_: &AccountId) -> bool { false }
 	/// Can freeze tokens.
 	fn is_freezer(_: &AccountId) -> bool { false }
+	/// Give `who` every role, so benchmarks can call what needs one.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_manager(_: &AccountId) {}
 }
 /// default implement for test
 impl ManagerAccessor<u64> for () {
@@ -26,6 +30,129 @@ impl RandomNumber<u32> for () {
 	fn generate_random_in_range(_: u32) -> u32 { 0 }
 }
 
+/// Something that turns raw entropy into a feature code, biasing the distribution of features.
+pub trait FeatureRoller {
+	fn roll(entropy: u32) -> u32;
+}
+/// default implement keeps every feature code equally likely
+impl FeatureRoller for () {
+	fn roll(entropy: u32) -> u32 { entropy }
+}
+
+/// Something that derives asset identifiers from an owner and a salt.
+pub trait AssetIdDeriver<AccountId, AssetId> {
+	fn derive_asset_id(owner: &AccountId, salt: &[u8; 32]) -> AssetId;
+}
+
+/// Why a transfer was rejected by a `TransferPolicy`.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum TransferError {
+	/// The sender may not send this asset.
+	Sender,
+	/// The recipient may not receive this asset.
+	Recipient,
+	/// The amount is not acceptable.
+	Amount,
+}
+
+/// An external policy which has the final say on every asset transfer.
+pub trait TransferPolicy<AccountId, AssetId, Balance> {
+	fn can_transfer(
+		id: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> Result<(), TransferError>;
+}
+/// default implement allows everything
+impl<AccountId, AssetId, Balance> TransferPolicy<AccountId, AssetId, Balance> for () {
+	fn can_transfer(_: AssetId, _: &AccountId, _: &AccountId, _: Balance) -> Result<(), TransferError> {
+		Ok(())
+	}
+}
+
+/// Something that credits accounts with assets on behalf of trusted runtime code, without
+/// holding any asset role.
+pub trait AssetRewarder<AccountId, AssetId, Balance> {
+	fn reward(id: AssetId, who: AccountId, amount: Balance) -> DispatchResult;
+}
+/// default implement rewards nothing
+impl<AccountId, AssetId, Balance> AssetRewarder<AccountId, AssetId, Balance> for () {
+	fn reward(_: AssetId, _: AccountId, _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("no asset rewarder"))
+	}
+}
+
+/// Something that decides where the transfer fees of an asset go.
+pub trait FeeRouter<AccountId, AssetId, Balance> {
+	/// Split `fee` into shares, each credited to an account or burned if it has none.
+	fn route(id: AssetId, owner: &AccountId, fee: Balance) -> Vec<(Option<AccountId>, Balance)>;
+}
+/// default implement pays the whole fee to the owner of the asset
+impl<AccountId: Clone, AssetId, Balance> FeeRouter<AccountId, AssetId, Balance> for () {
+	fn route(_: AssetId, owner: &AccountId, fee: Balance) -> Vec<(Option<AccountId>, Balance)> {
+		sp_std::vec![(Some(owner.clone()), fee)]
+	}
+}
+
+/// Something that cleans up state depending on an asset when it is destroyed.
+pub trait OnAssetDestroyed<AssetId> {
+	fn on_destroyed(id: AssetId);
+}
+/// default implement does nothing
+impl<AssetId> OnAssetDestroyed<AssetId> for () {
+	fn on_destroyed(_: AssetId) {}
+}
+
+/// Something that mirrors the movements of cross-chain assets to a bridge or XCM layer.
+pub trait CrossChainNotifier<AccountId, AssetId, Balance> {
+	fn on_transfer(id: AssetId, from: &AccountId, to: &AccountId, amount: Balance);
+	fn on_burn(id: AssetId, who: &AccountId, amount: Balance);
+}
+/// default implement notifies nobody
+impl<AccountId, AssetId, Balance> CrossChainNotifier<AccountId, AssetId, Balance> for () {
+	fn on_transfer(_: AssetId, _: &AccountId, _: &AccountId, _: Balance) {}
+	fn on_burn(_: AssetId, _: &AccountId, _: Balance) {}
+}
+
+/// Something that keeps extra data of an asset account up to date as its balance changes.
+pub trait OnAccountTouched<AssetId, AccountId, Balance, Extra> {
+	fn on_touched(id: AssetId, who: &AccountId, balance: Balance, extra: &mut Extra);
+}
+/// default implement leaves the extra data alone
+impl<AssetId, AccountId, Balance, Extra> OnAccountTouched<AssetId, AccountId, Balance, Extra> for () {
+	fn on_touched(_: AssetId, _: &AccountId, _: Balance, _: &mut Extra) {}
+}
+
+/// Something that presents asset identifiers as text and reads them back.
+pub trait AssetIdDisplay<AssetId> {
+	fn to_string(id: &AssetId) -> Vec<u8>;
+	fn from_string(s: &[u8]) -> Option<AssetId>;
+}
+/// default implement writes identifiers in decimal
+impl<AssetId: Copy + Into<u128> + TryFrom<u128>> AssetIdDisplay<AssetId> for () {
+	fn to_string(id: &AssetId) -> Vec<u8> {
+		let mut n: u128 = (*id).into();
+		let mut digits = Vec::new();
+		loop {
+			digits.push(b'0' + (n % 10) as u8);
+			n /= 10;
+			if n == 0 { break }
+		}
+		digits.reverse();
+		digits
+	}
+	fn from_string(s: &[u8]) -> Option<AssetId> {
+		if s.is_empty() { return None }
+		let mut n: u128 = 0;
+		for &c in s {
+			if !c.is_ascii_digit() { return None }
+			n = n.checked_mul(10)?.checked_add((c - b'0') as u128)?;
+		}
+		AssetId::try_from(n).ok()
+	}
+}
+
 // some thing with life
 pub trait LifeTime<BlockNumber> {
 	fn base_age(level: u32) -> BlockNumber;
@@ -1,5 +1,6 @@
 use mc_support::traits::{ LifeTime };
 use super::primitives::{ BlockNumber };
+use frame_support::traits::Filter;
 
 pub struct DemoActor;
 
@@ -17,3 +18,12 @@ impl LifeTime<BlockNumber> for DemoItem {
 		10_000_000
 	}
 }
+
+/// Asset ids below 1000 are scarce, so they are auctioned rather than taken by whoever is first.
+pub struct ShortAssetIds;
+
+impl Filter<u32> for ShortAssetIds {
+	fn filter(id: &u32) -> bool {
+		(1..1000).contains(id)
+	}
+}
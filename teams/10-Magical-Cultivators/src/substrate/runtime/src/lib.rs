@@ -235,9 +235,31 @@ impl pallet_sudo::Config for Runtime {
 parameter_types! {
 	pub const AssetDepositBase: Balance = 100 * DOLLARS;
 	pub const AssetDepositPerZombie: Balance = 1 * DOLLARS;
+	pub const ApprovalDeposit: Balance = 1 * DOLLARS;
 	pub const StringLimit: u32 = 50;
+	pub const MaxTotalMetadataBytes: u32 = 64;
 	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
 	pub const MetadataDepositPerByte: Balance = 1 * DOLLARS;
+	pub const RateLimitWindow: BlockNumber = DAYS;
+	pub const ReusableAssetIds: bool = false;
+	pub const MaxBatchSize: u32 = 100;
+	pub const DestroyRefundRatio: Permill = Permill::one();
+	pub const MaxMinBalance: Balance = Balance::max_value();
+	pub BatchWeightLimit: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const DepositAsset: Option<u32> = None;
+	pub const SupplyReportPeriod: BlockNumber = HOURS;
+	pub const MaxSupplyReportAssets: u32 = 100;
+	pub const MinAccountLifetime: BlockNumber = HOURS;
+	pub const EmitScaledAmounts: bool = false;
+	pub const ClaimDeposit: Balance = 1 * DOLLARS;
+	pub const ClaimPeriod: BlockNumber = 10 * MINUTES;
+	pub const IdAuctionDuration: BlockNumber = DAYS;
+	pub const IdAuctionClaimPeriod: BlockNumber = 7 * DAYS;
+	pub const RecordActivity: bool = false;
+	pub const MaxRecentActivity: u32 = 20;
+	pub const BurnAddress: Option<AccountId> = None;
+	pub const MaxBurnRateBps: u16 = 500;
+	pub const MaxFeeShares: u32 = 4;
 }
 impl mc_featured_assets::Config for Runtime {
 	type Event = Event;
@@ -247,13 +269,49 @@ impl mc_featured_assets::Config for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type AssetDepositBase = AssetDepositBase;
 	type AssetDepositPerZombie = AssetDepositPerZombie;
+	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
+	type MaxTotalMetadataBytes = MaxTotalMetadataBytes;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type WeightInfo = mc_featured_assets::weights::SubstrateWeight<Runtime>;
 	// Featured part
 	type AssetAdmin = Nature;
 	type RandomNumber = Nature;
+	type AssetIdDeriver = mc_featured_assets::HashedAssetId<Runtime>;
+	type TransferPolicy = ();
+	type RateLimitWindow = RateLimitWindow;
+	type ReusableAssetIds = ReusableAssetIds;
+	type MaxBatchSize = MaxBatchSize;
+	type FeatureRoller = ();
+	type DestroyRefundRatio = DestroyRefundRatio;
+	type DestroyFeeDestination = ();
+	type MaxMinBalance = MaxMinBalance;
+	type AssetIdConverter = mc_featured_assets::ZeroPaddedAssetId;
+	type HoldOrigin = frame_system::EnsureRoot<AccountId>;
+	type BatchWeightLimit = BatchWeightLimit;
+	type DepositAsset = DepositAsset;
+	type SupplyReportPeriod = SupplyReportPeriod;
+	type MaxSupplyReportAssets = MaxSupplyReportAssets;
+	type AssetIdDisplay = ();
+	type MinAccountLifetime = MinAccountLifetime;
+	type OnDestroy = ();
+	type EmitScaledAmounts = EmitScaledAmounts;
+	type ClaimDeposit = ClaimDeposit;
+	type ClaimPeriod = ClaimPeriod;
+	type FeeRouter = ();
+	type MaxFeeShares = MaxFeeShares;
+	type AccountExtra = ();
+	type OnAccountTouched = ();
+	type PremiumAssetIds = ShortAssetIds;
+	type IdAuctionDuration = IdAuctionDuration;
+	type IdAuctionClaimPeriod = IdAuctionClaimPeriod;
+	type IdAuctionProceeds = ();
+	type CrossChainNotifier = ();
+	type RecordActivity = RecordActivity;
+	type MaxRecentActivity = MaxRecentActivity;
+	type BurnAddress = BurnAddress;
+	type MaxBurnRateBps = MaxBurnRateBps;
 }
 
 parameter_types! {
@@ -492,6 +550,88 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl mc_featured_assets_runtime_api::FeaturedAssetsApi<Block, AccountId, u32, Balance, Balance>
+		for Runtime {
+		fn transferable_now(id: u32, who: AccountId) -> Balance {
+			FeaturedAssets::transferable_now(id, who)
+		}
+
+		fn try_total_supply(id: u32) -> Option<Balance> {
+			FeaturedAssets::try_total_supply(id)
+		}
+
+		fn can_receive(id: u32, who: AccountId) -> mc_featured_assets_runtime_api::CanReceive {
+			FeaturedAssets::can_receive(id, who)
+		}
+
+		fn would_be_zombie(id: u32, who: AccountId) -> Option<bool> {
+			FeaturedAssets::would_be_zombie(id, who)
+		}
+
+		fn simulate_transfer(
+			id: u32,
+			from: AccountId,
+			to: AccountId,
+			amount: Balance,
+		) -> mc_featured_assets_runtime_api::TransferSimulation<Balance> {
+			FeaturedAssets::simulate_transfer(id, from, to, amount)
+		}
+
+		fn asset_overview(
+			id: u32,
+		) -> Option<mc_featured_assets_runtime_api::AssetOverview<Balance, AccountId>> {
+			FeaturedAssets::asset_overview(id)
+		}
+
+		fn eth_address_of(id: u32) -> [u8; 20] {
+			FeaturedAssets::eth_address_of(id)
+		}
+
+		fn asset_of_eth_address(address: [u8; 20]) -> Option<u32> {
+			FeaturedAssets::asset_of_eth_address(address)
+		}
+
+		fn create_deposit(max_zombies: u32) -> Balance {
+			FeaturedAssets::create_deposit(max_zombies)
+		}
+
+		fn metadata_deposit(name_len: u32, symbol_len: u32) -> Balance {
+			FeaturedAssets::metadata_deposit(name_len, symbol_len)
+		}
+
+		fn assets_created_by(creator: AccountId) -> Vec<u32> {
+			FeaturedAssets::assets_created_by(creator)
+		}
+
+		fn total_deposit_of(owner: AccountId) -> Balance {
+			FeaturedAssets::total_deposit_of(owner)
+		}
+
+		fn approvals_for_delegate(
+			delegate: AccountId,
+			start_after: Option<(u32, AccountId)>,
+			limit: u32,
+		) -> Vec<(u32, AccountId, Balance)> {
+			FeaturedAssets::approvals_for_delegate(delegate, start_after, limit)
+		}
+
+		fn feature_histogram() -> Vec<(mc_featured_assets_runtime_api::FeatureDestinyRank, u32)> {
+			FeaturedAssets::feature_histogram()
+		}
+
+		fn feature_status(id: u32) -> Option<(bool, Option<mc_featured_assets_runtime_api::AssetFeature>)> {
+			FeaturedAssets::feature_status(id)
+		}
+
+		fn format_asset_id(id: u32) -> Vec<u8> {
+			FeaturedAssets::format_asset_id(id)
+		}
+
+		fn parse_asset_id(s: Vec<u8>) -> Option<u32> {
+			FeaturedAssets::parse_asset_id(s)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
@@ -526,6 +666,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, mc_featured_assets, FeaturedAssets);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)